@@ -2,6 +2,16 @@ use criterion::black_box;
 use criterion::criterion_group;
 use criterion::criterion_main;
 use criterion::Criterion;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::DataType;
+use gitql_core::environment::Environment;
+use gitql_core::object::Row;
+use gitql_core::schema::Schema;
+use gitql_core::values::integer::IntValue;
+use gitql_core::values::Value;
+use gitql_engine::data_provider::DataProvider;
+use gitql_engine::engine::evaluate;
+use gitql_parser::parser::parse_gql;
 use gitql_parser::tokenizer::Tokenizer;
 
 const QUERY_100_CHAR: &str = "SELECT name, COUNT(name) FROM commits GROUP BY name, author_email ORDER BY commit_num DESC LIMIT 100";
@@ -33,6 +43,46 @@ fn tokenizer_10m_char_benchmark(c: &mut Criterion) {
     });
 }
 
+const ORDER_BY_QUERY: &str = "SELECT value FROM numbers ORDER BY value DESC";
+
+struct NumbersDataProvider {
+    rows_count: i64,
+}
+
+impl DataProvider for NumbersDataProvider {
+    fn provide(&self, _table: &str, _columns: &[String]) -> Result<Vec<Row>, String> {
+        // Shuffled unique values, 7919 is a prime so it's coprime with the number of rows
+        let rows = (0..self.rows_count)
+            .map(|index| Row {
+                values: vec![
+                    Box::new(IntValue::new(index * 7919 % self.rows_count)) as Box<dyn Value>
+                ],
+            })
+            .collect();
+        Ok(rows)
+    }
+}
+
+fn order_by_100k_rows_benchmark(c: &mut Criterion) {
+    let mut schema = Schema::default();
+    let columns: [(&'static str, Box<dyn DataType>); 1] = [("value", Box::new(IntType))];
+    schema.add_table("numbers", &columns).unwrap();
+
+    let mut env = Environment::new(schema);
+    let provider: Box<dyn DataProvider> = Box::new(NumbersDataProvider {
+        rows_count: 100_000,
+    });
+    c.bench_function("Order By 100K Rows", |b| {
+        b.iter(|| {
+            let tokens = Tokenizer::tokenize(black_box(ORDER_BY_QUERY.to_owned()))
+                .ok()
+                .unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            evaluate(&mut env, &provider, queries).is_ok()
+        })
+    });
+}
+
 criterion_group! {
    name = benches;
    config = Criterion::default().significance_level(0.1).sample_size(10);
@@ -41,7 +91,9 @@ criterion_group! {
    tokenizer_100_char_benchmark,
    tokenizer_100k_char_benchmark,
    tokenizer_1m_char_benchmark,
-   tokenizer_10m_char_benchmark
+   tokenizer_10m_char_benchmark,
+   // Engine
+   order_by_100k_rows_benchmark
 }
 
 criterion_main!(benches);
@@ -10,6 +10,7 @@ use super::types::text::TextType;
 use super::types::DataType;
 
 use crate::interval::Interval;
+use crate::location::SourceLocation;
 use crate::operator::ArithmeticOperator;
 use crate::operator::BinaryBitwiseOperator;
 use crate::operator::BinaryLogicalOperator;
@@ -26,6 +27,8 @@ pub enum ExprKind {
     Symbol,
     Array,
     GlobalVariable,
+    Parameter,
+    Hoisted,
     Number,
     Boolean,
     Interval,
@@ -33,11 +36,13 @@ pub enum ExprKind {
     Index,
     Slice,
     Arithmetic,
+    Concat,
     Comparison,
     GroupComparison,
     Contains,
     ContainedBy,
     Like,
+    GroupLike,
     Regex,
     Glob,
     Logical,
@@ -46,12 +51,16 @@ pub enum ExprKind {
     BenchmarkCall,
     Between,
     Case,
+    Coalesce,
     In,
+    InSubquery,
     IsNull,
+    IsDistinct,
     Null,
     Cast,
     Grouping,
     MemberAccess,
+    JsonAccess,
 }
 
 dyn_clone::clone_trait_object!(Expr);
@@ -178,6 +187,47 @@ impl Expr for GlobalVariableExpr {
     }
 }
 
+#[derive(Clone)]
+pub struct ParameterExpr {
+    pub index: usize,
+    pub result_type: Box<dyn DataType>,
+}
+
+impl Expr for ParameterExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Parameter
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.result_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Reference to the value of row independent expression that is evaluated once before scanning the rows
+#[derive(Clone)]
+pub struct HoistedExpr {
+    pub index: usize,
+    pub result_type: Box<dyn DataType>,
+}
+
+impl Expr for HoistedExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Hoisted
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.result_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub enum Number {
     Int(i64),
@@ -321,6 +371,8 @@ pub struct ArithmeticExpr {
     pub operator: ArithmeticOperator,
     pub right: Box<dyn Expr>,
     pub result_type: Box<dyn DataType>,
+    /// Location of the operator, used to report runtime errors like division by zero
+    pub location: SourceLocation,
 }
 
 impl Expr for ArithmeticExpr {
@@ -337,6 +389,27 @@ impl Expr for ArithmeticExpr {
     }
 }
 
+/// Concatenation of two values as Text using `||` operator
+#[derive(Clone)]
+pub struct ConcatExpr {
+    pub left: Box<dyn Expr>,
+    pub right: Box<dyn Expr>,
+}
+
+impl Expr for ConcatExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Concat
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(TextType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct ComparisonExpr {
     pub left: Box<dyn Expr>,
@@ -448,10 +521,33 @@ impl Expr for LikeExpr {
     }
 }
 
+#[derive(Clone)]
+pub struct GroupLikeExpr {
+    pub input: Box<dyn Expr>,
+    pub patterns: Box<dyn Expr>,
+    pub group_operator: GroupComparisonOperator,
+}
+
+impl Expr for GroupLikeExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::GroupLike
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(BoolType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct RegexExpr {
     pub input: Box<dyn Expr>,
     pub pattern: Box<dyn Expr>,
+    /// Location of the operator, used to report runtime errors like invalid pattern
+    pub location: SourceLocation,
 }
 
 impl Expr for RegexExpr {
@@ -631,6 +727,28 @@ impl Expr for CaseExpr {
     }
 }
 
+/// `COALESCE(a, b, ...)` that evaluates the values from left to right
+/// and returns the first value that is not NULL
+#[derive(Clone)]
+pub struct CoalesceExpr {
+    pub values: Vec<Box<dyn Expr>>,
+    pub values_type: Box<dyn DataType>,
+}
+
+impl Expr for CoalesceExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::Coalesce
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        self.values_type.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct InExpr {
     pub argument: Box<dyn Expr>,
@@ -645,7 +763,30 @@ impl Expr for InExpr {
     }
 
     fn expr_type(&self) -> Box<dyn DataType> {
-        self.values_type.clone()
+        Box::new(BoolType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// `IN (SELECT ...)` membership test against the values selected by a subquery,
+/// the subquery is referenced by its index in the subqueries of the enclosing query
+#[derive(Clone)]
+pub struct InSubqueryExpr {
+    pub argument: Box<dyn Expr>,
+    pub subquery_index: usize,
+    pub has_not_keyword: bool,
+}
+
+impl Expr for InSubqueryExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::InSubquery
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(BoolType)
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -673,6 +814,27 @@ impl Expr for IsNullExpr {
     }
 }
 
+#[derive(Clone)]
+pub struct IsDistinctExpr {
+    pub left: Box<dyn Expr>,
+    pub right: Box<dyn Expr>,
+    pub has_not: bool,
+}
+
+impl Expr for IsDistinctExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::IsDistinct
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(BoolType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 #[derive(Clone)]
 pub struct NullExpr;
 
@@ -694,6 +856,8 @@ impl Expr for NullExpr {
 pub struct CastExpr {
     pub value: Box<dyn Expr>,
     pub result_type: Box<dyn DataType>,
+    /// Location of the explicit cast used to report runtime errors, implicit casts have no location
+    pub location: Option<SourceLocation>,
 }
 
 impl Expr for CastExpr {
@@ -749,3 +913,24 @@ impl Expr for MemberAccessExpr {
         self
     }
 }
+
+#[derive(Clone)]
+pub struct JsonAccessExpr {
+    pub value: Box<dyn Expr>,
+    pub key: Box<dyn Expr>,
+    pub as_text: bool,
+}
+
+impl Expr for JsonAccessExpr {
+    fn kind(&self) -> ExprKind {
+        ExprKind::JsonAccess
+    }
+
+    fn expr_type(&self) -> Box<dyn DataType> {
+        Box::new(TextType)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -1,5 +1,6 @@
 pub mod expression;
 pub mod format_checker;
+pub mod location;
 pub mod operator;
 pub mod statement;
 pub mod types;
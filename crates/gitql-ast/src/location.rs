@@ -0,0 +1,42 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result;
+
+/// Location of a token or expression in the query source, used to report diagnostics
+#[derive(Copy, Clone, Debug)]
+pub struct SourceLocation {
+    pub line_start: u32,
+    pub line_end: u32,
+    pub column_start: u32,
+    pub column_end: u32,
+}
+
+impl SourceLocation {
+    pub fn new(
+        line_start: u32,
+        line_end: u32,
+        column_start: u32,
+        column_end: u32,
+    ) -> SourceLocation {
+        SourceLocation {
+            line_start,
+            line_end,
+            column_start,
+            column_end,
+        }
+    }
+
+    pub fn expand_until(&mut self, location: SourceLocation) {
+        self.column_end = location.column_end;
+        self.line_end = location.line_end;
+    }
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_fmt(format_args!(
+            "Loc(L {}:{}, C {}:{})",
+            self.line_start, self.line_end, self.column_start, self.column_end
+        ))
+    }
+}
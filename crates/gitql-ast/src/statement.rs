@@ -33,6 +33,7 @@ pub enum Query {
     GlobalVariableDeclaration(GlobalVariableStatement),
     Describe(DescribeStatement),
     ShowTables,
+    Compound(CompoundQuery),
 }
 
 pub struct GQLQuery {
@@ -41,6 +42,26 @@ pub struct GQLQuery {
     pub has_aggregation_function: bool,
     pub has_group_by_statement: bool,
     pub hidden_selections: HashMap<String, Vec<String>>,
+    /// Uncorrelated subqueries used by this query, evaluated once before it
+    pub subqueries: Vec<GQLQuery>,
+}
+
+/// Set operator that combines the results of two `SELECT` queries
+#[derive(Clone, PartialEq)]
+pub enum SetOperator {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
+}
+
+/// Two or more `SELECT` queries combined by set operators like `UNION`,
+/// the left query can be another compound query to support chains like `A UNION B EXCEPT C`,
+/// and the right query can be a compound query of operators with higher precedence like `INTERSECT`
+pub struct CompoundQuery {
+    pub left: Box<Query>,
+    pub right: Box<Query>,
+    pub operator: SetOperator,
 }
 
 #[derive(Clone)]
@@ -68,9 +89,20 @@ pub enum Distinct {
 #[derive(Clone)]
 pub struct TableSelection {
     pub table_name: String,
+    /// Optional alias from `FROM table AS alias`, used to reference the table instead of its name
+    pub table_alias: Option<String>,
+    /// Columns names, columns qualified by the table reference name like `alias.column`
+    /// are provided by the column name and keep the qualified name as title
     pub columns_names: Vec<String>,
 }
 
+impl TableSelection {
+    /// Return the name used to reference this table in the query, the alias if exists or the table name
+    pub fn reference_name(&self) -> &str {
+        self.table_alias.as_deref().unwrap_or(&self.table_name)
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub enum JoinKind {
     Cross,
@@ -83,6 +115,7 @@ pub enum JoinKind {
 #[derive(Clone)]
 pub enum JoinOperand {
     /// Used when JOIN is used first time on query, X JOIN Y,
+    /// tables are referenced by their alias if exists or their name
     OuterAndInner(String, String),
     /// Used for JOIN that used after first time, JOIN Z
     Inner(String),
@@ -102,6 +135,10 @@ pub struct SelectStatement {
     pub selected_expr_titles: Vec<String>,
     pub selected_expr: Vec<Box<dyn Expr>>,
     pub distinct: Distinct,
+    /// Generated fields names of `SELECT * REPLACE (...)` expressions mapped to the replaced columns names
+    pub replaced_fields: HashMap<String, String>,
+    /// Generated fields names of `ROWNUM()` that are assigned the final position of each row
+    pub row_number_fields: Vec<String>,
 }
 
 impl Statement for SelectStatement {
@@ -162,6 +199,7 @@ impl Statement for LimitStatement {
 #[derive(Clone)]
 pub struct OffsetStatement {
     pub count: usize,
+    pub is_percentage: bool,
 }
 
 impl Statement for OffsetStatement {
@@ -186,11 +224,21 @@ pub enum NullsOrderPolicy {
     NullsLast,
 }
 
+/// Text collation used to compare values in `ORDER BY`
+#[derive(Clone, PartialEq)]
+pub enum Collation {
+    /// Compare text values by their bytes, this is the default collation
+    Binary,
+    /// Compare text values case-insensitively
+    NoCase,
+}
+
 #[derive(Clone)]
 pub struct OrderByStatement {
     pub arguments: Vec<Box<dyn Expr>>,
     pub sorting_orders: Vec<SortingOrder>,
     pub nulls_order_policies: Vec<NullsOrderPolicy>,
+    pub collations: Vec<Collation>,
 }
 
 impl Statement for OrderByStatement {
@@ -275,6 +323,8 @@ impl Statement for WindowFunctionsStatement {
 pub enum AggregateValue {
     Expression(Box<dyn Expr>),
     Function(String, Vec<Box<dyn Expr>>),
+    /// Aggregation function called with `DISTINCT` on the combination of all arguments
+    DistinctFunction(String, Vec<Box<dyn Expr>>),
 }
 
 #[derive(Clone)]
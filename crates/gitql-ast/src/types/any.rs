@@ -10,6 +10,10 @@ impl DataType for AnyType {
         "Any".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "any".to_string()
+    }
+
     fn equals(&self, _other: &Box<dyn DataType>) -> bool {
         true
     }
@@ -20,6 +20,10 @@ impl DataType for ArrayType {
         format!("Array({})", self.base.literal())
     }
 
+    fn type_tag(&self) -> String {
+        "array".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         let array_type: Box<dyn DataType> = Box::new(self.clone());
         if other.is_any() || other.is_variant_contains(&array_type) {
@@ -63,4 +67,12 @@ impl DataType for ArrayType {
     fn logical_or_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
         Box::new(BoolType)
     }
+
+    fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
+        self.base
+            .can_perform_explicit_cast_op_to()
+            .into_iter()
+            .map(|element_type| Box::new(ArrayType::new(element_type)) as Box<dyn DataType>)
+            .collect()
+    }
 }
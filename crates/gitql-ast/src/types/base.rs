@@ -30,6 +30,10 @@ pub trait DataType: DynClone {
     /// Return the literal representation for this [`DataType`]
     fn literal(&self) -> String;
 
+    /// Return a stable lowercase tag for this [`DataType`] like `integer` or `array`,
+    /// unlike the literal it's not intended for humans and doesn't contain the inner types
+    fn type_tag(&self) -> String;
+
     /// Return if other [`DataType`] is equal or not to current Type
     #[allow(unused_variables)]
     #[allow(clippy::borrowed_box)]
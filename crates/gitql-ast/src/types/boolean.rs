@@ -4,6 +4,7 @@ use crate::expression::Expr;
 use crate::expression::StringExpr;
 use crate::types::array::ArrayType;
 use crate::types::integer::IntType;
+use crate::types::text::TextType;
 
 use super::base::DataType;
 
@@ -15,6 +16,10 @@ impl DataType for BoolType {
         "Boolean".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "boolean".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_bool() || other.is_variant_with(|t| t.is_bool())
     }
@@ -117,6 +122,6 @@ impl DataType for BoolType {
     }
 
     fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(TextType)]
     }
 }
@@ -32,6 +32,10 @@ impl DataType for CompositeType {
         self.name.to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "composite".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         if other.is_any() {
             return true;
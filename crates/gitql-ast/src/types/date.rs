@@ -17,6 +17,10 @@ impl DataType for DateType {
         "Date".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "date".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_date() || other.is_variant_with(|t| t.is_date())
     }
@@ -34,10 +38,14 @@ impl DataType for DateType {
     }
 
     fn can_perform_sub_op_with(&self) -> Vec<Box<dyn DataType>> {
-        vec![Box::new(IntType)]
+        vec![Box::new(IntType), Box::new(DateType)]
     }
 
-    fn sub_op_result_type(&self, _other: &Box<dyn DataType>) -> Box<dyn DataType> {
+    fn sub_op_result_type(&self, other: &Box<dyn DataType>) -> Box<dyn DataType> {
+        // Subtracting two dates returns the number of days between them
+        if other.is_date() {
+            return Box::new(IntType);
+        }
         Box::new(DateType)
     }
 
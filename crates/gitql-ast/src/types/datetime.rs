@@ -16,6 +16,10 @@ impl DataType for DateTimeType {
         "DateTime".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "datetime".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_date_time() || other.is_variant_with(|t| t.is_date_time())
     }
@@ -23,6 +23,10 @@ impl DataType for DynamicType {
         "Dynamic".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "dynamic".to_string()
+    }
+
     fn equals(&self, _other: &Box<dyn DataType>) -> bool {
         false
     }
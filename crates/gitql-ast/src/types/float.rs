@@ -1,5 +1,6 @@
 use std::any::Any;
 
+use crate::expression::Expr;
 use crate::types::{array::ArrayType, integer::IntType};
 
 use super::base::DataType;
@@ -12,6 +13,10 @@ impl DataType for FloatType {
         "Float".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "float".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_float() || other.is_variant_with(|t| t.is_float())
     }
@@ -108,6 +113,12 @@ impl DataType for FloatType {
         Box::new(self.clone())
     }
 
+    fn has_implicit_cast_from(&self, expr: &Box<dyn Expr>) -> bool {
+        // Integer is widened to Float wherever a Float is expected, for example function arguments,
+        // comparisons and `BETWEEN` bounds
+        expr.expr_type().is_int()
+    }
+
     fn can_perform_explicit_cast_op_to(&self) -> Vec<Box<dyn DataType>> {
         vec![Box::new(IntType)]
     }
@@ -14,6 +14,10 @@ impl DataType for IntType {
         "Int".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "integer".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_int() || other.is_variant_with(|t| t.is_int())
     }
@@ -12,6 +12,10 @@ impl DataType for IntervalType {
         "Interval".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "interval".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_interval() || other.is_variant_with(|t| t.is_interval())
     }
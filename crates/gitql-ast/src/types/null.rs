@@ -10,6 +10,10 @@ impl DataType for NullType {
         "Null".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "null".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_null() || other.is_variant_with(|t| t.is_null())
     }
@@ -21,6 +21,10 @@ impl DataType for OptionType {
         "None".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "optional".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         if other.is_any() {
             return true;
@@ -19,6 +19,10 @@ impl DataType for RangeType {
         format!("Range({})", self.base.literal())
     }
 
+    fn type_tag(&self) -> String {
+        "range".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         let range_type: Box<dyn DataType> = Box::new(self.clone());
         if other.is_any() || other.is_variant_contains(&range_type) {
@@ -12,6 +12,10 @@ impl DataType for TextType {
         "Text".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "text".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_text() || other.is_variant_with(|t| t.is_text())
     }
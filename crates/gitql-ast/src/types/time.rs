@@ -15,6 +15,10 @@ impl DataType for TimeType {
         "Time".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "time".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || other.is_time() || other.is_variant_with(|t| t.is_time())
     }
@@ -10,6 +10,10 @@ impl DataType for UndefType {
         "Undef".to_string()
     }
 
+    fn type_tag(&self) -> String {
+        "undefined".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.as_any().downcast_ref::<UndefType>().is_some()
     }
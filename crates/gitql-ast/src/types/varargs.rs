@@ -18,6 +18,10 @@ impl DataType for VarargsType {
         format!("...{}", self.base.literal())
     }
 
+    fn type_tag(&self) -> String {
+        "varargs".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         other.is_any() || self.base.equals(other)
     }
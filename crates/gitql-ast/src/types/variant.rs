@@ -28,6 +28,10 @@ impl DataType for VariantType {
         str
     }
 
+    fn type_tag(&self) -> String {
+        "variant".to_string()
+    }
+
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         if other.is_any() {
             return true;
@@ -10,6 +10,17 @@ pub enum OutputFormat {
     CSV,
 }
 
+/// Represent the set of commits that should be walked for commits tables
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WalkScope {
+    /// Walk commits reachable from the current HEAD
+    Head,
+    /// Walk commits reachable from all local branches
+    Branches,
+    /// Walk commits reachable from all references including remotes and tags
+    All,
+}
+
 /// Arguments for GitQL
 #[derive(Debug, PartialEq)]
 pub struct Arguments {
@@ -19,6 +30,9 @@ pub struct Arguments {
     pub page_size: usize,
     pub enable_line_editor: bool,
     pub output_format: OutputFormat,
+    pub walk_scope: WalkScope,
+    pub float_precision: Option<usize>,
+    pub typed_output: bool,
 }
 
 /// Create a new instance of Arguments with the default settings
@@ -31,6 +45,9 @@ impl Arguments {
             page_size: 10,
             enable_line_editor: false,
             output_format: OutputFormat::Render,
+            walk_scope: WalkScope::Head,
+            float_precision: None,
+            typed_output: false,
         }
     }
 }
@@ -162,6 +179,45 @@ pub fn parse_arguments(args: &[String]) -> Command {
 
                 arg_index += 1;
             }
+            "--walk" | "-w" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {} must be followed by walk scope", arg);
+                    return Command::Error(message);
+                }
+
+                let walk_scope = &args[arg_index].to_lowercase();
+                if walk_scope == "head" {
+                    arguments.walk_scope = WalkScope::Head;
+                } else if walk_scope == "branches" {
+                    arguments.walk_scope = WalkScope::Branches;
+                } else if walk_scope == "all" {
+                    arguments.walk_scope = WalkScope::All;
+                } else {
+                    return Command::Error("Invalid walk scope".to_string());
+                }
+
+                arg_index += 1;
+            }
+            "--typed" | "-t" => {
+                arguments.typed_output = true;
+                arg_index += 1;
+            }
+            "--precision" | "-fp" => {
+                arg_index += 1;
+                if arg_index >= args_len {
+                    let message = format!("Argument {} must be followed by the precision", arg);
+                    return Command::Error(message);
+                }
+
+                let precision_result = args[arg_index].parse::<usize>();
+                if precision_result.is_err() {
+                    return Command::Error("Invalid float precision".to_string());
+                }
+
+                arguments.float_precision = precision_result.ok();
+                arg_index += 1;
+            }
             _ => return Command::Error(format!("Unknown command {}", arg)),
         }
     }
@@ -205,6 +261,9 @@ pub fn print_help_list() {
     println!("-p,  --pagination           Enable print result with pagination");
     println!("-ps, --pagesize             Set pagination page size [default: 10]");
     println!("-o,  --output               Set output format [render, json, csv]");
+    println!("-w,  --walk                 Set commits walk scope [head, branches, all]");
+    println!("-fp, --precision            Set max decimal places of displayed floats [default: unlimited]");
+    println!("-t,  --typed                Include values types tags in the json output");
     println!("-a,  --analysis             Print Query analysis");
     println!("-e,  --editor               Enable GitQL Rich Line Editor");
     println!("-h,  --help                 Print GitQL help");
@@ -305,4 +364,70 @@ mod tests {
         let command = parse_arguments(&arguments);
         assert!(matches!(command, Command::Error { .. }));
     }
+
+    #[test]
+    fn test_arguments_with_valid_walk_scope() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--walk".to_string(),
+            "branches".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        let Command::ReplMode(arguments) = command else {
+            panic!("Expect REPL mode command");
+        };
+        assert_eq!(arguments.walk_scope, WalkScope::Branches);
+    }
+
+    #[test]
+    fn test_arguments_with_invalid_walk_scope() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--walk".to_string(),
+            "remotes".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
+
+    #[test]
+    fn test_arguments_with_missing_walk_scope() {
+        let arguments = vec!["gitql".to_string(), "--walk".to_string()];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
+
+    #[test]
+    fn test_arguments_with_float_precision() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--precision".to_string(),
+            "2".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        let Command::ReplMode(arguments) = command else {
+            panic!("Expect REPL mode")
+        };
+        assert_eq!(arguments.float_precision, Some(2));
+
+        let arguments = vec!["gitql".to_string(), "-fp".to_string(), "-1".to_string()];
+        let command = parse_arguments(&arguments);
+        assert!(matches!(command, Command::Error { .. }));
+    }
+
+    #[test]
+    fn test_arguments_with_typed_output() {
+        let arguments = vec![
+            "gitql".to_string(),
+            "--output".to_string(),
+            "json".to_string(),
+            "--typed".to_string(),
+        ];
+        let command = parse_arguments(&arguments);
+        let Command::ReplMode(arguments) = command else {
+            panic!("Expect REPL mode")
+        };
+        assert_eq!(arguments.output_format, OutputFormat::JSON);
+        assert!(arguments.typed_output);
+    }
 }
@@ -3,7 +3,9 @@ use gitql_core::object::GitQLObject;
 
 use super::base::OutputPrinter;
 
-pub struct CSVPrinter;
+pub struct CSVPrinter {
+    pub float_precision: Option<usize>,
+}
 
 impl OutputPrinter for CSVPrinter {
     fn print(&self, object: &mut GitQLObject) {
@@ -14,7 +16,7 @@ impl OutputPrinter for CSVPrinter {
             for row in &group.rows {
                 let mut values_row: Vec<String> = Vec::with_capacity(row_len);
                 for value in &row.values {
-                    values_row.push(value.literal());
+                    values_row.push(value.display_literal(self.float_precision));
                 }
                 let _ = writer.write_record(values_row);
             }
@@ -2,10 +2,23 @@ use gitql_core::object::GitQLObject;
 
 use super::base::OutputPrinter;
 
-pub struct JSONPrinter;
+pub struct JSONPrinter {
+    pub float_precision: Option<usize>,
+    /// Print each value as object with its type tag and literal value
+    pub with_types: bool,
+}
 
 impl OutputPrinter for JSONPrinter {
     fn print(&self, object: &mut GitQLObject) {
+        if let Ok(json_str) = serde_json::to_string(&self.to_json(object)) {
+            println!("{}", json_str);
+        }
+    }
+}
+
+impl JSONPrinter {
+    /// Convert the rows of the first group to JSON array of objects keyed by the titles
+    fn to_json(&self, object: &GitQLObject) -> serde_json::Value {
         let mut elements: Vec<serde_json::Value> = vec![];
 
         if let Some(group) = object.groups.first() {
@@ -13,17 +26,92 @@ impl OutputPrinter for JSONPrinter {
             for row in &group.rows {
                 let mut object = serde_json::Map::new();
                 for (i, value) in row.values.iter().enumerate() {
-                    object.insert(
-                        titles[i].to_string(),
-                        serde_json::Value::String(value.literal()),
-                    );
+                    let literal =
+                        serde_json::Value::String(value.display_literal(self.float_precision));
+
+                    if self.with_types {
+                        let mut typed_value = serde_json::Map::new();
+                        typed_value.insert(
+                            "type".to_string(),
+                            serde_json::Value::String(value.type_tag()),
+                        );
+                        typed_value.insert("value".to_string(), literal);
+                        object.insert(
+                            titles[i].to_string(),
+                            serde_json::Value::Object(typed_value),
+                        );
+                        continue;
+                    }
+
+                    object.insert(titles[i].to_string(), literal);
                 }
                 elements.push(serde_json::Value::Object(object));
             }
         }
 
-        if let Ok(json_str) = serde_json::to_string(&serde_json::Value::Array(elements)) {
-            println!("{}", json_str);
-        }
+        serde_json::Value::Array(elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::types::integer::IntType;
+    use gitql_core::object::Group;
+    use gitql_core::object::Row;
+    use gitql_core::values::array::ArrayValue;
+    use gitql_core::values::composite::CompositeValue;
+    use gitql_core::values::integer::IntValue;
+    use gitql_core::values::null::NullValue;
+    use gitql_core::values::text::TextValue;
+    use gitql_core::values::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_json_printer_with_types() {
+        let values: Vec<Box<dyn Value>> = vec![
+            Box::new(IntValue::new(1)),
+            Box::new(TextValue::new("amr".to_string())),
+            Box::new(ArrayValue::new(
+                vec![Box::new(IntValue::new(2))],
+                Box::new(IntType),
+            )),
+            Box::new(
+                CompositeValue::empty("point".to_string())
+                    .add_member("x".to_string(), Box::new(IntValue::new(3))),
+            ),
+            Box::new(NullValue),
+        ];
+
+        let mut object = GitQLObject {
+            titles: ["id", "name", "ids", "point", "email"]
+                .iter()
+                .map(|title| title.to_string())
+                .collect(),
+            ..Default::default()
+        };
+        object.groups.push(Group {
+            rows: vec![Row { values }],
+        });
+
+        let printer = JSONPrinter {
+            float_precision: None,
+            with_types: true,
+        };
+
+        let json = printer.to_json(&object);
+        let row = &json[0];
+        assert_eq!(row["id"]["type"], "integer");
+        assert_eq!(row["id"]["value"], "1");
+        assert_eq!(row["name"]["type"], "text");
+        assert_eq!(row["ids"]["type"], "array");
+        assert_eq!(row["point"]["type"], "composite");
+        assert_eq!(row["email"]["type"], "null");
+
+        let printer = JSONPrinter {
+            float_precision: None,
+            with_types: false,
+        };
+        assert_eq!(printer.to_json(&object)[0]["name"], "amr");
     }
 }
@@ -1,4 +1,7 @@
+use comfy_table::ColumnConstraint;
+use comfy_table::Width;
 use gitql_core::object::Row;
+use unicode_width::UnicodeWidthStr;
 
 use super::base::OutputPrinter;
 
@@ -11,6 +14,7 @@ enum PaginationInput {
 pub struct TablePrinter {
     pub pagination: bool,
     pub page_size: usize,
+    pub float_precision: Option<usize>,
 }
 
 impl TablePrinter {
@@ -18,8 +22,15 @@ impl TablePrinter {
         TablePrinter {
             pagination,
             page_size,
+            float_precision: None,
         }
     }
+
+    /// Set the max number of decimal places used to display Float values
+    pub fn with_float_precision(mut self, float_precision: Option<usize>) -> Self {
+        self.float_precision = float_precision;
+        self
+    }
 }
 
 impl OutputPrinter for TablePrinter {
@@ -41,7 +52,7 @@ impl OutputPrinter for TablePrinter {
 
         // Print all data without pagination
         if !self.pagination || self.page_size >= group_len {
-            print_group_as_table(titles, table_headers, &group.rows);
+            print_group_as_table(titles, table_headers, &group.rows, self.float_precision);
             return;
         }
 
@@ -55,7 +66,12 @@ impl OutputPrinter for TablePrinter {
 
             let current_page_groups = &group.rows[start_index..end_index];
             println!("Page {}/{}", current_page, number_of_pages);
-            print_group_as_table(titles, table_headers.clone(), current_page_groups);
+            print_group_as_table(
+                titles,
+                table_headers.clone(),
+                current_page_groups,
+                self.float_precision,
+            );
 
             let pagination_input = handle_pagination_input(current_page, number_of_pages);
             match pagination_input {
@@ -67,7 +83,12 @@ impl OutputPrinter for TablePrinter {
     }
 }
 
-fn print_group_as_table(titles: &[String], table_headers: Vec<comfy_table::Cell>, rows: &[Row]) {
+fn print_group_as_table(
+    titles: &[String],
+    table_headers: Vec<comfy_table::Cell>,
+    rows: &[Row],
+    float_precision: Option<usize>,
+) {
     let mut table = comfy_table::Table::new();
 
     // Setup table style
@@ -78,18 +99,36 @@ fn print_group_as_table(titles: &[String], table_headers: Vec<comfy_table::Cell>
     table.set_header(table_headers);
 
     let titles_len = titles.len();
+    let mut columns_widths: Vec<usize> = titles.iter().map(|title| title.width()).collect();
 
-    // Add rows to the table
+    // Add rows to the table and measure the columns widths in the same pass
     for row in rows {
         let mut table_row: Vec<comfy_table::Cell> = vec![];
-        for index in 0..titles_len {
-            if let Some(value) = row.values.get(index) {
-                table_row.push(comfy_table::Cell::new(value.literal()));
-            }
+        for (value, column_width) in row.values.iter().zip(columns_widths.iter_mut()) {
+            *column_width = (*column_width).max(value.display_literal_width(float_precision));
+            table_row.push(comfy_table::Cell::new(
+                value.display_literal(float_precision),
+            ));
         }
         table.add_row(table_row);
     }
 
+    // Size the columns by their content if the table fits, otherwise wrap them dynamically
+    let padding_width = 2;
+    let borders_width = titles_len + 1;
+    let table_width =
+        columns_widths.iter().sum::<usize>() + padding_width * titles_len + borders_width;
+    let fits_terminal = table
+        .width()
+        .is_none_or(|terminal_width| table_width <= terminal_width as usize);
+    if fits_terminal {
+        // Absolute column width includes the padding around the content
+        table.set_constraints(columns_widths.iter().map(|width| {
+            let width = (width + padding_width).min(u16::MAX as usize) as u16;
+            ColumnConstraint::Absolute(Width::Fixed(width))
+        }));
+    }
+
     // Print table
     println!("{table}");
 }
@@ -1,9 +1,13 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use gitql_ast::types::DataType;
 
 use crate::schema::Schema;
+use crate::signature::AggregationAccumulator;
+use crate::signature::AggregationAccumulatorFactory;
 use crate::signature::AggregationFunction;
+use crate::signature::CollectedRowsAccumulator;
 use crate::signature::Signature;
 use crate::signature::StandardFunction;
 use crate::signature::WindowFunction;
@@ -28,23 +32,58 @@ pub struct Environment {
     /// Aggregation function references
     pub aggregation_functions: HashMap<&'static str, AggregationFunction>,
 
+    /// Aggregation accumulators factories that compute aggregation functions row by row
+    pub aggregation_accumulators: HashMap<&'static str, AggregationAccumulatorFactory>,
+
     /// Window function signatures
     pub window_signatures: HashMap<&'static str, Signature>,
 
     /// Window function references
     pub window_functions: HashMap<&'static str, WindowFunction>,
 
+    /// Standard functions that can return different values for the same arguments like `RAND()`
+    pub volatile_functions: HashSet<&'static str>,
+
     /// All Global Variables values that can life for this program session
     pub globals: HashMap<String, Box<dyn Value>>,
 
     /// All Global Variables Types that can life for this program session
     pub globals_types: HashMap<String, Box<dyn DataType>>,
 
+    /// Positional parameters values that bound to `$1`, `$2`... in the current query
+    pub parameters: Vec<Box<dyn Value>>,
+
+    /// Values of row independent expressions that are evaluated once before scanning the rows
+    pub hoisted_values: Vec<Box<dyn Value>>,
+
+    /// Hash keys of the values selected by each subquery of the current query, used by `IN (subquery)`
+    pub subqueries_values: Vec<HashSet<String>>,
+
     /// Local variables types in the current scope, later will be multi layer scopes
     pub scopes: HashMap<String, Box<dyn DataType>>,
 
+    /// Snapshots of the outer scopes saved by [`Environment::push_scope`]
+    pub scopes_snapshots: Vec<HashMap<String, Box<dyn DataType>>>,
+
     /// A Table of DataTypes mapped to their original names or aliases
     pub types_table: TypesTable,
+
+    /// Allow comparing Boolean with `0` and `1` integers literals by treating them as `FALSE` and `TRUE`
+    pub boolean_integer_coercion: bool,
+
+    /// Replace the value of a selected cell that failed to evaluate with an error value
+    /// and record a warning instead of failing the whole query
+    pub lenient_evaluation: bool,
+
+    /// Keywords that are parsed as column names when they appear in an expression
+    /// position and a column with the same name exists in the schema
+    pub relaxed_keywords: HashSet<String>,
+
+    /// Warnings recorded while evaluating the last queries in lenient mode
+    pub warnings: Vec<String>,
+
+    /// Maximum number of subqueries that can be nested inside each other, default is 16
+    pub max_subquery_depth: usize,
 }
 
 impl Environment {
@@ -56,12 +95,23 @@ impl Environment {
             std_functions: HashMap::default(),
             aggregation_signatures: HashMap::default(),
             aggregation_functions: HashMap::default(),
+            aggregation_accumulators: HashMap::default(),
             window_signatures: HashMap::default(),
             window_functions: HashMap::default(),
+            volatile_functions: HashSet::default(),
             globals: HashMap::default(),
             globals_types: HashMap::default(),
+            parameters: vec![],
+            hoisted_values: vec![],
+            subqueries_values: vec![],
             scopes: HashMap::default(),
+            scopes_snapshots: vec![],
             types_table: TypesTable::new(),
+            boolean_integer_coercion: false,
+            lenient_evaluation: false,
+            relaxed_keywords: HashSet::default(),
+            warnings: vec![],
+            max_subquery_depth: 16,
         }
     }
 
@@ -85,6 +135,15 @@ impl Environment {
         self.aggregation_functions.extend(aggregation.to_owned());
     }
 
+    /// Register aggregation accumulators that compute aggregation functions without collecting the group rows
+    pub fn with_aggregation_accumulators(
+        &mut self,
+        accumulators: &HashMap<&'static str, AggregationAccumulatorFactory>,
+    ) {
+        self.aggregation_accumulators
+            .extend(accumulators.to_owned());
+    }
+
     /// Register Window functions signatures and references
     pub fn with_window_functions(
         &mut self,
@@ -95,11 +154,60 @@ impl Environment {
         self.window_functions.extend(window.to_owned());
     }
 
+    /// Register names of functions that can return different values for the same arguments,
+    /// the engine never evaluates them once to reuse the result like other functions
+    pub fn with_volatile_functions(&mut self, names: &[&'static str]) {
+        self.volatile_functions.extend(names);
+    }
+
     /// Register new Modified Types table
     pub fn with_types_table(&mut self, types_table: TypesTable) {
         self.types_table = types_table
     }
 
+    /// Enable or disable comparing Boolean values with `0` and `1` integers literals
+    pub fn with_boolean_integer_coercion(&mut self, is_enabled: bool) {
+        self.boolean_integer_coercion = is_enabled
+    }
+
+    /// Enable or disable replacing the failed cells evaluations with error values
+    pub fn with_lenient_evaluation(&mut self, is_enabled: bool) {
+        self.lenient_evaluation = is_enabled
+    }
+
+    /// Set the maximum number of subqueries that can be nested inside each other
+    pub fn with_max_subquery_depth(&mut self, max_depth: usize) {
+        self.max_subquery_depth = max_depth
+    }
+
+    /// Register keywords that can be used as column names, for example `order` or `group`
+    pub fn with_relaxed_keywords(&mut self, keywords: &[&str]) {
+        self.relaxed_keywords
+            .extend(keywords.iter().map(|keyword| keyword.to_lowercase()));
+    }
+
+    /// Return true if this keyword can be resolved as a column with the same name
+    pub fn is_relaxed_keyword_column(&self, keyword: &str) -> bool {
+        let name = keyword.to_lowercase();
+        self.relaxed_keywords.contains(&name)
+            && self.schema.tables_fields_types.contains_key(name.as_str())
+    }
+
+    /// Bind ordered values to the positional parameters `$1`, `$2`... of the next evaluated queries,
+    /// the engine clears them after evaluating the queries
+    pub fn with_parameters(&mut self, parameters: Vec<Box<dyn Value>>) {
+        self.parameters = parameters
+    }
+
+    /// Return the value bound to the positional parameter with one based index
+    #[allow(clippy::borrowed_box)]
+    pub fn parameter(&self, index: usize) -> Option<&Box<dyn Value>> {
+        if index == 0 {
+            return None;
+        }
+        self.parameters.get(index - 1)
+    }
+
     /// Return true if this name is a valid standard function
     pub fn is_std_function(&self, str: &str) -> bool {
         self.std_functions.contains_key(str)
@@ -110,6 +218,11 @@ impl Environment {
         self.std_signatures.get(str)
     }
 
+    /// Return true if this function can return different values for the same arguments
+    pub fn is_volatile_function(&self, str: &str) -> bool {
+        self.volatile_functions.contains(str)
+    }
+
     /// Return Standard function reference by name
     pub fn std_function(&self, str: &str) -> Option<&StandardFunction> {
         self.std_functions.get(str)
@@ -130,6 +243,17 @@ impl Environment {
         self.aggregation_functions.get(str)
     }
 
+    /// Return new Aggregation accumulator by name, aggregation function without registered accumulator
+    /// is wrapped by [`CollectedRowsAccumulator`]
+    pub fn aggregation_accumulator(&self, str: &str) -> Option<Box<dyn AggregationAccumulator>> {
+        if let Some(factory) = self.aggregation_accumulators.get(str) {
+            return Some(factory());
+        }
+
+        let function = self.aggregation_functions.get(str)?;
+        Some(Box::new(CollectedRowsAccumulator::new(*function)))
+    }
+
     /// Return true if this name is a valid Window function
     pub fn is_window_function(&self, str: &str) -> bool {
         self.window_functions.contains_key(str)
@@ -145,11 +269,46 @@ impl Environment {
         self.window_functions.get(str)
     }
 
+    /// Return sorted names of all registered Standard, Aggregation and Window functions
+    pub fn all_function_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .std_signatures
+            .keys()
+            .chain(self.aggregation_signatures.keys())
+            .chain(self.window_signatures.keys())
+            .map(|name| name.to_string())
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Return Standard, Aggregation or Window function signature by name
+    pub fn function_signature(&self, str: &str) -> Option<&Signature> {
+        self.std_signatures
+            .get(str)
+            .or_else(|| self.aggregation_signatures.get(str))
+            .or_else(|| self.window_signatures.get(str))
+    }
+
     /// Define in the current scope
     pub fn define(&mut self, str: String, data_type: Box<dyn DataType>) {
         self.scopes.insert(str, data_type);
     }
 
+    /// Start a new empty local scope, so the current definitions are not visible inside it
+    /// and its own definitions are removed by [`Environment::pop_scope`]
+    pub fn push_scope(&mut self) {
+        self.scopes_snapshots.push(std::mem::take(&mut self.scopes));
+    }
+
+    /// Restore the local scope that was active before the last [`Environment::push_scope`]
+    pub fn pop_scope(&mut self) {
+        if let Some(scopes) = self.scopes_snapshots.pop() {
+            self.scopes = scopes;
+        }
+    }
+
     /// Define in the global scope
     pub fn define_global(&mut self, str: String, data_type: Box<dyn DataType>) {
         self.globals_types.insert(str, data_type);
@@ -171,6 +330,10 @@ impl Environment {
 
     /// Clear all locals scopes and only save globals
     pub fn clear_session(&mut self) {
-        self.scopes.clear()
+        self.scopes.clear();
+        self.scopes_snapshots.clear();
+        self.hoisted_values.clear();
+        self.subqueries_values.clear();
+        self.warnings.clear();
     }
 }
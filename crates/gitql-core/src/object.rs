@@ -27,8 +27,11 @@ impl Group {
 /// In memory representation of the GitQL Object which has titles and groups
 #[derive(Default)]
 pub struct GitQLObject {
+    /// Ordered titles of the selected fields, with the alias name if the field has one
     pub titles: Vec<String>,
     pub groups: Vec<Group>,
+    /// Names of the optimizations applied by the engine while evaluating the query
+    pub applied_optimizations: Vec<String>,
 }
 
 impl GitQLObject {
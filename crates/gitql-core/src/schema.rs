@@ -6,29 +6,106 @@ use gitql_ast::types::DataType;
 ///
 /// [`tables_fields_names`]  is a map of tables and columns names
 ///
-/// # Examples
+/// [`tables_fields_types`] is a map of each column name in general with the expected data type
 ///
-/// ```
+/// [`tables_fields_descriptions`] is an optional map of each table and column name with a short description
+/// of what the column represents in this table, used by the `DESCRIBE` query
 ///
-/// pub static ref TABLES_FIELDS_NAMES: HashMap<&'static str, Vec<&'static str>> = {
-///    let mut map = HashMap::new();
-///    map.insert("refs", vec!["name", "full_name", "type", "repo"]);
-/// }
+/// # Examples
 ///
 /// ```
+/// use gitql_ast::types::integer::IntType;
+/// use gitql_ast::types::text::TextType;
+/// use gitql_core::schema::Schema;
 ///
-/// [`tables_fields_types`] is a map of each column name in general with the expected data type
-///
-/// # Examples
+/// let mut schema = Schema::default();
+/// schema
+///     .add_table("issues", &[("id", Box::new(IntType)), ("title", Box::new(TextType))])
+///     .unwrap();
 ///
-/// ```
-/// pub static ref TABLES_FIELDS_TYPES: HashMap<&'static str, Box<dyn DataType>> = {
-///    let mut map = HashMap::new();
-///    map.insert("commit_id", Box::new(TextType));
-/// }
+/// assert!(schema.tables_fields_names.contains_key("issues"));
+/// assert!(schema.tables_fields_types["title"].is_text());
 /// ```
 ///
+#[derive(Default)]
 pub struct Schema {
     pub tables_fields_names: HashMap<&'static str, Vec<&'static str>>,
     pub tables_fields_types: HashMap<&'static str, Box<dyn DataType>>,
+    pub tables_fields_descriptions: HashMap<(&'static str, &'static str), &'static str>,
+}
+
+impl Schema {
+    /// Create a new schema from the tables columns names and types without fields descriptions
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use gitql_ast::types::text::TextType;
+    /// use gitql_ast::types::DataType;
+    /// use gitql_core::schema::Schema;
+    ///
+    /// let names = HashMap::from([("issues", vec!["title"])]);
+    /// let types: HashMap<&'static str, Box<dyn DataType>> =
+    ///     HashMap::from([("title", Box::new(TextType) as Box<dyn DataType>)]);
+    /// let descriptions = HashMap::from([(("issues", "title"), "Issue title")]);
+    ///
+    /// let schema = Schema::new(names, types).with_tables_fields_descriptions(descriptions);
+    /// assert_eq!(schema.tables_fields_descriptions[&("issues", "title")], "Issue title");
+    /// ```
+    pub fn new(
+        tables_fields_names: HashMap<&'static str, Vec<&'static str>>,
+        tables_fields_types: HashMap<&'static str, Box<dyn DataType>>,
+    ) -> Self {
+        Schema {
+            tables_fields_names,
+            tables_fields_types,
+            tables_fields_descriptions: HashMap::default(),
+        }
+    }
+
+    /// Set the descriptions of the tables fields that are shown by the `DESCRIBE` query
+    pub fn with_tables_fields_descriptions(
+        mut self,
+        tables_fields_descriptions: HashMap<(&'static str, &'static str), &'static str>,
+    ) -> Self {
+        self.tables_fields_descriptions = tables_fields_descriptions;
+        self
+    }
+
+    /// Register a new table with its columns names and types
+    ///
+    /// Columns types are shared between all tables, so if a column with the same name
+    /// is already registered with a different type an error is returned and the schema is unchanged
+    pub fn add_table(
+        &mut self,
+        table_name: &'static str,
+        columns: &[(&'static str, Box<dyn DataType>)],
+    ) -> Result<(), String> {
+        if self.tables_fields_names.contains_key(table_name) {
+            return Err(format!("Table `{}` is already registered", table_name));
+        }
+
+        for (column_name, column_type) in columns {
+            if let Some(registered_type) = self.tables_fields_types.get(column_name) {
+                if !registered_type.equals(column_type) {
+                    return Err(format!(
+                        "Column `{}` is already registered with type `{}` but got `{}`",
+                        column_name,
+                        registered_type.literal(),
+                        column_type.literal()
+                    ));
+                }
+            }
+        }
+
+        let mut columns_names = Vec::with_capacity(columns.len());
+        for (column_name, column_type) in columns {
+            columns_names.push(*column_name);
+            self.tables_fields_types
+                .insert(column_name, column_type.clone());
+        }
+
+        self.tables_fields_names.insert(table_name, columns_names);
+        Ok(())
+    }
 }
@@ -18,6 +18,49 @@ pub type StandardFunction = fn(&[Box<dyn Value>]) -> Box<dyn Value>;
 ///
 pub type AggregationFunction = fn(&[Vec<Box<dyn Value>>]) -> Box<dyn Value>;
 
+/// Aggregation accumulator is updated with the arguments values of each row in group one by one
+/// and return the aggregated [`Value`] after visiting all rows
+///
+/// The engine evaluates all aggregations of a group in a single pass over its rows,
+/// so each aggregation keeps only its own state instead of the values of all rows
+pub trait AggregationAccumulator {
+    /// Update the accumulator state with the arguments values of one row
+    fn accumulate(&mut self, arguments: Vec<Box<dyn Value>>);
+
+    /// Return the aggregated value of all accumulated rows
+    fn finish(&mut self) -> Box<dyn Value>;
+}
+
+/// Create a new [`AggregationAccumulator`] with empty state for each group
+pub type AggregationAccumulatorFactory = fn() -> Box<dyn AggregationAccumulator>;
+
+/// Accumulator for [`AggregationFunction`] that has no [`AggregationAccumulator`],
+/// it collects the arguments values of all rows and call the function once at the end
+pub struct CollectedRowsAccumulator {
+    function: AggregationFunction,
+    rows: Vec<Vec<Box<dyn Value>>>,
+}
+
+impl CollectedRowsAccumulator {
+    /// Create Instance of [`CollectedRowsAccumulator`] for aggregation function
+    pub fn new(function: AggregationFunction) -> Self {
+        CollectedRowsAccumulator {
+            function,
+            rows: vec![],
+        }
+    }
+}
+
+impl AggregationAccumulator for CollectedRowsAccumulator {
+    fn accumulate(&mut self, arguments: Vec<Box<dyn Value>>) {
+        self.rows.push(arguments);
+    }
+
+    fn finish(&mut self) -> Box<dyn Value> {
+        (self.function)(&self.rows)
+    }
+}
+
 /// Window function  a selected row values for each row in a specific frame and return single [`Value`]
 ///
 /// [`Vec<Vec<Value>>`] represent the selected values from each row in frame of rows
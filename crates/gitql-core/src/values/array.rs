@@ -68,6 +68,7 @@ impl Value for ArrayValue {
                     return false;
                 }
             }
+            return true;
         }
         false
     }
@@ -152,6 +153,16 @@ impl Value for ArrayValue {
     }
 
     fn contains_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        // Array contains other Array if it has all of its elements regardless of the order,
+        // so the empty Array is contained by any Array
+        if let Some(other_array) = other.as_any().downcast_ref::<ArrayValue>() {
+            let is_contains = other_array
+                .values
+                .iter()
+                .all(|other_value| self.values.iter().any(|value| value.equals(other_value)));
+            return Ok(Box::new(BoolValue { value: is_contains }));
+        }
+
         for value in self.values.iter() {
             if value.equals(other) {
                 return Ok(Box::new(BoolValue { value: true }));
@@ -160,4 +171,108 @@ impl Value for ArrayValue {
 
         Ok(Box::new(BoolValue::new_false()))
     }
+
+    fn cast_op(&self, target_type: &Box<dyn DataType>) -> Result<Box<dyn Value>, String> {
+        // Cast to Array by casting each element to the target element type
+        if let Some(array_type) = target_type.as_any().downcast_ref::<ArrayType>() {
+            let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(self.values.len());
+            for element in self.values.iter() {
+                if element.is_null() {
+                    values.push(element.clone());
+                    continue;
+                }
+                values.push(element.cast_op(&array_type.base)?);
+            }
+            return Ok(Box::new(ArrayValue::new(values, array_type.base.clone())));
+        }
+
+        Err("Unexpected value to perform `CAST` with".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::types::float::FloatType;
+    use gitql_ast::types::integer::IntType;
+    use gitql_ast::types::text::TextType;
+
+    use super::*;
+    use crate::values::float::FloatValue;
+    use crate::values::null::NullValue;
+    use crate::values::text::TextValue;
+
+    #[test]
+    fn test_cast_int_array_to_float_array() {
+        let array: Box<dyn Value> = Box::new(ArrayValue::new(
+            vec![
+                Box::new(IntValue::new(1)),
+                Box::new(NullValue),
+                Box::new(IntValue::new(3)),
+            ],
+            Box::new(IntType),
+        ));
+
+        let target_type: Box<dyn DataType> = Box::new(ArrayType::new(Box::new(FloatType)));
+        let casted = array.cast_op(&target_type).ok().unwrap();
+        assert!(casted.data_type().equals(&target_type));
+        assert_eq!(casted.literal(), "[1, Null, 3]");
+
+        let casted_array = casted.as_any().downcast_ref::<ArrayValue>().unwrap();
+        assert_eq!(casted_array.values[0].as_float(), Some(1.0));
+        assert!(casted_array.values[1].is_null());
+
+        let scalar_type: Box<dyn DataType> = Box::new(FloatType);
+        assert!(array.cast_op(&scalar_type).is_err());
+    }
+
+    #[test]
+    fn test_array_contains_array() {
+        let text_array = |values: &[&str]| -> Box<dyn Value> {
+            let values = values
+                .iter()
+                .map(|value| Box::new(TextValue::new(value.to_string())) as Box<dyn Value>)
+                .collect();
+            Box::new(ArrayValue::new(values, Box::new(TextType)))
+        };
+
+        let tags = text_array(&["bug", "parser", "sql"]);
+        let cases = [
+            (text_array(&["sql", "bug"]), true),
+            (text_array(&["bug", "engine"]), false),
+            (text_array(&[]), true),
+        ];
+
+        for (other, expected) in cases {
+            let result = tags.contains_op(&other).ok().unwrap();
+            assert_eq!(result.as_bool(), Some(expected));
+        }
+
+        let empty = text_array(&[]);
+        let result = empty.contains_op(&tags).ok().unwrap();
+        assert_eq!(result.as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_array_equals() {
+        let int_array = |values: &[i64]| -> Box<dyn Value> {
+            let values = values
+                .iter()
+                .map(|value| Box::new(IntValue::new(*value)) as Box<dyn Value>)
+                .collect();
+            Box::new(ArrayValue::new(values, Box::new(IntType)))
+        };
+
+        let array = int_array(&[1, 2, 3]);
+        assert!(array.equals(&int_array(&[1, 2, 3])));
+        assert!(int_array(&[]).equals(&int_array(&[])));
+        assert!(!array.equals(&int_array(&[1, 2, 4])));
+        assert!(!array.equals(&int_array(&[1, 2])));
+
+        let float_array: Box<dyn Value> = Box::new(ArrayValue::new(
+            vec![Box::new(FloatValue::new(1.0))],
+            Box::new(FloatType),
+        ));
+        assert!(!int_array(&[1]).equals(&float_array));
+        assert!(!array.equals(&(Box::new(IntValue::new(1)) as Box<dyn Value>)));
+    }
 }
@@ -6,12 +6,14 @@ use dyn_clone::DynClone;
 use gitql_ast::operator::GroupComparisonOperator;
 use gitql_ast::types::DataType;
 use gitql_ast::Interval;
+use unicode_width::UnicodeWidthStr;
 
 use super::array::ArrayValue;
 use super::boolean::BoolValue;
 use super::composite::CompositeValue;
 use super::date::DateValue;
 use super::datetime::DateTimeValue;
+use super::error::ErrorValue;
 use super::float::FloatValue;
 use super::integer::IntValue;
 use super::interval::IntervalValue;
@@ -27,6 +29,12 @@ pub trait Value: DynClone {
     /// Return the literal representation for this [`Value`]
     fn literal(&self) -> String;
 
+    /// Return the number of terminal columns needed to display the literal of this [`Value`],
+    /// wide characters such as CJK are counted as two columns
+    fn display_width(&self) -> usize {
+        self.literal().width()
+    }
+
     /// Return if other [`Value`] is equal or not to current value
     #[allow(clippy::borrowed_box)]
     fn equals(&self, other: &Box<dyn Value>) -> bool;
@@ -39,6 +47,11 @@ pub trait Value: DynClone {
     /// Return the [`DataType`] for the current [`Value`]
     fn data_type(&self) -> Box<dyn DataType>;
 
+    /// Return the stable lowercase type tag of the current [`Value`] like `integer` or `array`
+    fn type_tag(&self) -> String {
+        self.data_type().type_tag()
+    }
+
     /// Return the current value as dynamic [`Any`]
     fn as_any(&self) -> &dyn Any;
 
@@ -475,6 +488,19 @@ impl dyn Value {
         None
     }
 
+    /// Return List of timestamps represent the inner values of [`ArrayValue`] of [`DateValue`]
+    /// or None if this type it's called from wrong [`Value`] or any element is not [`DateValue`]
+    pub fn as_array_of_dates(&self) -> Option<Vec<i64>> {
+        if let Some(array_value) = self.as_any().downcast_ref::<ArrayValue>() {
+            let mut timestamps = Vec::with_capacity(array_value.values.len());
+            for element in array_value.values.iter() {
+                timestamps.push(element.as_date()?);
+            }
+            return Some(timestamps);
+        }
+        None
+    }
+
     /// Return true if this value is [`RangeValue`]
     pub fn is_range(&self) -> bool {
         self.as_any().downcast_ref::<RangeValue>().is_some()
@@ -489,15 +515,86 @@ impl dyn Value {
         None
     }
 
+    /// Return the value of [`IntValue`] or [`FloatValue`] promoted to [`f64`],
+    /// so Int and Float values can be ordered together, or None for non numeric values
+    pub fn numeric_sort_key(&self) -> Option<f64> {
+        if let Some(int_value) = self.as_any().downcast_ref::<IntValue>() {
+            return Some(int_value.value as f64);
+        }
+
+        if let Some(float_value) = self.as_any().downcast_ref::<FloatValue>() {
+            return Some(float_value.value);
+        }
+
+        None
+    }
+
+    /// Return the literal of the value to be displayed, [`FloatValue`] is rounded to at most
+    /// `float_precision` decimal places with trailing zeros trimmed, other values use the literal
+    pub fn display_literal(&self, float_precision: Option<usize>) -> String {
+        let (Some(precision), Some(float_value)) =
+            (float_precision, self.as_any().downcast_ref::<FloatValue>())
+        else {
+            return self.literal();
+        };
+
+        if !float_value.value.is_finite() {
+            return self.literal();
+        }
+
+        let mut literal = format!("{:.*}", precision, float_value.value);
+        if literal.contains('.') {
+            literal = literal
+                .trim_end_matches('0')
+                .trim_end_matches('.')
+                .to_string();
+        }
+
+        if literal == "-0" {
+            return "0".to_string();
+        }
+
+        literal
+    }
+
+    /// Return the number of terminal columns needed to display the [`Value::display_literal`]
+    pub fn display_literal_width(&self, float_precision: Option<usize>) -> usize {
+        if float_precision.is_some() && self.as_any().is::<FloatValue>() {
+            return self.display_literal(float_precision).width();
+        }
+        self.display_width()
+    }
+
     /// Return true if this value is [`NullValue`]
     pub fn is_null(&self) -> bool {
         self.as_any().downcast_ref::<NullValue>().is_some()
     }
 
+    /// Return true if this value is [`ErrorValue`]
+    pub fn is_error(&self) -> bool {
+        self.as_any().downcast_ref::<ErrorValue>().is_some()
+    }
+
+    /// Return the error message if this value is [`ErrorValue`]
+    pub fn as_error(&self) -> Option<String> {
+        if let Some(error_value) = self.as_any().downcast_ref::<ErrorValue>() {
+            return Some(error_value.message.to_string());
+        }
+        None
+    }
+
     /// Return true if this value is [`CompositeValue`]
     pub fn is_composite(&self) -> bool {
         self.as_any().downcast_ref::<CompositeValue>().is_some()
     }
+
+    /// Return a stable key that can be hashed to group equal values together
+    ///
+    /// The key contains the type name, so values from different types never share the same key,
+    /// and all [`NullValue`] share one key, similar to SQL `DISTINCT` and `GROUP BY` semantics
+    pub fn hash_key(&self) -> String {
+        format!("{}:{}", self.data_type().literal(), self.literal())
+    }
 }
 
 impl fmt::Display for Box<dyn Value> {
@@ -517,3 +614,56 @@ impl PartialOrd for Box<dyn Value> {
         self.compare(other)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::types::integer::IntType;
+    use gitql_ast::types::text::TextType;
+
+    use super::*;
+
+    #[test]
+    fn test_values_type_tags() {
+        let values: Vec<(Box<dyn Value>, &str)> = vec![
+            (Box::new(IntValue::new(1)), "integer"),
+            (Box::new(FloatValue::new(1.5)), "float"),
+            (Box::new(TextValue::new("gql".to_string())), "text"),
+            (Box::new(BoolValue::new(true)), "boolean"),
+            (Box::new(DateValue::new(0)), "date"),
+            (Box::new(DateTimeValue::new(0)), "datetime"),
+            (Box::new(TimeValue::new("12:00:00".to_string())), "time"),
+            (
+                Box::new(IntervalValue::new(Interval::default())),
+                "interval",
+            ),
+            (
+                Box::new(ArrayValue::new(vec![], Box::new(TextType))),
+                "array",
+            ),
+            (
+                Box::new(RangeValue::new(
+                    Box::new(IntValue::new(1)),
+                    Box::new(IntValue::new(5)),
+                    Box::new(IntType),
+                )),
+                "range",
+            ),
+            (
+                Box::new(
+                    CompositeValue::empty("point".to_string())
+                        .add_member("x".to_string(), Box::new(IntValue::new(1))),
+                ),
+                "composite",
+            ),
+            (Box::new(NullValue), "null"),
+        ];
+
+        for (value, expected_tag) in values {
+            assert_eq!(value.type_tag(), expected_tag);
+            assert_eq!(value.data_type().type_tag(), expected_tag);
+        }
+
+        let error: Box<dyn Value> = Box::new(ErrorValue::new("failed".to_string()));
+        assert_eq!(error.type_tag(), "error");
+    }
+}
@@ -7,6 +7,7 @@ use gitql_ast::types::DataType;
 
 use super::base::Value;
 use super::integer::IntValue;
+use super::text::TextValue;
 
 #[derive(Clone)]
 pub struct BoolValue {
@@ -294,6 +295,32 @@ impl Value for BoolValue {
             return Ok(Box::new(IntValue { value }));
         }
 
+        // Cast to Text Type as lowercase `true` or `false`
+        if target_type.is_text() {
+            let value = if self.value { "true" } else { "false" };
+            return Ok(Box::new(TextValue::new(value.to_string())));
+        }
+
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::types::text::TextType;
+
+    use super::*;
+
+    #[test]
+    fn test_bool_cast_to_text() {
+        let text_type: Box<dyn DataType> = Box::new(TextType);
+        for (value, expected) in [(true, "true"), (false, "false")] {
+            let casted = BoolValue::new(value).cast_op(&text_type).ok().unwrap();
+            assert_eq!(casted.as_text(), Some(expected.to_string()));
+
+            let boolean_type: Box<dyn DataType> = Box::new(BoolType);
+            let restored = casted.cast_op(&boolean_type).ok().unwrap();
+            assert_eq!(restored.as_bool(), Some(value));
+        }
+    }
+}
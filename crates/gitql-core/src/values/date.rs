@@ -3,6 +3,7 @@ use std::cmp::Ordering;
 
 use super::base::Value;
 use super::boolean::BoolValue;
+use super::integer::IntValue;
 
 use chrono::DateTime;
 use gitql_ast::operator::GroupComparisonOperator;
@@ -22,6 +23,18 @@ impl DateValue {
     }
 }
 
+/// Add number of days to the timestamp, or return error if the result is not a valid date
+fn add_days_to_timestamp(timestamp: i64, days: i64) -> Result<i64, String> {
+    let result = days
+        .checked_mul(24 * 60 * 60)
+        .and_then(|days_to_timestamp| timestamp.checked_add(days_to_timestamp));
+
+    match result {
+        Some(timestamp) if DateTime::from_timestamp(timestamp, 0).is_some() => Ok(timestamp),
+        _ => Err("Date out of range".to_string()),
+    }
+}
+
 impl Value for DateValue {
     fn literal(&self) -> String {
         let datetime = DateTime::from_timestamp(self.timestamp, 0).unwrap();
@@ -52,17 +65,21 @@ impl Value for DateValue {
 
     fn add_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(days) = other.as_int() {
-            let days_to_timestamp = days * 24 * 60 * 60;
-            let timestamp = self.timestamp + days_to_timestamp;
+            let timestamp = add_days_to_timestamp(self.timestamp, days)?;
             return Ok(Box::new(DateValue::new(timestamp)));
         }
         Err("Unexpected type to perform `+` with".to_string())
     }
 
     fn sub_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
+        if let Some(other_date) = other.as_any().downcast_ref::<DateValue>() {
+            let days = (self.timestamp - other_date.timestamp) / (24 * 60 * 60);
+            return Ok(Box::new(IntValue::new(days)));
+        }
+
         if let Some(days) = other.as_int() {
-            let days_to_timestamp = days * 24 * 60 * 60;
-            let timestamp = self.timestamp - days_to_timestamp;
+            let days = days.checked_neg().ok_or("Date out of range")?;
+            let timestamp = add_days_to_timestamp(self.timestamp, days)?;
             return Ok(Box::new(DateValue::new(timestamp)));
         }
         Err("Unexpected type to perform `-` with".to_string())
@@ -83,23 +100,24 @@ impl Value for DateValue {
         group_op: &GroupComparisonOperator,
     ) -> Result<Box<dyn Value>, String> {
         if other.is_array_of(|element_type| element_type.is_date()) {
-            let elements = &other.as_array().unwrap();
-            let mut matches_count = 0;
-            for element in elements.iter() {
-                if self.timestamp == element.as_date().unwrap() {
-                    matches_count += 1;
-                    if GroupComparisonOperator::Any.eq(group_op) {
-                        break;
+            if let Some(elements) = other.as_array_of_dates() {
+                let mut matches_count = 0;
+                for element in elements.iter() {
+                    if self.timestamp == *element {
+                        matches_count += 1;
+                        if GroupComparisonOperator::Any.eq(group_op) {
+                            break;
+                        }
                     }
                 }
-            }
 
-            let result = match group_op {
-                GroupComparisonOperator::All => matches_count == elements.len(),
-                GroupComparisonOperator::Any => matches_count > 0,
-            };
+                let result = match group_op {
+                    GroupComparisonOperator::All => matches_count == elements.len(),
+                    GroupComparisonOperator::Any => matches_count > 0,
+                };
 
-            return Ok(Box::new(BoolValue::new(result)));
+                return Ok(Box::new(BoolValue::new(result)));
+            }
         }
         Err("Unexpected type to perform `=` with".to_string())
     }
@@ -119,23 +137,24 @@ impl Value for DateValue {
         group_op: &GroupComparisonOperator,
     ) -> Result<Box<dyn Value>, String> {
         if other.is_array_of(|element_type| element_type.is_date()) {
-            let elements = &other.as_array().unwrap();
-            let mut matches_count = 0;
-            for element in elements.iter() {
-                if self.timestamp != element.as_date().unwrap() {
-                    matches_count += 1;
-                    if GroupComparisonOperator::Any.eq(group_op) {
-                        break;
+            if let Some(elements) = other.as_array_of_dates() {
+                let mut matches_count = 0;
+                for element in elements.iter() {
+                    if self.timestamp != *element {
+                        matches_count += 1;
+                        if GroupComparisonOperator::Any.eq(group_op) {
+                            break;
+                        }
                     }
                 }
-            }
 
-            let result = match group_op {
-                GroupComparisonOperator::All => matches_count == elements.len(),
-                GroupComparisonOperator::Any => matches_count > 0,
-            };
+                let result = match group_op {
+                    GroupComparisonOperator::All => matches_count == elements.len(),
+                    GroupComparisonOperator::Any => matches_count > 0,
+                };
 
-            return Ok(Box::new(BoolValue::new(result)));
+                return Ok(Box::new(BoolValue::new(result)));
+            }
         }
         Err("Unexpected type to perform `!=` with".to_string())
     }
@@ -155,23 +174,24 @@ impl Value for DateValue {
         group_op: &GroupComparisonOperator,
     ) -> Result<Box<dyn Value>, String> {
         if other.is_array_of(|element_type| element_type.is_date()) {
-            let elements = &other.as_array().unwrap();
-            let mut matches_count = 0;
-            for element in elements.iter() {
-                if self.timestamp > element.as_date().unwrap() {
-                    matches_count += 1;
-                    if GroupComparisonOperator::Any.eq(group_op) {
-                        break;
+            if let Some(elements) = other.as_array_of_dates() {
+                let mut matches_count = 0;
+                for element in elements.iter() {
+                    if self.timestamp > *element {
+                        matches_count += 1;
+                        if GroupComparisonOperator::Any.eq(group_op) {
+                            break;
+                        }
                     }
                 }
-            }
 
-            let result = match group_op {
-                GroupComparisonOperator::All => matches_count == elements.len(),
-                GroupComparisonOperator::Any => matches_count > 0,
-            };
+                let result = match group_op {
+                    GroupComparisonOperator::All => matches_count == elements.len(),
+                    GroupComparisonOperator::Any => matches_count > 0,
+                };
 
-            return Ok(Box::new(BoolValue::new(result)));
+                return Ok(Box::new(BoolValue::new(result)));
+            }
         }
         Err("Unexpected type to perform `>` with".to_string())
     }
@@ -191,23 +211,24 @@ impl Value for DateValue {
         group_op: &GroupComparisonOperator,
     ) -> Result<Box<dyn Value>, String> {
         if other.is_array_of(|element_type| element_type.is_date()) {
-            let elements = &other.as_array().unwrap();
-            let mut matches_count = 0;
-            for element in elements.iter() {
-                if self.timestamp >= element.as_date().unwrap() {
-                    matches_count += 1;
-                    if GroupComparisonOperator::Any.eq(group_op) {
-                        break;
+            if let Some(elements) = other.as_array_of_dates() {
+                let mut matches_count = 0;
+                for element in elements.iter() {
+                    if self.timestamp >= *element {
+                        matches_count += 1;
+                        if GroupComparisonOperator::Any.eq(group_op) {
+                            break;
+                        }
                     }
                 }
-            }
 
-            let result = match group_op {
-                GroupComparisonOperator::All => matches_count == elements.len(),
-                GroupComparisonOperator::Any => matches_count > 0,
-            };
+                let result = match group_op {
+                    GroupComparisonOperator::All => matches_count == elements.len(),
+                    GroupComparisonOperator::Any => matches_count > 0,
+                };
 
-            return Ok(Box::new(BoolValue::new(result)));
+                return Ok(Box::new(BoolValue::new(result)));
+            }
         }
         Err("Unexpected type to perform `>=` with".to_string())
     }
@@ -227,23 +248,24 @@ impl Value for DateValue {
         group_op: &GroupComparisonOperator,
     ) -> Result<Box<dyn Value>, String> {
         if other.is_array_of(|element_type| element_type.is_date()) {
-            let elements = &other.as_array().unwrap();
-            let mut matches_count = 0;
-            for element in elements.iter() {
-                if self.timestamp < element.as_date().unwrap() {
-                    matches_count += 1;
-                    if GroupComparisonOperator::Any.eq(group_op) {
-                        break;
+            if let Some(elements) = other.as_array_of_dates() {
+                let mut matches_count = 0;
+                for element in elements.iter() {
+                    if self.timestamp < *element {
+                        matches_count += 1;
+                        if GroupComparisonOperator::Any.eq(group_op) {
+                            break;
+                        }
                     }
                 }
-            }
 
-            let result = match group_op {
-                GroupComparisonOperator::All => matches_count == elements.len(),
-                GroupComparisonOperator::Any => matches_count > 0,
-            };
+                let result = match group_op {
+                    GroupComparisonOperator::All => matches_count == elements.len(),
+                    GroupComparisonOperator::Any => matches_count > 0,
+                };
 
-            return Ok(Box::new(BoolValue::new(result)));
+                return Ok(Box::new(BoolValue::new(result)));
+            }
         }
         Err("Unexpected type to perform `<` with".to_string())
     }
@@ -263,24 +285,84 @@ impl Value for DateValue {
         group_op: &GroupComparisonOperator,
     ) -> Result<Box<dyn Value>, String> {
         if other.is_array_of(|element_type| element_type.is_date()) {
-            let elements = &other.as_array().unwrap();
-            let mut matches_count = 0;
-            for element in elements.iter() {
-                if self.timestamp < element.as_date().unwrap() {
-                    matches_count += 1;
-                    if GroupComparisonOperator::Any.eq(group_op) {
-                        break;
+            if let Some(elements) = other.as_array_of_dates() {
+                let mut matches_count = 0;
+                for element in elements.iter() {
+                    if self.timestamp <= *element {
+                        matches_count += 1;
+                        if GroupComparisonOperator::Any.eq(group_op) {
+                            break;
+                        }
                     }
                 }
-            }
 
-            let result = match group_op {
-                GroupComparisonOperator::All => matches_count == elements.len(),
-                GroupComparisonOperator::Any => matches_count > 0,
-            };
+                let result = match group_op {
+                    GroupComparisonOperator::All => matches_count == elements.len(),
+                    GroupComparisonOperator::Any => matches_count > 0,
+                };
 
-            return Ok(Box::new(BoolValue::new(result)));
+                return Ok(Box::new(BoolValue::new(result)));
+            }
         }
         Err("Unexpected type to perform `<=` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::types::date::DateType;
+
+    use super::*;
+    use crate::values::array::ArrayValue;
+
+    #[test]
+    fn test_group_op_with_malformed_array_element() {
+        let date: Box<dyn Value> = Box::new(DateValue::new(0));
+        let elements: Vec<Box<dyn Value>> =
+            vec![Box::new(DateValue::new(0)), Box::new(IntValue::new(1))];
+        let array: Box<dyn Value> = Box::new(ArrayValue::new(elements, Box::new(DateType)));
+        assert!(date
+            .group_eq_op(&array, &GroupComparisonOperator::Any)
+            .is_err());
+    }
+
+    #[test]
+    fn test_group_lte_op_with_equal_element() {
+        let date: Box<dyn Value> = Box::new(DateValue::new(86400));
+        let elements: Vec<Box<dyn Value>> =
+            vec![Box::new(DateValue::new(0)), Box::new(DateValue::new(86400))];
+        let array: Box<dyn Value> = Box::new(ArrayValue::new(elements, Box::new(DateType)));
+        let result = date
+            .group_lte_op(&array, &GroupComparisonOperator::Any)
+            .unwrap();
+        assert_eq!(result.as_bool(), Some(true));
+    }
+
+    #[test]
+    fn test_add_and_sub_days_out_of_range() {
+        let date: Box<dyn Value> = Box::new(DateValue::new(0));
+        let max_days: Box<dyn Value> = Box::new(IntValue::new(i64::MAX));
+        let min_days: Box<dyn Value> = Box::new(IntValue::new(i64::MIN));
+        assert_eq!(date.add_op(&max_days).err().unwrap(), "Date out of range");
+        assert_eq!(date.sub_op(&max_days).err().unwrap(), "Date out of range");
+        assert_eq!(date.sub_op(&min_days).err().unwrap(), "Date out of range");
+
+        let too_many_days: Box<dyn Value> = Box::new(IntValue::new(1_000_000_000));
+        assert_eq!(
+            date.add_op(&too_many_days).err().unwrap(),
+            "Date out of range"
+        );
+
+        let one_day: Box<dyn Value> = Box::new(IntValue::new(1));
+        assert_eq!(date.add_op(&one_day).unwrap().literal(), "1970-01-02");
+        assert_eq!(date.sub_op(&one_day).unwrap().literal(), "1969-12-31");
+    }
+
+    #[test]
+    fn test_sub_date_from_date() {
+        let start: Box<dyn Value> = Box::new(DateValue::new(0));
+        let end: Box<dyn Value> = Box::new(DateValue::new(10 * 86400));
+        assert_eq!(end.sub_op(&start).unwrap().as_int(), Some(10));
+        assert_eq!(start.sub_op(&end).unwrap().as_int(), Some(-10));
+    }
+}
@@ -0,0 +1,46 @@
+use std::any::Any;
+use std::cmp::Ordering;
+
+use gitql_ast::types::undefined::UndefType;
+use gitql_ast::types::DataType;
+
+use super::base::Value;
+
+/// Sentinel value that replaces the value of a cell that failed to evaluate
+/// when the engine runs in lenient mode, it keeps the evaluation error message
+#[derive(Clone)]
+pub struct ErrorValue {
+    pub message: String,
+}
+
+impl ErrorValue {
+    pub fn new(message: String) -> Self {
+        ErrorValue { message }
+    }
+}
+
+impl Value for ErrorValue {
+    fn literal(&self) -> String {
+        format!("Error({})", self.message)
+    }
+
+    fn equals(&self, _other: &Box<dyn Value>) -> bool {
+        false
+    }
+
+    fn compare(&self, _other: &Box<dyn Value>) -> Option<Ordering> {
+        None
+    }
+
+    fn data_type(&self) -> Box<dyn DataType> {
+        Box::new(UndefType)
+    }
+
+    fn type_tag(&self) -> String {
+        "error".to_string()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -88,6 +88,13 @@ impl Value for FloatValue {
             let value = self.value == other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        // Compare with Integer by promoting the Integer value to Float
+        if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
+            let value = self.value == other_int.value as f64;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `=` with".to_string())
     }
 
@@ -123,6 +130,13 @@ impl Value for FloatValue {
             let value = self.value != other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        // Compare with Integer by promoting the Integer value to Float
+        if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
+            let value = self.value != other_int.value as f64;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `!=` with".to_string())
     }
 
@@ -303,3 +317,34 @@ impl Value for FloatValue {
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn float(value: f64) -> Box<dyn Value> {
+        Box::new(FloatValue::new(value))
+    }
+
+    #[test]
+    fn test_float_display_literal_with_precision() {
+        assert_eq!(float(12.34567).display_literal(Some(2)), "12.35");
+        assert_eq!(float(2.5).display_literal(Some(2)), "2.5");
+        assert_eq!(float(2.0).display_literal(Some(2)), "2");
+        assert_eq!(float(-0.001).display_literal(Some(2)), "0");
+        assert_eq!(float(1.005).display_literal(Some(0)), "1");
+        assert_eq!(float(12.34567).display_literal(None), "12.34567");
+
+        let int: Box<dyn Value> = Box::new(IntValue::new(10));
+        assert_eq!(int.display_literal(Some(2)), "10");
+    }
+
+    #[test]
+    fn test_float_display_literal_width_with_precision() {
+        assert_eq!(float(12.34567).display_literal_width(Some(2)), 5);
+        assert_eq!(float(12.34567).display_literal_width(None), 8);
+
+        let int: Box<dyn Value> = Box::new(IntValue::new(-10));
+        assert_eq!(int.display_literal_width(Some(2)), 3);
+    }
+}
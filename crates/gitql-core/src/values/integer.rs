@@ -29,6 +29,12 @@ impl Value for IntValue {
         self.value.to_string()
     }
 
+    fn display_width(&self) -> usize {
+        let sign_width = if self.value < 0 { 1 } else { 0 };
+        let digits_width = self.value.unsigned_abs().checked_ilog10().unwrap_or(0) as usize + 1;
+        sign_width + digits_width
+    }
+
     fn equals(&self, other: &Box<dyn Value>) -> bool {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
             return self.value == other_int.value;
@@ -88,7 +94,11 @@ impl Value for IntValue {
 
     fn rem_op(&self, other: &Box<dyn Value>) -> Result<Box<dyn Value>, String> {
         if let Some(other_int) = other.as_any().downcast_ref::<IntValue>() {
-            let value = self.value % other_int.value;
+            if other_int.value == 0 {
+                return Err("Can't perform `%` operator with 0 value".to_string());
+            }
+            // Same as SQL the result has the sign of the left hand side, for example `-7 % 3 = -1`
+            let value = self.value.wrapping_rem(other_int.value);
             return Ok(Box::new(IntValue::new(value)));
         }
         Err("Unexpected type to perform `%` with".to_string())
@@ -150,6 +160,13 @@ impl Value for IntValue {
             let value = self.value == other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        // Compare with Float by promoting the Integer value to Float
+        if let Some(other_float) = other.as_any().downcast_ref::<FloatValue>() {
+            let value = self.value as f64 == other_float.value;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `=` with".to_string())
     }
 
@@ -185,6 +202,13 @@ impl Value for IntValue {
             let value = self.value != other_bool.value;
             return Ok(Box::new(BoolValue::new(value)));
         }
+
+        // Compare with Float by promoting the Integer value to Float
+        if let Some(other_float) = other.as_any().downcast_ref::<FloatValue>() {
+            let value = self.value as f64 != other_float.value;
+            return Ok(Box::new(BoolValue::new(value)));
+        }
+
         Err("Unexpected type to perform `!=` with".to_string())
     }
 
@@ -375,3 +399,59 @@ impl Value for IntValue {
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_remainder_sign() {
+        let remainder = |lhs: i64, rhs: i64| -> Option<i64> {
+            let lhs: Box<dyn Value> = Box::new(IntValue::new(lhs));
+            let rhs: Box<dyn Value> = Box::new(IntValue::new(rhs));
+            lhs.rem_op(&rhs).ok()?.as_int()
+        };
+
+        assert_eq!(remainder(7, 3), Some(1));
+        assert_eq!(remainder(-7, 3), Some(-1));
+        assert_eq!(remainder(7, -3), Some(1));
+        assert_eq!(remainder(-7, -3), Some(-1));
+        assert_eq!(remainder(i64::MIN, -1), Some(0));
+        assert_eq!(remainder(7, 0), None);
+    }
+
+    #[test]
+    fn test_int_equality_with_float() {
+        let int_value: Box<dyn Value> = Box::new(IntValue::new(3));
+        let equal_float: Box<dyn Value> = Box::new(FloatValue::new(3.0));
+        let other_float: Box<dyn Value> = Box::new(FloatValue::new(3.1));
+
+        assert_eq!(int_value.eq_op(&equal_float).unwrap().as_bool(), Some(true));
+        assert_eq!(
+            int_value.eq_op(&other_float).unwrap().as_bool(),
+            Some(false)
+        );
+        assert_eq!(
+            int_value.bang_eq_op(&other_float).unwrap().as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            int_value.bang_eq_op(&equal_float).unwrap().as_bool(),
+            Some(false)
+        );
+
+        assert_eq!(equal_float.eq_op(&int_value).unwrap().as_bool(), Some(true));
+        assert_eq!(
+            other_float.bang_eq_op(&int_value).unwrap().as_bool(),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_int_display_width() {
+        for value in [0, 7, 10, -10, 12345, i64::MIN, i64::MAX] {
+            let int_value: Box<dyn Value> = Box::new(IntValue::new(value));
+            assert_eq!(int_value.display_width(), value.to_string().len());
+        }
+    }
+}
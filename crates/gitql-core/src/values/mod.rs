@@ -4,11 +4,13 @@ pub mod composite;
 pub mod converters;
 pub mod date;
 pub mod datetime;
+pub mod error;
 pub mod float;
 pub mod integer;
 pub mod interval;
 pub mod null;
 pub mod range;
+pub mod serialization;
 pub mod text;
 pub mod time;
 
@@ -6,7 +6,9 @@ use gitql_ast::types::DataType;
 
 use super::base::Value;
 
-#[derive(Clone)]
+/// NULL value is a zero sized unit type, so `Box::new(NullValue)` is cheap
+/// and never allocate memory even when it's created for each NULL in a large scan
+#[derive(Clone, Copy, Default)]
 pub struct NullValue;
 
 impl Value for NullValue {
@@ -0,0 +1,396 @@
+use std::collections::HashMap;
+
+use gitql_ast::types::any::AnyType;
+use gitql_ast::types::array::ArrayType;
+use gitql_ast::types::boolean::BoolType;
+use gitql_ast::types::composite::CompositeType;
+use gitql_ast::types::date::DateType;
+use gitql_ast::types::datetime::DateTimeType;
+use gitql_ast::types::float::FloatType;
+use gitql_ast::types::integer::IntType;
+use gitql_ast::types::interval::IntervalType;
+use gitql_ast::types::null::NullType;
+use gitql_ast::types::range::RangeType;
+use gitql_ast::types::text::TextType;
+use gitql_ast::types::time::TimeType;
+use gitql_ast::types::DataType;
+use gitql_ast::Interval;
+use indexmap::IndexMap;
+
+use super::array::ArrayValue;
+use super::base::Value;
+use super::boolean::BoolValue;
+use super::composite::CompositeValue;
+use super::date::DateValue;
+use super::datetime::DateTimeValue;
+use super::float::FloatValue;
+use super::integer::IntValue;
+use super::interval::IntervalValue;
+use super::null::NullValue;
+use super::range::RangeValue;
+use super::text::TextValue;
+use super::time::TimeValue;
+
+const NULL_TAG: u8 = 0;
+const BOOL_TAG: u8 = 1;
+const INT_TAG: u8 = 2;
+const FLOAT_TAG: u8 = 3;
+const TEXT_TAG: u8 = 4;
+const DATE_TAG: u8 = 5;
+const DATE_TIME_TAG: u8 = 6;
+const TIME_TAG: u8 = 7;
+const INTERVAL_TAG: u8 = 8;
+const ARRAY_TAG: u8 = 9;
+const RANGE_TAG: u8 = 10;
+const COMPOSITE_TAG: u8 = 11;
+const ANY_TAG: u8 = 12;
+
+/// Maximum nesting of arrays, ranges and composites values or types to read,
+/// so malformed or malicious bytes can't overflow the stack
+const MAX_NESTING_DEPTH: usize = 64;
+
+impl dyn Value {
+    /// Serialize this [`Value`] into a compact binary representation
+    /// that can be restored later using [`Value::from_bytes`]
+    ///
+    /// Numbers are encoded as little endian, Texts are encoded as UTF-8 prefixed by the length,
+    /// Returns Exception message as [`String`] if the value or its element type is not a builtin one
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let mut bytes = vec![];
+        write_value(&mut bytes, self)?;
+        Ok(bytes)
+    }
+
+    /// Deserialize a [`Value`] from the binary representation created by [`Value::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Box<dyn Value>, String> {
+        let mut reader = BytesReader { bytes, position: 0 };
+        let value = read_value(&mut reader, 0)?;
+        if reader.position != bytes.len() {
+            return Err("Unexpected trailing bytes after the serialized value".to_string());
+        }
+        Ok(value)
+    }
+}
+
+fn write_value(bytes: &mut Vec<u8>, value: &dyn Value) -> Result<(), String> {
+    let any = value.as_any();
+    if any.downcast_ref::<NullValue>().is_some() {
+        bytes.push(NULL_TAG);
+    } else if let Some(bool_value) = any.downcast_ref::<BoolValue>() {
+        bytes.push(BOOL_TAG);
+        bytes.push(bool_value.value as u8);
+    } else if let Some(int_value) = any.downcast_ref::<IntValue>() {
+        bytes.push(INT_TAG);
+        bytes.extend_from_slice(&int_value.value.to_le_bytes());
+    } else if let Some(float_value) = any.downcast_ref::<FloatValue>() {
+        bytes.push(FLOAT_TAG);
+        bytes.extend_from_slice(&float_value.value.to_le_bytes());
+    } else if let Some(text_value) = any.downcast_ref::<TextValue>() {
+        bytes.push(TEXT_TAG);
+        write_string(bytes, &text_value.value);
+    } else if let Some(date_value) = any.downcast_ref::<DateValue>() {
+        bytes.push(DATE_TAG);
+        bytes.extend_from_slice(&date_value.timestamp.to_le_bytes());
+    } else if let Some(date_time_value) = any.downcast_ref::<DateTimeValue>() {
+        bytes.push(DATE_TIME_TAG);
+        bytes.extend_from_slice(&date_time_value.value.to_le_bytes());
+    } else if let Some(time_value) = any.downcast_ref::<TimeValue>() {
+        bytes.push(TIME_TAG);
+        write_string(bytes, &time_value.value);
+    } else if let Some(interval_value) = any.downcast_ref::<IntervalValue>() {
+        let interval = &interval_value.interval;
+        bytes.push(INTERVAL_TAG);
+        bytes.extend_from_slice(&interval.years.to_le_bytes());
+        bytes.extend_from_slice(&interval.months.to_le_bytes());
+        bytes.extend_from_slice(&interval.days.to_le_bytes());
+        bytes.extend_from_slice(&interval.hours.to_le_bytes());
+        bytes.extend_from_slice(&interval.minutes.to_le_bytes());
+        bytes.extend_from_slice(&interval.seconds.to_le_bytes());
+    } else if let Some(array_value) = any.downcast_ref::<ArrayValue>() {
+        bytes.push(ARRAY_TAG);
+        write_data_type(bytes, &array_value.base_type)?;
+        write_length(bytes, array_value.values.len());
+        for element in array_value.values.iter() {
+            write_value(bytes, element.as_ref())?;
+        }
+    } else if let Some(range_value) = any.downcast_ref::<RangeValue>() {
+        bytes.push(RANGE_TAG);
+        write_data_type(bytes, &range_value.base_type)?;
+        write_value(bytes, range_value.start.as_ref())?;
+        write_value(bytes, range_value.end.as_ref())?;
+    } else if let Some(composite_value) = any.downcast_ref::<CompositeValue>() {
+        bytes.push(COMPOSITE_TAG);
+        write_string(bytes, &composite_value.name);
+        write_length(bytes, composite_value.members.len());
+        for (name, member) in composite_value.members.iter() {
+            write_string(bytes, name);
+            write_value(bytes, member.as_ref())?;
+        }
+    } else {
+        return Err(format!(
+            "Can't serialize value with type `{}`",
+            value.data_type().literal()
+        ));
+    }
+    Ok(())
+}
+
+#[allow(clippy::borrowed_box)]
+fn write_data_type(bytes: &mut Vec<u8>, data_type: &Box<dyn DataType>) -> Result<(), String> {
+    if data_type.is_any() {
+        bytes.push(ANY_TAG);
+    } else if data_type.is_null() {
+        bytes.push(NULL_TAG);
+    } else if data_type.is_bool() {
+        bytes.push(BOOL_TAG);
+    } else if data_type.is_int() {
+        bytes.push(INT_TAG);
+    } else if data_type.is_float() {
+        bytes.push(FLOAT_TAG);
+    } else if data_type.is_text() {
+        bytes.push(TEXT_TAG);
+    } else if data_type.is_date() {
+        bytes.push(DATE_TAG);
+    } else if data_type.is_date_time() {
+        bytes.push(DATE_TIME_TAG);
+    } else if data_type.is_time() {
+        bytes.push(TIME_TAG);
+    } else if data_type.is_interval() {
+        bytes.push(INTERVAL_TAG);
+    } else if let Some(array_type) = data_type.as_any().downcast_ref::<ArrayType>() {
+        bytes.push(ARRAY_TAG);
+        write_data_type(bytes, &array_type.base)?;
+    } else if let Some(range_type) = data_type.as_any().downcast_ref::<RangeType>() {
+        bytes.push(RANGE_TAG);
+        write_data_type(bytes, &range_type.base)?;
+    } else if let Some(composite_type) = data_type.as_any().downcast_ref::<CompositeType>() {
+        bytes.push(COMPOSITE_TAG);
+        write_string(bytes, &composite_type.name);
+        write_length(bytes, composite_type.members.len());
+
+        // Members are stored in a HashMap, so sort them to make the output deterministic
+        let mut members: Vec<_> = composite_type.members.iter().collect();
+        members.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, member_type) in members {
+            write_string(bytes, name);
+            write_data_type(bytes, member_type)?;
+        }
+    } else {
+        return Err(format!(
+            "Can't serialize data type `{}`",
+            data_type.literal()
+        ));
+    }
+    Ok(())
+}
+
+fn write_length(bytes: &mut Vec<u8>, length: usize) {
+    bytes.extend_from_slice(&(length as u32).to_le_bytes());
+}
+
+fn write_string(bytes: &mut Vec<u8>, str: &str) {
+    write_length(bytes, str.len());
+    bytes.extend_from_slice(str.as_bytes());
+}
+
+struct BytesReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl BytesReader<'_> {
+    fn read_slice(&mut self, length: usize) -> Result<&[u8], String> {
+        let end = self.position.saturating_add(length);
+        if end > self.bytes.len() {
+            return Err("Unexpected end of the serialized value".to_string());
+        }
+
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    fn read_i64(&mut self) -> Result<i64, String> {
+        let slice = self.read_slice(8)?;
+        Ok(i64::from_le_bytes(slice.try_into().unwrap()))
+    }
+
+    fn read_f64(&mut self) -> Result<f64, String> {
+        let slice = self.read_slice(8)?;
+        Ok(f64::from_le_bytes(slice.try_into().unwrap()))
+    }
+
+    fn read_length(&mut self) -> Result<usize, String> {
+        let slice = self.read_slice(4)?;
+        Ok(u32::from_le_bytes(slice.try_into().unwrap()) as usize)
+    }
+
+    fn read_string(&mut self) -> Result<String, String> {
+        let length = self.read_length()?;
+        let slice = self.read_slice(length)?;
+        String::from_utf8(slice.to_vec()).map_err(|_| "Invalid UTF-8 serialized text".to_string())
+    }
+}
+
+fn read_value(reader: &mut BytesReader, depth: usize) -> Result<Box<dyn Value>, String> {
+    check_nesting_depth(depth)?;
+    let value: Box<dyn Value> = match reader.read_u8()? {
+        NULL_TAG => Box::new(NullValue),
+        BOOL_TAG => Box::new(BoolValue::new(reader.read_u8()? != 0)),
+        INT_TAG => Box::new(IntValue::new(reader.read_i64()?)),
+        FLOAT_TAG => Box::new(FloatValue::new(reader.read_f64()?)),
+        TEXT_TAG => Box::new(TextValue::new(reader.read_string()?)),
+        DATE_TAG => Box::new(DateValue::new(reader.read_i64()?)),
+        DATE_TIME_TAG => Box::new(DateTimeValue::new(reader.read_i64()?)),
+        TIME_TAG => Box::new(TimeValue::new(reader.read_string()?)),
+        INTERVAL_TAG => Box::new(IntervalValue::new(Interval {
+            years: reader.read_i64()?,
+            months: reader.read_i64()?,
+            days: reader.read_i64()?,
+            hours: reader.read_i64()?,
+            minutes: reader.read_i64()?,
+            seconds: reader.read_f64()?,
+        })),
+        ARRAY_TAG => {
+            let base_type = read_data_type(reader, depth + 1)?;
+            let length = reader.read_length()?;
+            let mut values = Vec::with_capacity(length.min(reader.bytes.len()));
+            for _ in 0..length {
+                values.push(read_value(reader, depth + 1)?);
+            }
+            Box::new(ArrayValue::new(values, base_type))
+        }
+        RANGE_TAG => {
+            let base_type = read_data_type(reader, depth + 1)?;
+            let start = read_value(reader, depth + 1)?;
+            let end = read_value(reader, depth + 1)?;
+            Box::new(RangeValue::new(start, end, base_type))
+        }
+        COMPOSITE_TAG => {
+            let name = reader.read_string()?;
+            let length = reader.read_length()?;
+            let mut members = IndexMap::new();
+            for _ in 0..length {
+                let member_name = reader.read_string()?;
+                members.insert(member_name, read_value(reader, depth + 1)?);
+            }
+            Box::new(CompositeValue::new(name, members))
+        }
+        tag => return Err(format!("Unknown serialized value tag `{}`", tag)),
+    };
+    Ok(value)
+}
+
+fn read_data_type(reader: &mut BytesReader, depth: usize) -> Result<Box<dyn DataType>, String> {
+    check_nesting_depth(depth)?;
+    let data_type: Box<dyn DataType> = match reader.read_u8()? {
+        ANY_TAG => Box::new(AnyType),
+        NULL_TAG => Box::new(NullType),
+        BOOL_TAG => Box::new(BoolType),
+        INT_TAG => Box::new(IntType),
+        FLOAT_TAG => Box::new(FloatType),
+        TEXT_TAG => Box::new(TextType),
+        DATE_TAG => Box::new(DateType),
+        DATE_TIME_TAG => Box::new(DateTimeType),
+        TIME_TAG => Box::new(TimeType),
+        INTERVAL_TAG => Box::new(IntervalType),
+        ARRAY_TAG => Box::new(ArrayType::new(read_data_type(reader, depth + 1)?)),
+        RANGE_TAG => Box::new(RangeType::new(read_data_type(reader, depth + 1)?)),
+        COMPOSITE_TAG => {
+            let name = reader.read_string()?;
+            let length = reader.read_length()?;
+            let mut members = HashMap::new();
+            for _ in 0..length {
+                let member_name = reader.read_string()?;
+                members.insert(member_name, read_data_type(reader, depth + 1)?);
+            }
+            Box::new(CompositeType::new(name, members))
+        }
+        tag => return Err(format!("Unknown serialized data type tag `{}`", tag)),
+    };
+    Ok(data_type)
+}
+
+fn check_nesting_depth(depth: usize) -> Result<(), String> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(format!(
+            "Serialized value is nested deeper than {} levels",
+            MAX_NESTING_DEPTH
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip(value: Box<dyn Value>) {
+        let bytes = value.to_bytes().ok().unwrap();
+        let restored = <dyn Value>::from_bytes(&bytes).ok().unwrap();
+        assert_eq!(restored.literal(), value.literal());
+        assert!(restored.data_type().equals(&value.data_type()));
+        assert!(restored.equals(&value) || value.is_null());
+    }
+
+    #[test]
+    fn test_value_bytes_round_trip() {
+        assert_round_trip(Box::new(NullValue));
+        assert_round_trip(Box::new(BoolValue::new_true()));
+        assert_round_trip(Box::new(BoolValue::new_false()));
+        assert_round_trip(Box::new(IntValue::new(i64::MIN)));
+        assert_round_trip(Box::new(FloatValue::new(-1.5)));
+        assert_round_trip(Box::new(TextValue::new("héllo 世界".to_string())));
+        assert_round_trip(Box::new(DateValue::new(1_700_000_000)));
+        assert_round_trip(Box::new(DateTimeValue::new(1_700_000_123)));
+        assert_round_trip(Box::new(TimeValue::new("12:30:45".to_string())));
+        assert_round_trip(Box::new(IntervalValue::new(Interval {
+            years: 1,
+            months: 2,
+            days: 3,
+            hours: 4,
+            minutes: 5,
+            seconds: 6.5,
+        })));
+        assert_round_trip(Box::new(ArrayValue::new(
+            vec![Box::new(DateValue::new(0)), Box::new(NullValue)],
+            Box::new(DateType),
+        )));
+        assert_round_trip(Box::new(ArrayValue::empty(Box::new(ArrayType::new(
+            Box::new(IntType),
+        )))));
+        assert_round_trip(Box::new(RangeValue::new(
+            Box::new(IntValue::new(1)),
+            Box::new(IntValue::new(10)),
+            Box::new(IntType),
+        )));
+        assert_round_trip(Box::new(
+            CompositeValue::empty("point".to_string())
+                .add_member("x".to_string(), Box::new(IntValue::new(1)))
+                .add_member("y".to_string(), Box::new(FloatValue::new(2.0))),
+        ));
+    }
+
+    #[test]
+    fn test_value_from_invalid_bytes() {
+        assert!(<dyn Value>::from_bytes(&[]).is_err());
+        assert!(<dyn Value>::from_bytes(&[255]).is_err());
+        assert!(<dyn Value>::from_bytes(&[INT_TAG, 1, 2]).is_err());
+        assert!(<dyn Value>::from_bytes(&[NULL_TAG, NULL_TAG]).is_err());
+
+        let nested_type = vec![ARRAY_TAG; 100_000];
+        let error = <dyn Value>::from_bytes(&nested_type).err().unwrap();
+        assert_eq!(error, "Serialized value is nested deeper than 64 levels");
+
+        let mut nested_value = vec![];
+        for _ in 0..100_000 {
+            nested_value.extend_from_slice(&[RANGE_TAG, INT_TAG]);
+        }
+        let error = <dyn Value>::from_bytes(&nested_value).err().unwrap();
+        assert_eq!(error, "Serialized value is nested deeper than 64 levels");
+    }
+}
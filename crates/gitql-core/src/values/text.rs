@@ -4,6 +4,7 @@ use std::cmp::Ordering;
 use gitql_ast::operator::GroupComparisonOperator;
 use regex::Regex;
 use regex::RegexBuilder;
+use unicode_width::UnicodeWidthStr;
 
 use gitql_ast::types::text::TextType;
 use gitql_ast::types::DataType;
@@ -37,6 +38,15 @@ impl Value for TextValue {
         self.value.to_string()
     }
 
+    fn display_width(&self) -> usize {
+        // Multi lines text is displayed as lines on top of each other
+        self.value
+            .lines()
+            .map(|line| line.width())
+            .max()
+            .unwrap_or(0)
+    }
+
     fn equals(&self, other: &Box<dyn Value>) -> bool {
         if let Some(other_text) = other.as_any().downcast_ref::<TextValue>() {
             return self.value == other_text.value;
@@ -350,3 +360,23 @@ impl Value for TextValue {
         Err("Unexpected value to perform `CAST` with".to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_display_width() {
+        let ascii: Box<dyn Value> = Box::new(TextValue::new("commit".to_string()));
+        assert_eq!(ascii.display_width(), 6);
+
+        let multibyte: Box<dyn Value> = Box::new(TextValue::new("café".to_string()));
+        assert_eq!(multibyte.display_width(), 4);
+
+        let cjk: Box<dyn Value> = Box::new(TextValue::new("提交信息".to_string()));
+        assert_eq!(cjk.display_width(), 8);
+
+        let multiline: Box<dyn Value> = Box::new(TextValue::new("fix\nparser bug".to_string()));
+        assert_eq!(multiline.display_width(), 10);
+    }
+}
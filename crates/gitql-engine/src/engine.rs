@@ -1,17 +1,23 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::vec;
 
+use gitql_ast::statement::CompoundQuery;
 use gitql_ast::statement::DescribeStatement;
 use gitql_ast::statement::Distinct;
 use gitql_ast::statement::DoStatement;
 use gitql_ast::statement::GQLQuery;
 use gitql_ast::statement::GlobalVariableStatement;
+use gitql_ast::statement::LimitStatement;
+use gitql_ast::statement::OffsetStatement;
+use gitql_ast::statement::OrderByStatement;
 use gitql_ast::statement::Query;
 use gitql_ast::statement::SelectStatement;
 use gitql_core::environment::Environment;
 use gitql_core::object::GitQLObject;
 use gitql_core::object::Group;
 use gitql_core::object::Row;
+use gitql_core::values::integer::IntValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
@@ -20,9 +26,13 @@ use crate::engine_distinct::apply_distinct_operator;
 use crate::engine_evaluator::evaluate_expression;
 use crate::engine_executor::execute_global_variable_statement;
 use crate::engine_executor::execute_statement;
+use crate::engine_executor::resolve_actual_column_name;
+use crate::engine_ordering::execute_order_by_statement_with_limit;
+use crate::engine_set_operation::apply_set_operation;
+use crate::runtime_error::RuntimeError;
 
 /// Static Logical Plan, later must be replaced by optimized and Logical Planner
-const FIXED_LOGICAL_PLAN_LEN: usize = 9;
+const FIXED_LOGICAL_PLAN_LEN: usize = 10;
 const FIXED_LOGICAL_PLAN: [&str; FIXED_LOGICAL_PLAN_LEN] = [
     "select",
     "where",
@@ -30,6 +40,7 @@ const FIXED_LOGICAL_PLAN: [&str; FIXED_LOGICAL_PLAN_LEN] = [
     "aggregation",
     "having",
     "window_functions",
+    "distinct",
     "order",
     "offset",
     "limit",
@@ -47,7 +58,21 @@ pub fn evaluate(
     env: &mut Environment,
     data_provider: &Box<dyn DataProvider>,
     queries: Vec<Query>,
-) -> Result<Vec<EvaluationResult>, String> {
+) -> Result<Vec<EvaluationResult>, RuntimeError> {
+    env.warnings.clear();
+    let evaluations_results = evaluate_queries(env, data_provider, queries);
+
+    // Positional parameters are bound for the evaluated queries only, so they never leak to the next ones
+    env.parameters.clear();
+    evaluations_results
+}
+
+#[allow(clippy::borrowed_box)]
+fn evaluate_queries(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    queries: Vec<Query>,
+) -> Result<Vec<EvaluationResult>, RuntimeError> {
     let mut evaluations_results: Vec<EvaluationResult> = vec![];
     for query in queries {
         let evaluation_result = match query {
@@ -58,6 +83,9 @@ pub fn evaluate(
             }
             Query::Describe(describe_statement) => evaluate_describe_query(env, describe_statement),
             Query::ShowTables => evaluate_show_tables_query(env),
+            Query::Compound(compound_query) => {
+                evaluate_compound_query(env, data_provider, compound_query)
+            }
         }?;
         evaluations_results.push(evaluation_result);
     }
@@ -67,7 +95,7 @@ pub fn evaluate(
 fn evaluate_do_query(
     env: &mut Environment,
     do_statement: &DoStatement,
-) -> Result<EvaluationResult, String> {
+) -> Result<EvaluationResult, RuntimeError> {
     Ok(EvaluationResult::Do(evaluate_expression(
         env,
         &do_statement.expression,
@@ -81,7 +109,10 @@ fn evaluate_select_query(
     env: &mut Environment,
     data_provider: &Box<dyn DataProvider>,
     query: GQLQuery,
-) -> Result<EvaluationResult, String> {
+) -> Result<EvaluationResult, RuntimeError> {
+    let subqueries_values = evaluate_subqueries(env, data_provider, query.subqueries)?;
+    env.subqueries_values = subqueries_values;
+
     let mut gitql_object = GitQLObject::default();
     let mut alias_table: HashMap<String, String> = query.alias_table;
 
@@ -91,8 +122,40 @@ fn evaluate_select_query(
     let mut statements_map = query.statements;
     let has_group_by_statement = statements_map.contains_key("group");
 
+    // `LIMIT 0` is used to get only the result titles, so no need to ask the data provider for rows
+    let is_limit_zero = statements_map
+        .get("limit")
+        .and_then(|statement| statement.as_any().downcast_ref::<LimitStatement>())
+        .is_some_and(|limit_statement| limit_statement.count == 0);
+
+    // `ORDER BY` followed by `LIMIT` only need the first `offset + limit` ordered rows,
+    // percentage offset depends on the number of all rows so it can't be used with it
+    let order_by_limit = match statements_map
+        .get("offset")
+        .and_then(|statement| statement.as_any().downcast_ref::<OffsetStatement>())
+    {
+        Some(offset_statement) if offset_statement.is_percentage => None,
+        offset_statement => statements_map
+            .get("limit")
+            .and_then(|statement| statement.as_any().downcast_ref::<LimitStatement>())
+            .map(|limit_statement| {
+                limit_statement.count + offset_statement.map_or(0, |offset| offset.count)
+            }),
+    };
+
     let mut distinct: Option<Distinct> = None;
+    let mut replaced_fields: HashMap<String, String> = HashMap::new();
+    let mut row_number_fields: Vec<String> = vec![];
     for logical_node_name in FIXED_LOGICAL_PLAN {
+        // Distinct is an option of the select statement, executed after the aggregations
+        // and before the ordering, so `ORDER BY`, `OFFSET` and `LIMIT` work on unique rows
+        if logical_node_name == "distinct" {
+            if let Some(distinct) = &distinct {
+                apply_distinct_operator(distinct, &mut gitql_object, &hidden_selections);
+            }
+            continue;
+        }
+
         if let Some(statement) = statements_map.get_mut(logical_node_name) {
             match logical_node_name {
                 "select" => {
@@ -102,22 +165,66 @@ fn evaluate_select_query(
                         .downcast_ref::<SelectStatement>()
                         .unwrap();
 
+                    let empty_data_provider: Box<dyn DataProvider> = Box::new(EmptyDataProvider);
                     execute_statement(
                         env,
                         statement,
-                        data_provider,
+                        if is_limit_zero {
+                            &empty_data_provider
+                        } else {
+                            data_provider
+                        },
                         &mut gitql_object,
                         &mut alias_table,
                         &hidden_selections_map,
                         has_group_by_statement,
                     )?;
 
+                    if is_limit_zero {
+                        gitql_object
+                            .applied_optimizations
+                            .push("limit 0 pushdown".to_string());
+                    }
+
                     // If the main group is empty, no need to perform other statements
                     if gitql_object.is_empty() || gitql_object.groups[0].is_empty() {
+                        remove_hidden_selected_from_groups(
+                            &mut gitql_object.titles,
+                            &mut gitql_object.groups,
+                            &hidden_selections,
+                        );
+                        rename_replaced_fields(
+                            &mut gitql_object.titles,
+                            &select_statement.replaced_fields,
+                        );
                         return Ok(EvaluationResult::SelectedGroups(gitql_object));
                     }
 
                     distinct = Some(select_statement.distinct.to_owned());
+                    replaced_fields = select_statement.replaced_fields.to_owned();
+                    row_number_fields = select_statement.row_number_fields.to_owned();
+                }
+                "order" if order_by_limit.is_some() => {
+                    let order_by_statement = statement
+                        .as_any()
+                        .downcast_ref::<OrderByStatement>()
+                        .unwrap();
+
+                    if gitql_object.len() > 1 {
+                        gitql_object.flat();
+                    }
+
+                    execute_order_by_statement_with_limit(
+                        env,
+                        order_by_statement,
+                        &mut gitql_object,
+                        0,
+                        order_by_limit,
+                    )?;
+
+                    gitql_object
+                        .applied_optimizations
+                        .push("top-n order by".to_string());
                 }
                 _ => {
                     execute_statement(
@@ -134,11 +241,6 @@ fn evaluate_select_query(
         }
     }
 
-    // Apply the distinct operation after executing statements
-    if let Some(distinct) = distinct {
-        apply_distinct_operator(&distinct, &mut gitql_object, &hidden_selections);
-    }
-
     // Remove Hidden Selection from the rows after executing the query plan
     remove_hidden_selected_from_groups(
         &mut gitql_object.titles,
@@ -146,6 +248,9 @@ fn evaluate_select_query(
         &hidden_selections,
     );
 
+    // Rename the generated fields of `SELECT * REPLACE (...)` to the replaced columns names
+    rename_replaced_fields(&mut gitql_object.titles, &replaced_fields);
+
     let number_of_groups = gitql_object.groups.len();
     let main_group: &mut Group = &mut gitql_object.groups[0];
 
@@ -169,6 +274,9 @@ fn evaluate_select_query(
         main_group.rows.drain(1..);
     }
 
+    // `ROWNUM()` fields are assigned the final position of each row after ordering and limiting
+    apply_row_number_fields(&mut gitql_object, &row_number_fields, &alias_table);
+
     // Into statement must be executed last after flatted and remove hidden selections
     if let Some(into_statement) = statements_map.get_mut("into") {
         execute_statement(
@@ -187,10 +295,76 @@ fn evaluate_select_query(
     Ok(EvaluationResult::SelectedGroups(gitql_object))
 }
 
+/// Evaluate the uncorrelated subqueries once and collect the hash keys of their selected values,
+/// the subqueries are evaluated first because each one can override the values of its own subqueries
+#[allow(clippy::borrowed_box)]
+fn evaluate_subqueries(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    subqueries: Vec<GQLQuery>,
+) -> Result<Vec<HashSet<String>>, RuntimeError> {
+    let mut subqueries_values = Vec::with_capacity(subqueries.len());
+    for subquery in subqueries {
+        let EvaluationResult::SelectedGroups(gitql_object) =
+            evaluate_select_query(env, data_provider, subquery)?
+        else {
+            return Err("Subquery must select values".to_string().into());
+        };
+
+        let values: HashSet<String> = gitql_object
+            .groups
+            .iter()
+            .flat_map(|group| group.rows.iter())
+            .filter_map(|row| row.values.first())
+            .map(|value| value.hash_key())
+            .collect();
+        subqueries_values.push(values);
+    }
+    Ok(subqueries_values)
+}
+
+#[allow(clippy::borrowed_box)]
+fn evaluate_compound_query(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    query: CompoundQuery,
+) -> Result<EvaluationResult, RuntimeError> {
+    let left_result = evaluate_set_operation_query(env, data_provider, *query.left)?;
+    let right_result = evaluate_set_operation_query(env, data_provider, *query.right)?;
+
+    match (left_result, right_result) {
+        (EvaluationResult::SelectedGroups(left), EvaluationResult::SelectedGroups(right)) => Ok(
+            EvaluationResult::SelectedGroups(apply_set_operation(&query.operator, left, right)),
+        ),
+        _ => Err(
+            "Set operations can't be used with `SELECT ... INTO` queries"
+                .to_string()
+                .into(),
+        ),
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn evaluate_set_operation_query(
+    env: &mut Environment,
+    data_provider: &Box<dyn DataProvider>,
+    query: Query,
+) -> Result<EvaluationResult, RuntimeError> {
+    match query {
+        Query::Select(gql_query) => evaluate_select_query(env, data_provider, gql_query),
+        Query::Compound(compound_query) => {
+            evaluate_compound_query(env, data_provider, compound_query)
+        }
+        _ => Err("Set operations can be used only between `SELECT` queries"
+            .to_string()
+            .into()),
+    }
+}
+
 fn evaluate_global_declaration_query(
     env: &mut Environment,
     statement: &GlobalVariableStatement,
-) -> Result<EvaluationResult, String> {
+) -> Result<EvaluationResult, RuntimeError> {
     execute_global_variable_statement(env, statement)?;
     Ok(EvaluationResult::SetGlobalVariable)
 }
@@ -198,37 +372,50 @@ fn evaluate_global_declaration_query(
 fn evaluate_describe_query(
     env: &mut Environment,
     stmt: DescribeStatement,
-) -> Result<EvaluationResult, String> {
-    let table_fields = env
-        .schema
-        .tables_fields_names
-        .get(&stmt.table_name.as_str())
-        .unwrap();
+) -> Result<EvaluationResult, RuntimeError> {
+    let table_name = stmt.table_name.as_str();
+    let table_fields = env.schema.tables_fields_names.get(table_name).unwrap();
+
+    // Only show the description column if the schema describes at least one of the table fields
+    let descriptions = &env.schema.tables_fields_descriptions;
+    let has_descriptions = table_fields
+        .iter()
+        .any(|field| descriptions.contains_key(&(table_name, *field)));
 
     let mut gitql_object = GitQLObject::default();
     gitql_object.titles.push("Field".to_owned());
     gitql_object.titles.push("Type".to_owned());
+    if has_descriptions {
+        gitql_object.titles.push("Description".to_owned());
+    }
 
     let mut rows: Vec<Row> = Vec::with_capacity(table_fields.len());
     for field in table_fields {
         let value = env.schema.tables_fields_types.get(field).unwrap();
-        rows.push(Row {
-            values: vec![
-                Box::new(TextValue {
-                    value: field.to_owned().to_owned(),
-                }),
-                Box::new(TextValue {
-                    value: value.literal(),
-                }),
-            ],
-        })
+        let mut values: Vec<Box<dyn Value>> = vec![
+            Box::new(TextValue {
+                value: field.to_owned().to_owned(),
+            }),
+            Box::new(TextValue {
+                value: value.literal(),
+            }),
+        ];
+
+        if has_descriptions {
+            let description = descriptions.get(&(table_name, *field)).unwrap_or(&"");
+            values.push(Box::new(TextValue {
+                value: description.to_string(),
+            }));
+        }
+
+        rows.push(Row { values })
     }
 
     gitql_object.groups.push(Group { rows });
     Ok(EvaluationResult::SelectedGroups(gitql_object))
 }
 
-fn evaluate_show_tables_query(env: &mut Environment) -> Result<EvaluationResult, String> {
+fn evaluate_show_tables_query(env: &mut Environment) -> Result<EvaluationResult, RuntimeError> {
     let tables = env.schema.tables_fields_names.keys();
 
     let mut rows: Vec<Row> = Vec::with_capacity(tables.len());
@@ -247,6 +434,43 @@ fn evaluate_show_tables_query(env: &mut Environment) -> Result<EvaluationResult,
     Ok(EvaluationResult::SelectedGroups(gitql_object))
 }
 
+fn rename_replaced_fields(titles: &mut [String], replaced_fields: &HashMap<String, String>) {
+    for title in titles.iter_mut() {
+        if let Some(replaced_field) = replaced_fields.get(title) {
+            *title = replaced_field.to_string();
+        }
+    }
+}
+
+/// Assign the 1-based position of each row to the `ROWNUM()` fields
+fn apply_row_number_fields(
+    gitql_object: &mut GitQLObject,
+    row_number_fields: &[String],
+    alias_table: &HashMap<String, String>,
+) {
+    for field in row_number_fields {
+        let column_name = resolve_actual_column_name(alias_table, field);
+        let Some(column_index) = gitql_object.titles.iter().position(|t| t.eq(&column_name)) else {
+            continue;
+        };
+
+        for group in gitql_object.groups.iter_mut() {
+            for (index, row) in group.rows.iter_mut().enumerate() {
+                row.values[column_index] = Box::new(IntValue::new(index as i64 + 1));
+            }
+        }
+    }
+}
+
+/// Data provider that has no rows for any table, used when the query needs only the result titles
+struct EmptyDataProvider;
+
+impl DataProvider for EmptyDataProvider {
+    fn provide(&self, _table: &str, _selected_columns: &[String]) -> Result<Vec<Row>, String> {
+        Ok(vec![])
+    }
+}
+
 fn remove_hidden_selected_from_groups(
     titles: &mut Vec<String>,
     groups: &mut [Group],
@@ -269,3 +493,1587 @@ fn remove_hidden_selected_from_groups(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::Mutex;
+
+    use gitql_ast::types::array::ArrayType;
+    use gitql_ast::types::date::DateType;
+    use gitql_ast::types::float::FloatType;
+    use gitql_ast::types::integer::IntType;
+    use gitql_ast::types::text::TextType;
+    use gitql_ast::types::DataType;
+    use gitql_core::schema::Schema;
+    use gitql_core::signature::Signature;
+    use gitql_core::values::array::ArrayValue;
+    use gitql_core::values::date::DateValue;
+    use gitql_core::values::float::FloatValue;
+    use gitql_core::values::integer::IntValue;
+    use gitql_core::values::null::NullValue;
+    use gitql_parser::parser::parse_gql;
+    use gitql_parser::tokenizer::Tokenizer;
+    use gitql_std::aggregation::aggregation_accumulators;
+    use gitql_std::aggregation::aggregation_function_signatures;
+    use gitql_std::aggregation::aggregation_functions;
+    use gitql_std::standard::standard_function_signatures;
+    use gitql_std::standard::standard_functions;
+
+    use super::*;
+
+    /// Provide the rows of each table, the selected columns that are not in the table are NULL
+    #[derive(Default)]
+    struct TableRowsDataProvider {
+        tables: HashMap<&'static str, TableRows>,
+    }
+
+    struct TableRows {
+        columns: Vec<&'static str>,
+        rows: Vec<Vec<Box<dyn Value>>>,
+    }
+
+    impl TableRowsDataProvider {
+        fn with_table(
+            mut self,
+            table: &'static str,
+            columns: &[&'static str],
+            rows: Vec<Vec<Box<dyn Value>>>,
+        ) -> Self {
+            let columns = columns.to_vec();
+            self.tables.insert(table, TableRows { columns, rows });
+            self
+        }
+    }
+
+    impl DataProvider for TableRowsDataProvider {
+        fn provide(&self, table: &str, selected_columns: &[String]) -> Result<Vec<Row>, String> {
+            let Some(TableRows { columns, rows }) = self.tables.get(table) else {
+                return Ok(vec![]);
+            };
+
+            let mut selected_rows = Vec::with_capacity(rows.len());
+            for row in rows {
+                let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(selected_columns.len());
+                for column in selected_columns {
+                    match columns.iter().position(|name| name == column) {
+                        Some(index) => values.push(row[index].clone()),
+                        None => values.push(Box::new(NullValue)),
+                    }
+                }
+                selected_rows.push(Row { values });
+            }
+            Ok(selected_rows)
+        }
+    }
+
+    fn int(value: i64) -> Box<dyn Value> {
+        Box::new(IntValue::new(value))
+    }
+
+    fn text(value: &str) -> Box<dyn Value> {
+        Box::new(TextValue::new(value.to_string()))
+    }
+
+    fn null() -> Box<dyn Value> {
+        Box::new(NullValue)
+    }
+
+    fn authors_env() -> Environment {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("name", Box::new(TextType))];
+        assert!(schema.add_table("authors", &columns).is_ok());
+        Environment::new(schema)
+    }
+
+    fn authors_data_provider() -> Box<dyn DataProvider> {
+        let tags = |tags: [&str; 2]| -> Box<dyn Value> {
+            let tags = tags.into_iter().map(text).collect();
+            Box::new(ArrayValue::new(tags, Box::new(TextType)))
+        };
+
+        Box::new(TableRowsDataProvider::default().with_table(
+            "authors",
+            &["id", "name", "tags"],
+            vec![
+                vec![int(1), text("amr"), tags(["rust", "sql"])],
+                vec![int(2), text("sara"), tags(["go", "sql"])],
+            ],
+        ))
+    }
+
+    #[allow(clippy::borrowed_box)]
+    fn evaluate_query(
+        env: &mut Environment,
+        provider: &Box<dyn DataProvider>,
+        query: &str,
+    ) -> GitQLObject {
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, env).ok().unwrap();
+        let mut results = evaluate(env, provider, queries).unwrap();
+        match results.remove(0) {
+            EvaluationResult::SelectedGroups(gitql_object) => gitql_object,
+            _ => panic!("Expect selected groups"),
+        }
+    }
+
+    fn evaluate_authors_query(env: &mut Environment, query: &str) -> GitQLObject {
+        evaluate_query(env, &authors_data_provider(), query)
+    }
+
+    #[test]
+    fn test_describe_shows_field_descriptions() {
+        let mut env = authors_env();
+        env.schema
+            .tables_fields_descriptions
+            .insert(("authors", "name"), "Commit author name");
+        env.schema
+            .tables_fields_descriptions
+            .insert(("books", "id"), "Book id");
+
+        let gitql_object = evaluate_authors_query(&mut env, "DESCRIBE authors");
+        assert_eq!(
+            gitql_object.titles,
+            vec![
+                "Field".to_string(),
+                "Type".to_string(),
+                "Description".to_string()
+            ]
+        );
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows[0].values[2].literal(), "");
+        assert_eq!(rows[1].values[0].literal(), "name");
+        assert_eq!(rows[1].values[1].literal(), "Text");
+        assert_eq!(rows[1].values[2].literal(), "Commit author name");
+    }
+
+    #[test]
+    fn test_select_all_replace_column() {
+        let mut env = authors_env();
+        env.std_signatures.insert(
+            "upper",
+            Signature {
+                parameters: vec![Box::new(TextType)],
+                return_type: Box::new(TextType),
+            },
+        );
+        env.std_functions.insert("upper", |inputs| {
+            Box::new(TextValue::new(inputs[0].as_text().unwrap().to_uppercase()))
+        });
+
+        let query = "SELECT * REPLACE (UPPER(name) AS name) FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(
+            gitql_object.titles,
+            vec!["id".to_string(), "name".to_string()]
+        );
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].values[0].as_int(), Some(1));
+        assert_eq!(rows[0].values[1].as_text(), Some("AMR".to_string()));
+        assert_eq!(rows[1].values[1].as_text(), Some("SARA".to_string()));
+
+        let query = "SELECT *, UPPER(name) AS shout FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(
+            gitql_object.titles,
+            vec!["id".to_string(), "name".to_string(), "shout".to_string()]
+        );
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows[0].values[1].as_text(), Some("amr".to_string()));
+        assert_eq!(rows[0].values[2].as_text(), Some("AMR".to_string()));
+    }
+
+    #[test]
+    fn test_order_by_function_of_non_selected_column() {
+        let mut env = authors_env();
+        env.std_signatures.insert(
+            "len",
+            Signature {
+                parameters: vec![Box::new(TextType)],
+                return_type: Box::new(IntType),
+            },
+        );
+        env.std_functions.insert("len", |inputs| {
+            Box::new(IntValue::new(inputs[0].as_text().unwrap().len() as i64))
+        });
+
+        let query = "SELECT id FROM authors ORDER BY LEN(name) DESC";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.titles, vec!["id".to_string()]);
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].values.len(), 1);
+        assert_eq!(rows[0].values[0].as_int(), Some(2));
+        assert_eq!(rows[1].values[0].as_int(), Some(1));
+    }
+
+    #[test]
+    fn test_order_by_reuse_selected_expression() {
+        static LEN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut env = authors_env();
+        env.std_signatures.insert(
+            "counted_len",
+            Signature {
+                parameters: vec![Box::new(TextType)],
+                return_type: Box::new(IntType),
+            },
+        );
+        env.std_functions.insert("counted_len", |inputs| {
+            LEN_CALLS.fetch_add(1, Ordering::SeqCst);
+            Box::new(IntValue::new(inputs[0].as_text().unwrap().len() as i64))
+        });
+
+        let query =
+            "SELECT name, COUNTED_LEN(name) AS l FROM authors ORDER BY COUNTED_LEN(name) DESC";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(LEN_CALLS.load(Ordering::SeqCst), 2);
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows[0].values[0].literal(), "sara");
+        assert_eq!(rows[0].values[1].as_int(), Some(4));
+        assert_eq!(rows[1].values[0].literal(), "amr");
+        assert_eq!(rows[1].values[1].as_int(), Some(3));
+    }
+
+    #[test]
+    fn test_where_row_independent_call_evaluated_once() {
+        static PROBE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        let mut env = authors_env();
+        env.std_signatures.insert(
+            "probe",
+            Signature {
+                parameters: vec![Box::new(IntType)],
+                return_type: Box::new(IntType),
+            },
+        );
+        env.std_functions.insert("probe", |inputs| {
+            PROBE_CALLS.fetch_add(1, Ordering::SeqCst);
+            Box::new(IntValue::new(inputs[0].as_int().unwrap()))
+        });
+
+        let query = "SELECT name FROM authors WHERE id > PROBE(1)";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(PROBE_CALLS.load(Ordering::SeqCst), 1);
+        assert_eq!(gitql_object.groups[0].rows.len(), 1);
+        assert_eq!(gitql_object.groups[0].rows[0].values[0].literal(), "sara");
+        assert_eq!(
+            gitql_object.applied_optimizations,
+            vec!["hoisting row independent expressions".to_string()]
+        );
+
+        // Volatile functions must be evaluated for each row
+        PROBE_CALLS.store(0, Ordering::SeqCst);
+        env.with_volatile_functions(&["probe"]);
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(PROBE_CALLS.load(Ordering::SeqCst), 2);
+        assert_eq!(gitql_object.groups[0].rows.len(), 1);
+        assert!(gitql_object.applied_optimizations.is_empty());
+    }
+
+    #[test]
+    fn test_where_array_contains_and_contained_by() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("name", Box::new(TextType)),
+            ("tags", Box::new(ArrayType::new(Box::new(TextType)))),
+        ];
+        assert!(schema.add_table("authors", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let queries = [
+            ("SELECT name FROM authors WHERE tags @> ARRAY['sql']", 2),
+            (
+                "SELECT name FROM authors WHERE tags @> ARRAY['sql', 'rust']",
+                1,
+            ),
+            (
+                "SELECT name FROM authors WHERE tags @> ARRAY['rust', 'go']",
+                0,
+            ),
+            (
+                "SELECT name FROM authors WHERE tags <@ ARRAY['sql', 'go', 'c']",
+                1,
+            ),
+            ("SELECT name FROM authors WHERE ARRAY['sql'] <@ tags", 2),
+            ("SELECT name FROM authors WHERE ARRAY[] <@ tags", 2),
+        ];
+
+        for (query, expected_count) in queries {
+            let gitql_object = evaluate_authors_query(&mut env, query);
+            let rows_count = gitql_object.groups.first().map_or(0, |group| group.len());
+            assert_eq!(rows_count, expected_count, "{}", query);
+        }
+
+        let query = "SELECT name FROM authors WHERE tags @> ARRAY[1]";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_where_any_of_array_column() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("name", Box::new(TextType)),
+            ("tags", Box::new(ArrayType::new(Box::new(TextType)))),
+        ];
+        assert!(schema.add_table("authors", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT name FROM authors WHERE 'rust' = ANY(tags)";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].as_text(), Some("amr".to_string()));
+
+        let query = "SELECT name FROM authors WHERE NOT 'rust' = ANY(tags)";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].as_text(), Some("sara".to_string()));
+
+        let query = "SELECT id FROM authors WHERE 'sql' = ANY(tags)";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.groups[0].rows.len(), 2);
+
+        let query = "SELECT id FROM authors WHERE 'sql' = ALL(tags)";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert!(gitql_object.is_empty() || gitql_object.groups[0].rows.is_empty());
+
+        let tokens = Tokenizer::tokenize("SELECT id FROM authors WHERE 1 = ANY(tags)".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_aggregation_over_empty_window() {
+        let mut env = authors_env();
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+
+        let query = "SELECT name, COUNT(id) OVER () AS total FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(
+            gitql_object.titles,
+            vec!["name".to_string(), "total".to_string()]
+        );
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].values[0].as_text(), Some("amr".to_string()));
+        assert_eq!(rows[1].values[0].as_text(), Some("sara".to_string()));
+        for row in rows {
+            assert_eq!(row.values[1].as_int(), Some(2));
+        }
+    }
+
+    #[test]
+    fn test_cast_boolean_to_text_in_concat() {
+        let mut env = authors_env();
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+
+        let query =
+            "SELECT id, CONCAT(name, ' first: ', CAST(id = 1 AS Text)) AS flag FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(
+            rows[0].values[1].as_text(),
+            Some("amr first: true".to_string())
+        );
+        assert_eq!(
+            rows[1].values[1].as_text(),
+            Some("sara first: false".to_string())
+        );
+    }
+
+    #[test]
+    fn test_format_function_placeholders() {
+        let mut env = authors_env();
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+
+        let query = "SELECT FORMAT('%s has id %s', name, id) FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(
+            rows[0].values[0].as_text(),
+            Some("amr has id 1".to_string())
+        );
+        assert_eq!(
+            rows[1].values[0].as_text(),
+            Some("sara has id 2".to_string())
+        );
+
+        for query in [
+            "SELECT FORMAT('%s has id %s', name) FROM authors",
+            "SELECT FORMAT('%s', name, id) FROM authors",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+            assert!(diagnostic.message().starts_with("FORMAT has"));
+        }
+
+        // Format that is known only at runtime is checked while evaluating it
+        for (query, message) in [
+            (
+                "SELECT FORMAT(name, id) FROM authors",
+                "FORMAT has 0 `%s` placeholders but got 1 arguments",
+            ),
+            (
+                "SELECT FORMAT(name || ' %s %s', id) FROM authors",
+                "FORMAT has 2 `%s` placeholders but got 1 arguments",
+            ),
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            let error = evaluate(&mut env, &authors_data_provider(), queries)
+                .err()
+                .unwrap();
+            assert_eq!(error.message, message);
+        }
+    }
+
+    #[test]
+    fn test_like_match_function_pattern_shape() {
+        let mut env = authors_env();
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+
+        let query = "SELECT LIKE_MATCH(name, 'S%a') FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert!(rows[0].values[0].is_null());
+        assert_eq!(rows[1].values[0].as_text(), Some("ar".to_string()));
+
+        for query in [
+            "SELECT LIKE_MATCH(name, 'sara') FROM authors",
+            "SELECT LIKE_MATCH(name, '%ar%') FROM authors",
+            "SELECT LIKE_MATCH(name, 's_r%') FROM authors",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+            assert!(diagnostic.message().starts_with("LIKE_MATCH pattern"));
+        }
+    }
+
+    #[test]
+    fn test_date_part_and_extract_agree() {
+        let mut env = authors_env();
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+
+        let query = "SELECT id, \
+            DATE_PART('year', MAKEDATE(2024, 75)), EXTRACT(year FROM MAKEDATE(2024, 75)), \
+            DATE_PART('month', MAKEDATE(2024, 75)), EXTRACT(MONTH FROM MAKEDATE(2024, 75)), \
+            DATE_PART('doy', MAKEDATE(2024, 75)), EXTRACT(doy FROM MAKEDATE(2024, 75)) \
+            FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let values = &gitql_object.groups[0].rows[0].values;
+        assert_eq!(values[1].as_int(), Some(2024));
+        assert_eq!(values[3].as_int(), Some(3));
+        assert_eq!(values[5].as_int(), Some(75));
+        for index in [1, 3, 5] {
+            assert!(values[index].equals(&values[index + 1]));
+        }
+    }
+
+    #[test]
+    fn test_select_comparison_as_boolean_column() {
+        let mut env = authors_env();
+
+        let query = "SELECT id, id > 1 AS is_later FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(
+            gitql_object.titles,
+            vec!["id".to_string(), "is_later".to_string()]
+        );
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows[0].values[1].as_bool(), Some(false));
+        assert_eq!(rows[1].values[1].as_bool(), Some(true));
+        assert_eq!(rows[0].values[1].literal(), "false");
+        assert_eq!(rows[1].values[1].literal(), "true");
+    }
+
+    #[test]
+    fn test_where_like_any_and_all() {
+        let mut env = authors_env();
+
+        let query = "SELECT name FROM authors WHERE name LIKE ANY (ARRAY['%m%', 'x%'])";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "amr");
+
+        let query = "SELECT name FROM authors WHERE name LIKE ALL (ARRAY['%a%', 's%'])";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "sara");
+
+        let query = "SELECT name FROM authors WHERE name NOT LIKE ALL (ARRAY['%a%', 's%'])";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "amr");
+
+        let query = "SELECT name FROM authors WHERE name LIKE ANY (ARRAY[1, 2])";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_count_distinct_multiple_columns() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("author", Box::new(TextType)), ("day", Box::new(TextType))];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+
+        let query = "SELECT COUNT(author) AS total, COUNT(DISTINCT author) AS authors, \
+            COUNT(DISTINCT day) AS days, COUNT(DISTINCT author, day) AS pairs FROM commits";
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "commits",
+                &["author", "day"],
+                vec![
+                    vec![text("amr"), text("2024-01-01")],
+                    vec![text("amr"), text("2024-01-01")],
+                    vec![text("amr"), text("2024-01-02")],
+                    vec![text("sara"), text("2024-01-01")],
+                ],
+            ));
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        let values = &gitql_object.groups[0].rows[0].values;
+        let counts: Vec<Option<i64>> = values.iter().map(|value| value.as_int()).collect();
+        assert_eq!(counts, vec![Some(4), Some(2), Some(2), Some(3)]);
+
+        let query = "SELECT GROUP_CONCAT(DISTINCT author, day) FROM commits";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_group_by_dates_aggregations_difference() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] = [
+            ("author", Box::new(TextType)),
+            ("committed_date", Box::new(DateType)),
+        ];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+
+        let query = "SELECT author, MAX(committed_date) - MIN(committed_date) AS active_days \
+            FROM commits GROUP BY author ORDER BY author";
+        let date = |day: i64| -> Box<dyn Value> { Box::new(DateValue::new(day * 86400)) };
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "commits",
+                &["author", "committed_date"],
+                vec![
+                    vec![text("amr"), date(0)],
+                    vec![text("amr"), date(30)],
+                    vec![text("amr"), date(12)],
+                    vec![text("sara"), date(5)],
+                ],
+            ));
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].values[0].literal(), "amr");
+        assert_eq!(rows[0].values[1].as_int(), Some(30));
+        assert_eq!(rows[1].values[0].literal(), "sara");
+        assert_eq!(rows[1].values[1].as_int(), Some(0));
+    }
+
+    #[test]
+    fn test_comma_separated_tables_cross_join() {
+        let mut env = authors_env();
+        let books_columns: [(&'static str, Box<dyn DataType>); 2] = [
+            ("author_id", Box::new(IntType)),
+            ("title", Box::new(TextType)),
+        ];
+        assert!(env.schema.add_table("books", &books_columns).is_ok());
+        let provider: Box<dyn DataProvider> = Box::new(
+            TableRowsDataProvider::default()
+                .with_table(
+                    "authors",
+                    &["id", "name"],
+                    vec![vec![int(1), text("amr")], vec![int(2), text("sara")]],
+                )
+                .with_table(
+                    "books",
+                    &["author_id", "title"],
+                    vec![
+                        vec![int(1), text("rust")],
+                        vec![int(2), text("go")],
+                        vec![int(1), text("sql")],
+                    ],
+                ),
+        );
+
+        let mut evaluate_library_query = |query: &str| -> Vec<(String, String)> {
+            evaluate_query(&mut env, &provider, query).groups[0]
+                .rows
+                .iter()
+                .map(|row| (row.values[0].literal(), row.values[1].literal()))
+                .collect()
+        };
+
+        let rows = evaluate_library_query("SELECT name, title FROM authors, books");
+        assert_eq!(rows.len(), 6);
+
+        // Filtering the implicit cross join by WHERE behaves like an inner join
+        let rows = evaluate_library_query(
+            "SELECT name, title FROM authors, books WHERE id = author_id ORDER BY title",
+        );
+        assert_eq!(
+            rows,
+            vec![
+                ("sara".to_string(), "go".to_string()),
+                ("amr".to_string(), "rust".to_string()),
+                ("amr".to_string(), "sql".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_where_computed_expression_is_null() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("insertions", Box::new(IntType)),
+            ("removals", Box::new(IntType)),
+        ];
+        assert!(schema.add_table("changes", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let queries = [
+            (
+                "SELECT id FROM changes WHERE (insertions + removals) IS NULL",
+                2,
+            ),
+            (
+                "SELECT id FROM changes WHERE insertions - removals IS NULL",
+                2,
+            ),
+            (
+                "SELECT id FROM changes WHERE insertions * removals IS NOT NULL",
+                1,
+            ),
+        ];
+
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "changes",
+                &["id", "insertions", "removals"],
+                vec![vec![int(1), int(10), int(5)], vec![int(2), int(10), null()]],
+            ));
+
+        for (query, expected_id) in queries {
+            let gitql_object = evaluate_query(&mut env, &provider, query);
+
+            let rows = &gitql_object.groups[0].rows;
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0].values[0].as_int(), Some(expected_id));
+        }
+    }
+
+    #[test]
+    fn test_count_all_rows_star_argument() {
+        let mut env = authors_env();
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+
+        let gitql_object = evaluate_authors_query(&mut env, "SELECT COUNT(*) FROM authors");
+        assert_eq!(gitql_object.groups[0].rows[0].values[0].as_int(), Some(2));
+
+        for query in [
+            "SELECT UPPER(*) FROM authors",
+            "SELECT SUM(*) FROM authors",
+            "SELECT COUNT(*, id) FROM authors",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+            assert!(diagnostic.message().starts_with("Unexpected `*` in"));
+            assert_eq!(
+                diagnostic.helps(),
+                &vec!["`*` can only be used as the only argument of `COUNT(*)`".to_string()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_where_case_guards_division_by_zero() {
+        let mut env = authors_env();
+
+        let query = "SELECT id FROM authors \
+            WHERE CASE WHEN id - 1 != 0 THEN 10 / (id - 1) ELSE 0 END = 0";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].as_int(), Some(1));
+    }
+
+    #[test]
+    fn test_runtime_error_location() {
+        let mut env = authors_env();
+        let provider = authors_data_provider();
+
+        let mut evaluate_error = |query: &str| {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            evaluate(&mut env, &provider, queries).err()
+        };
+
+        let query = "SELECT id FROM authors WHERE 10 / (id - 1) > 0";
+        let error = evaluate_error(query).unwrap();
+        assert_eq!(error.message, "Can't perform `/` operator with 0 value");
+        let location = error.location.unwrap();
+        assert_eq!(location.line_start, 1);
+        assert_eq!(location.column_start, query.find('/').unwrap() as u32);
+
+        let query = "SELECT id FROM authors WHERE name REGEXP '['";
+        let location = evaluate_error(query).unwrap().location.unwrap();
+        assert_eq!(location.column_start, query.find("REGEXP").unwrap() as u32);
+
+        // Failed evaluation of hoisted expression must not leave any location behind
+        let query = "SELECT id FROM authors WHERE CASE WHEN id > 5 THEN 10 / 0 ELSE 1 END = 1";
+        assert!(evaluate_error(query).is_none());
+    }
+
+    #[test]
+    fn test_limit_zero_returns_titles_without_providing_rows() {
+        struct UnreachableDataProvider;
+
+        impl DataProvider for UnreachableDataProvider {
+            fn provide(&self, _table: &str, _columns: &[String]) -> Result<Vec<Row>, String> {
+                panic!("Data provider must not be called with `LIMIT 0`")
+            }
+        }
+
+        let mut env = authors_env();
+
+        let query = "SELECT * FROM authors WHERE id > 1 LIMIT 0";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let provider: Box<dyn DataProvider> = Box::new(UnreachableDataProvider);
+        let mut results = evaluate(&mut env, &provider, queries).ok().unwrap();
+        let EvaluationResult::SelectedGroups(gitql_object) = results.remove(0) else {
+            panic!("Expect selected groups")
+        };
+
+        assert_eq!(
+            gitql_object.titles,
+            vec!["id".to_string(), "name".to_string()]
+        );
+        assert!(gitql_object.groups.iter().all(|group| group.is_empty()));
+        assert_eq!(
+            gitql_object.applied_optimizations,
+            vec!["limit 0 pushdown".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_order_by_with_limit_top_n() {
+        let mut env = authors_env();
+
+        let query = "SELECT name FROM authors ORDER BY id DESC LIMIT 1";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "sara");
+        assert_eq!(
+            gitql_object.applied_optimizations,
+            vec!["top-n order by".to_string()]
+        );
+
+        let query = "SELECT name FROM authors ORDER BY id DESC OFFSET 1 LIMIT 1";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "amr");
+
+        let query = "SELECT name FROM authors ORDER BY id DESC OFFSET 50 PERCENT LIMIT 1";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.groups[0].rows[0].values[0].literal(), "amr");
+        assert!(gitql_object.applied_optimizations.is_empty());
+    }
+
+    #[test]
+    fn test_having_constant_condition() {
+        let mut env = authors_env();
+
+        let query = "SELECT name FROM authors GROUP BY name HAVING 1 = 1";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.groups[0].rows.len(), 2);
+        assert_eq!(
+            gitql_object.applied_optimizations,
+            vec!["constant folding on having".to_string()]
+        );
+
+        let query = "SELECT name FROM authors GROUP BY name HAVING name = 'amr'";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert!(gitql_object.applied_optimizations.is_empty());
+
+        let query = "SELECT name FROM authors GROUP BY name HAVING FALSE";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert!(gitql_object.groups[0].rows.is_empty());
+    }
+
+    #[test]
+    fn test_where_modulo_sampling() {
+        let mut env = authors_env();
+
+        let query = "SELECT name FROM authors WHERE id % 2 = 0";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].as_text(), Some("sara".to_string()));
+    }
+
+    #[test]
+    fn test_distinct_executed_after_group_by_and_before_limit() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("author", Box::new(TextType)), ("day", Box::new(TextType))];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "commits",
+                &["author", "day"],
+                vec![
+                    vec![text("amr"), text("2024-01-01")],
+                    vec![text("amr"), text("2024-01-02")],
+                    vec![text("sara"), text("2024-01-01")],
+                    vec![text("amr"), text("2024-01-03")],
+                    vec![text("omar"), text("2024-01-02")],
+                ],
+            ));
+        let mut evaluate_commits_query = |query: &str| -> Vec<String> {
+            evaluate_query(&mut env, &provider, query).groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].literal())
+                .collect()
+        };
+
+        // Without group by, the limit is applied on the unique rows
+        let authors = evaluate_commits_query("SELECT DISTINCT author FROM commits LIMIT 2");
+        assert_eq!(authors, vec!["amr", "sara"]);
+
+        // With group by, the rows of the groups are compared after the aggregation
+        let counts = evaluate_commits_query(
+            "SELECT DISTINCT COUNT(day) AS days FROM commits GROUP BY author ORDER BY days",
+        );
+        assert_eq!(counts, vec!["1", "3"]);
+
+        let authors = evaluate_commits_query(
+            "SELECT DISTINCT author FROM commits GROUP BY author ORDER BY author DESC",
+        );
+        assert_eq!(authors, vec!["sara", "omar", "amr"]);
+    }
+
+    #[test]
+    fn test_selected_titles_use_aliases() {
+        let mut env = authors_env();
+
+        let query = "SELECT name AS author, id * 10 AS \"Scaled Id\", 'git' source \
+            FROM authors WHERE id > 0";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(
+            gitql_object.titles,
+            vec![
+                "author".to_string(),
+                "Scaled Id".to_string(),
+                "source".to_string()
+            ]
+        );
+        assert_eq!(gitql_object.groups[0].rows[0].values.len(), 3);
+    }
+
+    #[test]
+    fn test_grouped_aggregation_divided_by_window_total() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 1] = [("author", Box::new(TextType))];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+
+        let query = "SELECT author, COUNT(*) * 100.0 / SUM(COUNT(*)) OVER () AS pct \
+            FROM commits GROUP BY author ORDER BY author";
+        let authors = ["amr", "sara", "amr", "omar", "amr", "amr", "sara", "amr"];
+        let provider: Box<dyn DataProvider> = Box::new(
+            TableRowsDataProvider::default().with_table(
+                "commits",
+                &["author"],
+                authors
+                    .into_iter()
+                    .map(|author| vec![text(author)])
+                    .collect(),
+            ),
+        );
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        assert_eq!(gitql_object.titles, vec!["author", "pct"]);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 3);
+
+        let percentages: Vec<f64> = rows
+            .iter()
+            .map(|row| row.values[1].as_float().unwrap())
+            .collect();
+        assert_eq!(percentages, vec![62.5, 12.5, 25.0]);
+        assert!((percentages.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lenient_evaluation_keeps_failed_rows() {
+        let mut env = authors_env();
+
+        let query = "SELECT id, 10 / (id - 1) AS ratio FROM authors";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let provider = authors_data_provider();
+        assert!(evaluate(&mut env, &provider, queries).is_err());
+
+        env.with_lenient_evaluation(true);
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].values[1].is_error());
+        assert_eq!(
+            rows[0].values[1].as_error(),
+            Some("Can't perform `/` operator with 0 value".to_string())
+        );
+        assert_eq!(rows[1].values[1].as_int(), Some(10));
+
+        assert_eq!(env.warnings.len(), 1);
+        assert!(env.warnings[0].contains("row 1"));
+    }
+
+    #[test]
+    fn test_order_by_case_expression() {
+        let mut env = authors_env();
+
+        // The CASE columns are not selected so they must be hidden selections
+        let query = "SELECT id FROM authors ORDER BY CASE WHEN name = 'sara' THEN 0 ELSE 1 END, id";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.titles, vec!["id"]);
+
+        let ids: Vec<Option<i64>> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| row.values[0].as_int())
+            .collect();
+        assert_eq!(ids, vec![Some(2), Some(1)]);
+    }
+
+    #[test]
+    fn test_between_implicit_cast_of_bounds() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("ratio", Box::new(FloatType)),
+            ("day", Box::new(DateType)),
+        ];
+        assert!(schema.add_table("samples", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        // Days since epoch of 2024-01-10, 2024-02-10 and 2023-12-31
+        let date = |day: i64| -> Box<dyn Value> { Box::new(DateValue::new(day * 86400)) };
+        let ratio = |ratio: f64| -> Box<dyn Value> { Box::new(FloatValue::new(ratio)) };
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "samples",
+                &["id", "ratio", "day"],
+                vec![
+                    vec![int(1), ratio(0.5), date(19732)],
+                    vec![int(2), ratio(1.5), date(19763)],
+                    vec![int(3), ratio(-0.25), date(19722)],
+                ],
+            ));
+        let mut evaluate_samples_ids = |query: &str| -> Vec<Option<i64>> {
+            evaluate_query(&mut env, &provider, query).groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].as_int())
+                .collect()
+        };
+
+        // Integer bounds are casted to Float
+        let ids = evaluate_samples_ids("SELECT id FROM samples WHERE ratio BETWEEN 0 AND 1");
+        assert_eq!(ids, vec![Some(1)]);
+
+        // Integer value is casted to Float bounds
+        let ids = evaluate_samples_ids("SELECT id FROM samples WHERE id BETWEEN 1.5 AND 3");
+        assert_eq!(ids, vec![Some(2), Some(3)]);
+
+        // Text bounds are casted to Date
+        let ids = evaluate_samples_ids(
+            "SELECT id FROM samples WHERE day NOT BETWEEN '2024-01-01' AND '2024-01-31'",
+        );
+        assert_eq!(ids, vec![Some(2), Some(3)]);
+
+        let tokens = Tokenizer::tokenize(
+            "SELECT id FROM samples WHERE day BETWEEN 1 AND 'text'".to_string(),
+        )
+        .ok()
+        .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_row_number_field_after_order_and_limit() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 1] = [("id", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let provider: Box<dyn DataProvider> = Box::new(
+            TableRowsDataProvider::default().with_table(
+                "numbers",
+                &["id"],
+                [3, 1, 5, 2, 4]
+                    .into_iter()
+                    .map(|id| vec![int(id)])
+                    .collect(),
+            ),
+        );
+
+        let gitql_object = evaluate_query(
+            &mut env,
+            &provider,
+            "SELECT ROWNUM() AS position, id FROM numbers ORDER BY id DESC LIMIT 3",
+        );
+        assert_eq!(gitql_object.titles, vec!["position", "id"]);
+        let rows: Vec<(Option<i64>, Option<i64>)> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| (row.values[0].as_int(), row.values[1].as_int()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![(Some(1), Some(5)), (Some(2), Some(4)), (Some(3), Some(3))]
+        );
+
+        let gitql_object = evaluate_query(
+            &mut env,
+            &provider,
+            "SELECT id, rownum() FROM numbers WHERE id > 1 ORDER BY id LIMIT 2 OFFSET 1",
+        );
+        let rows: Vec<(Option<i64>, Option<i64>)> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| (row.values[0].as_int(), row.values[1].as_int()))
+            .collect();
+        assert_eq!(rows, vec![(Some(3), Some(1)), (Some(4), Some(2))]);
+
+        let tokens = Tokenizer::tokenize("SELECT id FROM numbers WHERE ROWNUM() < 3".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert!(diagnostic.message().contains("ROWNUM()"));
+    }
+
+    #[test]
+    fn test_relaxed_keyword_resolved_as_column() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("name", Box::new(TextType)), ("order", Box::new(IntType))];
+        assert!(schema.add_table("tasks", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT name, order FROM tasks WHERE order > 1 ORDER BY order";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+
+        env.with_relaxed_keywords(&["ORDER"]);
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "tasks",
+                &["name", "order"],
+                vec![
+                    vec![text("build"), int(2)],
+                    vec![text("test"), int(3)],
+                    vec![text("fetch"), int(1)],
+                ],
+            ));
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        assert_eq!(gitql_object.titles, vec!["name", "order"]);
+        let rows: Vec<(String, Option<i64>)> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| (row.values[0].literal(), row.values[1].as_int()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("build".to_string(), Some(2)),
+                ("test".to_string(), Some(3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiple_aggregations_single_pass_over_group() {
+        static TRACED_VALUES: Mutex<Vec<i64>> = Mutex::new(vec![]);
+        const ROWS_COUNT: i64 = 10_000;
+
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 1] = [("id", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+        env.with_aggregation_accumulators(aggregation_accumulators());
+        env.std_signatures.insert(
+            "trace",
+            Signature {
+                parameters: vec![Box::new(IntType)],
+                return_type: Box::new(IntType),
+            },
+        );
+        env.std_functions.insert("trace", |inputs| {
+            let value = inputs[0].as_int().unwrap();
+            TRACED_VALUES.lock().unwrap().push(value);
+            Box::new(IntValue::new(value))
+        });
+
+        let query = "SELECT COUNT(*), SUM(TRACE(id)), MAX(TRACE(id)), MIN(TRACE(id)) FROM numbers";
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "numbers",
+                &["id"],
+                (1..=ROWS_COUNT).map(|id| vec![int(id)]).collect(),
+            ));
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        let row = &gitql_object.groups[0].rows[0];
+        assert_eq!(row.values[0].as_int(), Some(ROWS_COUNT));
+        assert_eq!(
+            row.values[1].as_int(),
+            Some(ROWS_COUNT * (ROWS_COUNT + 1) / 2)
+        );
+        assert_eq!(row.values[2].as_int(), Some(ROWS_COUNT));
+        assert_eq!(row.values[3].as_int(), Some(1));
+
+        // Each row is visited once, so its arguments are evaluated for all aggregations together
+        let traced_values = TRACED_VALUES.lock().unwrap();
+        let expected_values: Vec<i64> = (1..=ROWS_COUNT).flat_map(|id| [id, id, id]).collect();
+        assert_eq!(*traced_values, expected_values);
+    }
+
+    #[test]
+    fn test_inner_join_with_on_predicate() {
+        let mut schema = Schema::default();
+        let branches: [(&'static str, Box<dyn DataType>); 2] = [
+            ("name", Box::new(TextType)),
+            ("commit_id", Box::new(IntType)),
+        ];
+        let commits: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("message", Box::new(TextType))];
+        assert!(schema.add_table("branches", &branches).is_ok());
+        assert!(schema.add_table("commits", &commits).is_ok());
+        let mut env = Environment::new(schema);
+
+        let provider: Box<dyn DataProvider> = Box::new(
+            TableRowsDataProvider::default()
+                .with_table(
+                    "branches",
+                    &["name", "commit_id"],
+                    vec![
+                        vec![text("main"), int(1)],
+                        vec![text("dev"), int(2)],
+                        vec![text("docs"), int(4)],
+                    ],
+                )
+                .with_table(
+                    "commits",
+                    &["id", "message"],
+                    vec![
+                        vec![int(1), text("init")],
+                        vec![int(2), text("add parser")],
+                        vec![int(3), text("fix typo")],
+                    ],
+                ),
+        );
+        let mut evaluate_repository_query = |query: &str| -> Vec<(String, String)> {
+            evaluate_query(&mut env, &provider, query).groups[0]
+                .rows
+                .iter()
+                .map(|row| (row.values[0].literal(), row.values[1].literal()))
+                .collect()
+        };
+
+        let expected = vec![
+            ("main".to_string(), "init".to_string()),
+            ("dev".to_string(), "add parser".to_string()),
+        ];
+
+        let rows = evaluate_repository_query(
+            "SELECT name, message FROM branches INNER JOIN commits ON branches.commit_id = commits.id",
+        );
+        assert_eq!(rows, expected);
+
+        let rows = evaluate_repository_query(
+            "SELECT name, message FROM branches JOIN commits ON commit_id = id",
+        );
+        assert_eq!(rows, expected);
+
+        // Cross join without predicate keeps all combinations
+        let rows =
+            evaluate_repository_query("SELECT name, message FROM branches CROSS JOIN commits");
+        assert_eq!(rows.len(), 9);
+    }
+
+    #[test]
+    fn test_self_join_with_tables_aliases() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("title", Box::new(TextType)),
+            ("parent_id", Box::new(IntType)),
+        ];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT child.title, parent.title FROM commits AS child \
+                     JOIN commits AS parent ON child.parent_id = parent.id WHERE parent.id > 1";
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "commits",
+                &["id", "title", "parent_id"],
+                vec![
+                    vec![int(1), text("init"), int(0)],
+                    vec![int(2), text("add parser"), int(1)],
+                    vec![int(3), text("fix"), int(2)],
+                ],
+            ));
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        assert_eq!(gitql_object.titles, vec!["child.title", "parent.title"]);
+        let rows: Vec<(String, String)> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| (row.values[0].literal(), row.values[1].literal()))
+            .collect();
+        assert_eq!(rows, vec![("fix".to_string(), "add parser".to_string())]);
+
+        // Qualified `DISTINCT ON` field is not ambiguous with the same column of the other table
+        let query = "SELECT DISTINCT ON (child.title) parent.title FROM commits AS child \
+                     CROSS JOIN commits AS parent";
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+        assert_eq!(gitql_object.titles, vec!["parent.title"]);
+        assert_eq!(gitql_object.groups[0].rows.len(), 3);
+    }
+
+    #[test]
+    fn test_set_operations_between_select_queries() {
+        let mut schema = Schema::default();
+        let authors: [(&'static str, Box<dyn DataType>); 1] = [("name", Box::new(TextType))];
+        let reviewers: [(&'static str, Box<dyn DataType>); 1] = [("reviewer", Box::new(TextType))];
+        assert!(schema.add_table("authors", &authors).is_ok());
+        assert!(schema.add_table("reviewers", &reviewers).is_ok());
+        let mut env = Environment::new(schema);
+        let provider: Box<dyn DataProvider> = Box::new(
+            TableRowsDataProvider::default()
+                .with_table(
+                    "authors",
+                    &["name"],
+                    vec![
+                        vec![text("amr")],
+                        vec![text("sam")],
+                        vec![text("sam")],
+                        vec![text("lina")],
+                    ],
+                )
+                .with_table(
+                    "reviewers",
+                    &["reviewer"],
+                    vec![vec![text("lina")], vec![text("omar")], vec![text("amr")]],
+                ),
+        );
+
+        for (operator, expected) in [
+            (
+                "UNION ALL",
+                vec!["amr", "sam", "sam", "lina", "lina", "omar", "amr"],
+            ),
+            ("UNION", vec!["amr", "sam", "lina", "omar"]),
+            ("INTERSECT", vec!["amr", "lina"]),
+            ("EXCEPT", vec!["sam"]),
+        ] {
+            let query = format!(
+                "SELECT name FROM authors {} SELECT reviewer FROM reviewers",
+                operator
+            );
+            let gitql_object = evaluate_query(&mut env, &provider, &query);
+
+            assert_eq!(gitql_object.titles, vec!["name"]);
+            let names: Vec<String> = gitql_object.groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].literal())
+                .collect();
+            assert_eq!(names, expected, "Unexpected result of `{}`", operator);
+        }
+
+        // INTERSECT is evaluated before UNION
+        let query = "SELECT name FROM authors UNION SELECT reviewer FROM reviewers \
+                     INTERSECT SELECT 'omar'";
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+        let names: Vec<String> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| row.values[0].literal())
+            .collect();
+        assert_eq!(names, vec!["amr", "sam", "lina", "omar"]);
+    }
+
+    #[test]
+    fn test_concat_operator() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("author_name", Box::new(TextType)),
+            ("author_email", Box::new(TextType)),
+            ("insertions", Box::new(IntType)),
+        ];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT author_name || ' <' || author_email || '>' AS author, \
+                     insertions || ' lines' AS changes FROM commits";
+        let provider: Box<dyn DataProvider> =
+            Box::new(TableRowsDataProvider::default().with_table(
+                "commits",
+                &["author_name", "author_email", "insertions"],
+                vec![vec![text("amr"), text("amr@gql.dev"), int(3)]],
+            ));
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+
+        let row = &gitql_object.groups[0].rows[0];
+        assert_eq!(row.values[0].literal(), "amr <amr@gql.dev>");
+        assert_eq!(row.values[1].literal(), "3 lines");
+
+        // Columns used only as operands of other selected expressions are selected too
+        let query = "SELECT insertions + 1 AS next FROM commits";
+        let gitql_object = evaluate_query(&mut env, &provider, query);
+        assert_eq!(gitql_object.groups[0].rows[0].values[0].as_int(), Some(4));
+    }
+
+    #[test]
+    fn test_where_logical_or_operator_between_booleans() {
+        let mut env = authors_env();
+
+        let query = "SELECT name FROM authors WHERE id = 1 || id = 3";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "amr");
+
+        let query = "SELECT name FROM authors WHERE id = 3 || name = 'sara' OR FALSE";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values[0].literal(), "sara");
+    }
+
+    #[test]
+    fn test_order_by_column_position() {
+        let mut env = authors_env();
+
+        let gitql_object =
+            evaluate_authors_query(&mut env, "SELECT name, id FROM authors ORDER BY 2 DESC");
+        let names: Vec<String> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| row.values[0].literal())
+            .collect();
+        assert_eq!(names, vec!["sara", "amr"]);
+    }
+
+    #[test]
+    fn test_coalesce_stops_at_first_non_null_value() {
+        let mut env = authors_env();
+
+        // The division by zero is never evaluated because `id` is never NULL
+        let query = "SELECT COALESCE(NULL, name) AS author, \
+                     COALESCE(id, id / (id - id)) AS author_id FROM authors";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].values[0].literal(), "amr");
+        assert_eq!(rows[0].values[1].as_int().unwrap(), 1);
+        assert_eq!(rows[1].values[0].literal(), "sara");
+        assert_eq!(rows[1].values[1].as_int().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_where_in_subquery() {
+        let mut env = authors_env();
+        let books_columns: [(&'static str, Box<dyn DataType>); 2] = [
+            ("author_id", Box::new(IntType)),
+            ("title", Box::new(TextType)),
+        ];
+        assert!(env.schema.add_table("books", &books_columns).is_ok());
+        let provider: Box<dyn DataProvider> = Box::new(
+            TableRowsDataProvider::default()
+                .with_table(
+                    "authors",
+                    &["id", "name"],
+                    vec![
+                        vec![int(1), text("amr")],
+                        vec![int(2), text("sara")],
+                        vec![int(3), text("omar")],
+                    ],
+                )
+                .with_table(
+                    "books",
+                    &["author_id", "title"],
+                    vec![
+                        vec![int(2), text("go")],
+                        vec![int(3), text("rust")],
+                        vec![int(2), text("sql")],
+                        vec![null(), text("draft")],
+                    ],
+                ),
+        );
+
+        let mut evaluate_names_query = |query: &str| -> Vec<String> {
+            evaluate_query(&mut env, &provider, query).groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].literal())
+                .collect()
+        };
+
+        let names = evaluate_names_query(
+            "SELECT name FROM authors WHERE id IN (SELECT author_id FROM books)",
+        );
+        assert_eq!(names, vec!["sara", "omar"]);
+
+        let names = evaluate_names_query(
+            "SELECT name FROM authors WHERE id NOT IN \
+             (SELECT author_id FROM books WHERE title != 'draft')",
+        );
+        assert_eq!(names, vec!["amr"]);
+
+        // A NULL selected by the subquery makes NOT IN unknown for the values that are not found
+        let names = evaluate_names_query(
+            "SELECT name FROM authors WHERE id NOT IN (SELECT author_id FROM books)",
+        );
+        assert!(names.is_empty());
+
+        let names = evaluate_names_query(
+            "SELECT name FROM authors \
+             WHERE (id IN (SELECT author_id FROM books WHERE title = 'rust')) \
+             OR (name IN (SELECT 'amr'))",
+        );
+        assert_eq!(names, vec!["amr", "omar"]);
+    }
+
+    #[test]
+    fn test_where_in_list_with_null() {
+        let mut env = authors_env();
+        let mut evaluate_names_query = |query: &str| -> Vec<String> {
+            evaluate_authors_query(&mut env, query).groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].literal())
+                .collect()
+        };
+
+        let names = evaluate_names_query("SELECT name FROM authors WHERE id IN (2, NULL)");
+        assert_eq!(names, vec!["sara"]);
+
+        // A NULL value makes NOT IN unknown for the values that are not found
+        let names = evaluate_names_query("SELECT name FROM authors WHERE id NOT IN (2, NULL)");
+        assert!(names.is_empty());
+
+        let names = evaluate_names_query("SELECT name FROM authors WHERE NULL IN (1, 2)");
+        assert!(names.is_empty());
+    }
+
+    #[test]
+    fn test_where_comparison_with_null_matches_no_rows() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("name", Box::new(TextType)),
+            ("email", Box::new(TextType)),
+        ];
+        assert!(schema.add_table("authors", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        // The authors provider has no emails so each row has NULL email
+        let queries = [
+            "SELECT name FROM authors WHERE email = 'amr@gitql.com'",
+            "SELECT name FROM authors WHERE email != 'amr@gitql.com'",
+            "SELECT name FROM authors WHERE NOT (email > 'a')",
+        ];
+
+        for query in queries {
+            let gitql_object = evaluate_authors_query(&mut env, query);
+            assert!(gitql_object.groups[0].rows.is_empty(), "{}", query);
+        }
+
+        let query = "SELECT name FROM authors WHERE email IS NULL";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.groups[0].rows.len(), 2);
+    }
+
+    #[test]
+    fn test_having_keeps_only_true_conditions() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("name", Box::new(TextType)),
+            ("email", Box::new(TextType)),
+        ];
+        assert!(schema.add_table("authors", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        // NULL condition is not a match, same as false condition
+        let query = "SELECT name, email FROM authors GROUP BY name HAVING email != 'amr@gitql.com'";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert!(gitql_object.groups[0].rows.is_empty());
+
+        let query = "SELECT name, email FROM authors GROUP BY name HAVING email IS NULL";
+        let gitql_object = evaluate_authors_query(&mut env, query);
+        assert_eq!(gitql_object.groups[0].rows.len(), 2);
+    }
+
+    #[test]
+    fn test_positional_parameters_are_cleared_after_evaluation() {
+        let mut env = Environment::new(Schema::default());
+        env.with_parameters(vec![Box::new(IntValue::new(5))]);
+
+        let tokens = Tokenizer::tokenize("DO $1".to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let provider = authors_data_provider();
+        let mut results = evaluate(&mut env, &provider, queries).ok().unwrap();
+        let EvaluationResult::Do(value) = results.remove(0) else {
+            panic!("Expect DO result")
+        };
+        assert_eq!(value.as_int(), Some(5));
+        assert!(env.parameters.is_empty());
+
+        let tokens = Tokenizer::tokenize("DO $1".to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+}
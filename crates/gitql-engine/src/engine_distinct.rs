@@ -9,15 +9,25 @@ use gitql_core::object::Group;
 use gitql_core::object::Row;
 
 /// Apply the distinct operator depending on the type of distinct
+///
+/// If the object still has many groups from `GROUP BY`, each group is merged
+/// into its first row before comparing rows, same as the final result
 pub(crate) fn apply_distinct_operator(
     distinct: &Distinct,
     object: &mut GitQLObject,
     hidden_selections: &[String],
 ) {
-    if object.is_empty() {
+    if object.is_empty() || matches!(distinct, Distinct::None) {
         return;
     }
 
+    if object.len() > 1 {
+        for group in object.groups.iter_mut() {
+            group.rows.truncate(1);
+        }
+        object.flat();
+    }
+
     match distinct {
         Distinct::DistinctAll => apply_distinct_all_operation(object, hidden_selections),
         Distinct::DistinctOn(fields) => apply_distinct_on_operation(object, fields),
@@ -45,7 +55,7 @@ fn apply_distinct_all_operation(object: &mut GitQLObject, hidden_selections: &[S
         let mut row_values: Vec<String> = Vec::with_capacity(titles_count);
         for i in 0..titles.len() {
             if let Some(value) = object.values.get(i + hidden_selection_count) {
-                row_values.push(value.literal());
+                row_values.push(value.hash_key());
             }
         }
 
@@ -81,7 +91,7 @@ fn apply_distinct_on_operation(object: &mut GitQLObject, distinct_fields: &[Stri
         let mut row_values: Vec<String> = Vec::with_capacity(distinct_fields.len());
         for field in distinct_fields {
             if let Some(index) = titles.iter().position(|r| r.eq(field)) {
-                row_values.push(object.values.get(index).unwrap().literal());
+                row_values.push(object.values.get(index).unwrap().hash_key());
             }
         }
 
@@ -103,3 +113,44 @@ fn apply_distinct_on_operation(object: &mut GitQLObject, distinct_fields: &[Stri
         object.groups[0].rows.append(&mut new_objects.rows);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::null::NullValue;
+    use gitql_core::values::text::TextValue;
+    use gitql_core::values::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_distinct_collapse_null_values() {
+        let values: Vec<Box<dyn Value>> = vec![
+            Box::new(NullValue),
+            Box::new(TextValue::new("Null".to_string())),
+            Box::new(NullValue),
+            Box::new(TextValue::new("main".to_string())),
+            Box::new(NullValue),
+        ];
+
+        let rows = values
+            .into_iter()
+            .map(|value| Row {
+                values: vec![value],
+            })
+            .collect();
+
+        let mut gitql_object = GitQLObject {
+            titles: vec!["branch".to_string()],
+            groups: vec![Group { rows }],
+            applied_optimizations: vec![],
+        };
+
+        apply_distinct_operator(&Distinct::DistinctAll, &mut gitql_object, &[]);
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 3);
+
+        let null_rows = rows.iter().filter(|row| row.values[0].is_null()).count();
+        assert_eq!(null_rows, 1);
+    }
+}
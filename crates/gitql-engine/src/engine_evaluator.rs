@@ -9,7 +9,9 @@ use gitql_ast::expression::BooleanExpr;
 use gitql_ast::expression::CallExpr;
 use gitql_ast::expression::CaseExpr;
 use gitql_ast::expression::CastExpr;
+use gitql_ast::expression::CoalesceExpr;
 use gitql_ast::expression::ComparisonExpr;
+use gitql_ast::expression::ConcatExpr;
 use gitql_ast::expression::ContainedByExpr;
 use gitql_ast::expression::ContainsExpr;
 use gitql_ast::expression::Expr;
@@ -18,15 +20,21 @@ use gitql_ast::expression::GlobExpr;
 use gitql_ast::expression::GlobalVariableExpr;
 use gitql_ast::expression::GroupComparisonExpr;
 use gitql_ast::expression::GroupExpr;
+use gitql_ast::expression::GroupLikeExpr;
+use gitql_ast::expression::HoistedExpr;
 use gitql_ast::expression::InExpr;
+use gitql_ast::expression::InSubqueryExpr;
 use gitql_ast::expression::IndexExpr;
 use gitql_ast::expression::IntervalExpr;
+use gitql_ast::expression::IsDistinctExpr;
 use gitql_ast::expression::IsNullExpr;
+use gitql_ast::expression::JsonAccessExpr;
 use gitql_ast::expression::LikeExpr;
 use gitql_ast::expression::LogicalExpr;
 use gitql_ast::expression::MemberAccessExpr;
 use gitql_ast::expression::Number;
 use gitql_ast::expression::NumberExpr;
+use gitql_ast::expression::ParameterExpr;
 use gitql_ast::expression::RegexExpr;
 use gitql_ast::expression::SliceExpr;
 use gitql_ast::expression::StringExpr;
@@ -36,6 +44,7 @@ use gitql_ast::operator::ArithmeticOperator;
 use gitql_ast::operator::BinaryBitwiseOperator;
 use gitql_ast::operator::BinaryLogicalOperator;
 use gitql_ast::operator::ComparisonOperator;
+use gitql_ast::operator::GroupComparisonOperator;
 use gitql_ast::operator::PrefixUnaryOperator;
 use gitql_core::environment::Environment;
 use gitql_core::values::array::ArrayValue;
@@ -51,13 +60,15 @@ use gitql_core::values::Value;
 use std::cmp::Ordering;
 use std::string::String;
 
+use crate::runtime_error::RuntimeError;
+
 #[allow(clippy::borrowed_box)]
 pub fn evaluate_expression(
     env: &mut Environment,
     expression: &Box<dyn Expr>,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     match expression.kind() {
         Assignment => {
             let expr = expression
@@ -85,6 +96,14 @@ pub fn evaluate_expression(
                 .unwrap();
             evaluate_global_variable(env, expr)
         }
+        Parameter => {
+            let expr = expression.as_any().downcast_ref::<ParameterExpr>().unwrap();
+            evaluate_parameter(env, expr)
+        }
+        Hoisted => {
+            let expr = expression.as_any().downcast_ref::<HoistedExpr>().unwrap();
+            evaluate_hoisted(env, expr)
+        }
         Number => {
             let expr = expression.as_any().downcast_ref::<NumberExpr>().unwrap();
             evaluate_number(expr)
@@ -116,6 +135,10 @@ pub fn evaluate_expression(
                 .unwrap();
             evaluate_arithmetic(env, expr, titles, object)
         }
+        Concat => {
+            let expr = expression.as_any().downcast_ref::<ConcatExpr>().unwrap();
+            evaluate_concat(env, expr, titles, object)
+        }
         Comparison => {
             let expr = expression
                 .as_any()
@@ -145,6 +168,10 @@ pub fn evaluate_expression(
             let expr = expression.as_any().downcast_ref::<LikeExpr>().unwrap();
             evaluate_like(env, expr, titles, object)
         }
+        GroupLike => {
+            let expr = expression.as_any().downcast_ref::<GroupLikeExpr>().unwrap();
+            evaluate_group_like(env, expr, titles, object)
+        }
         Regex => {
             let expr = expression.as_any().downcast_ref::<RegexExpr>().unwrap();
             evaluate_regex(env, expr, titles, object)
@@ -180,14 +207,32 @@ pub fn evaluate_expression(
             let expr = expression.as_any().downcast_ref::<CaseExpr>().unwrap();
             evaluate_case(env, expr, titles, object)
         }
+        Coalesce => {
+            let expr = expression.as_any().downcast_ref::<CoalesceExpr>().unwrap();
+            evaluate_coalesce(env, expr, titles, object)
+        }
         In => {
             let expr = expression.as_any().downcast_ref::<InExpr>().unwrap();
             evaluate_in(env, expr, titles, object)
         }
+        InSubquery => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<InSubqueryExpr>()
+                .unwrap();
+            evaluate_in_subquery(env, expr, titles, object)
+        }
         IsNull => {
             let expr = expression.as_any().downcast_ref::<IsNullExpr>().unwrap();
             evaluate_is_null(env, expr, titles, object)
         }
+        IsDistinct => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<IsDistinctExpr>()
+                .unwrap();
+            evaluate_is_distinct(env, expr, titles, object)
+        }
         Cast => {
             let expr = expression.as_any().downcast_ref::<CastExpr>().unwrap();
             evaluate_cast(env, expr, titles, object)
@@ -203,6 +248,13 @@ pub fn evaluate_expression(
                 .unwrap();
             evaluate_member_access(env, expr, titles, object)
         }
+        JsonAccess => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<JsonAccessExpr>()
+                .unwrap();
+            evaluate_json_access(env, expr, titles, object)
+        }
         Null => Ok(Box::new(NullValue)),
     }
 }
@@ -212,13 +264,13 @@ fn evaluate_assignment(
     expr: &AssignmentExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let value = evaluate_expression(env, &expr.value, titles, object)?;
     env.globals.insert(expr.symbol.to_string(), value.clone());
     Ok(value)
 }
 
-fn evaluate_string(expr: &StringExpr) -> Result<Box<dyn Value>, String> {
+fn evaluate_string(expr: &StringExpr) -> Result<Box<dyn Value>, RuntimeError> {
     Ok(Box::new(TextValue::new(expr.value.to_owned())))
 }
 
@@ -226,13 +278,13 @@ fn evaluate_symbol(
     expr: &SymbolExpr,
     titles: &[String],
     object: &[Box<dyn Value>],
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     for (index, title) in titles.iter().enumerate() {
         if expr.value.eq(title) {
             return Ok(object[index].clone());
         }
     }
-    Err(format!("Invalid column name `{}`", &expr.value))
+    Err(format!("Invalid column name `{}`", &expr.value).into())
 }
 
 fn evaluate_array(
@@ -240,7 +292,7 @@ fn evaluate_array(
     expr: &ArrayExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let mut values: Vec<Box<dyn Value>> = Vec::with_capacity(expr.values.len());
     for value in &expr.values {
         values.push(evaluate_expression(env, value, titles, object)?);
@@ -251,7 +303,7 @@ fn evaluate_array(
 fn evaluate_global_variable(
     env: &mut Environment,
     expr: &GlobalVariableExpr,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let name = &expr.name;
     if env.globals.contains_key(name) {
         return Ok(env.globals[name].clone());
@@ -260,21 +312,49 @@ fn evaluate_global_variable(
     Err(format!(
         "The value of `{}` may be not exists or calculated yet",
         name
-    ))
+    )
+    .into())
+}
+
+fn evaluate_parameter(
+    env: &mut Environment,
+    expr: &ParameterExpr,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    if let Some(value) = env.parameter(expr.index) {
+        return Ok(value.clone());
+    }
+
+    Err(format!(
+        "The value of positional parameter `${}` is not bound",
+        expr.index
+    )
+    .into())
+}
+
+fn evaluate_hoisted(
+    env: &mut Environment,
+    expr: &HoistedExpr,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    match env.hoisted_values.get(expr.index) {
+        Some(value) => Ok(value.clone()),
+        None => Err("Invalid reference to hoisted expression value"
+            .to_string()
+            .into()),
+    }
 }
 
-fn evaluate_number(expr: &NumberExpr) -> Result<Box<dyn Value>, String> {
+fn evaluate_number(expr: &NumberExpr) -> Result<Box<dyn Value>, RuntimeError> {
     Ok(match expr.value {
         Number::Int(integer) => Box::new(IntValue::new(integer)),
         Number::Float(float) => Box::new(FloatValue::new(float)),
     })
 }
 
-fn evaluate_boolean(expr: &BooleanExpr) -> Result<Box<dyn Value>, String> {
+fn evaluate_boolean(expr: &BooleanExpr) -> Result<Box<dyn Value>, RuntimeError> {
     Ok(Box::new(BoolValue::new(expr.is_true)))
 }
 
-fn evaluate_interval(expr: &IntervalExpr) -> Result<Box<dyn Value>, String> {
+fn evaluate_interval(expr: &IntervalExpr) -> Result<Box<dyn Value>, RuntimeError> {
     Ok(Box::new(IntervalValue::new(expr.interval.clone())))
 }
 
@@ -283,10 +363,10 @@ fn evaluate_collection_index(
     expr: &IndexExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let array = evaluate_expression(env, &expr.collection, titles, object)?;
     let index = evaluate_expression(env, &expr.index, titles, object)?;
-    array.index_op(&index)
+    Ok(array.index_op(&index)?)
 }
 
 fn evaluate_collection_slice(
@@ -294,7 +374,7 @@ fn evaluate_collection_slice(
     expr: &SliceExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let array = evaluate_expression(env, &expr.collection, titles, object)?;
 
     let start = if let Some(start_expr) = &expr.start {
@@ -309,7 +389,7 @@ fn evaluate_collection_slice(
         None
     };
 
-    array.slice_op(&start, &end)
+    Ok(array.slice_op(&start, &end)?)
 }
 
 fn evaluate_prefix_unary(
@@ -317,13 +397,20 @@ fn evaluate_prefix_unary(
     expr: &UnaryExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    match expr.operator {
+
+    // Unary operator on unknown value is also unknown, for example `NOT NULL` is NULL
+    if rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let result = match expr.operator {
         PrefixUnaryOperator::Negative => rhs.neg_op(),
         PrefixUnaryOperator::Bang => rhs.bang_op(),
         PrefixUnaryOperator::Not => rhs.not_op(),
-    }
+    };
+    Ok(result?)
 }
 
 fn evaluate_arithmetic(
@@ -331,17 +418,25 @@ fn evaluate_arithmetic(
     expr: &ArithmeticExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    match expr.operator {
+
+    // Arithmetic with unknown value is also unknown
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let result = match expr.operator {
         ArithmeticOperator::Plus => lhs.add_op(&rhs),
         ArithmeticOperator::Minus => lhs.sub_op(&rhs),
         ArithmeticOperator::Star => lhs.mul_op(&rhs),
         ArithmeticOperator::Slash => lhs.div_op(&rhs),
         ArithmeticOperator::Modulus => lhs.rem_op(&rhs),
         ArithmeticOperator::Exponentiation => lhs.caret_op(&rhs),
-    }
+    };
+
+    result.map_err(|error| RuntimeError::from(error).with_location(expr.location))
 }
 
 fn evaluate_comparison(
@@ -349,10 +444,16 @@ fn evaluate_comparison(
     expr: &ComparisonExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    match expr.operator {
+
+    // Comparing with unknown value is also unknown, except for the null safe equal operator
+    if expr.operator != ComparisonOperator::NullSafeEqual && (lhs.is_null() || rhs.is_null()) {
+        return Ok(Box::new(NullValue));
+    }
+
+    let result = match expr.operator {
         ComparisonOperator::Greater => lhs.gt_op(&rhs),
         ComparisonOperator::GreaterEqual => lhs.gte_op(&rhs),
         ComparisonOperator::Less => lhs.lt_op(&rhs),
@@ -360,26 +461,76 @@ fn evaluate_comparison(
         ComparisonOperator::Equal => lhs.eq_op(&rhs),
         ComparisonOperator::NotEqual => lhs.bang_eq_op(&rhs),
         ComparisonOperator::NullSafeEqual => lhs.null_safe_eq_op(&rhs),
-    }
+    };
+    Ok(result?)
 }
 
+/// Group comparison operator function like [`Value::group_eq_op`]
+type GroupComparisonFunction = fn(
+    &(dyn Value + 'static),
+    &Box<dyn Value>,
+    &GroupComparisonOperator,
+) -> Result<Box<dyn Value>, String>;
+
 fn evaluate_group_comparison(
     env: &mut Environment,
     expr: &GroupComparisonExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    match expr.comparison_operator {
-        ComparisonOperator::Greater => lhs.group_gt_op(&rhs, &expr.group_operator),
-        ComparisonOperator::GreaterEqual => lhs.group_gte_op(&rhs, &expr.group_operator),
-        ComparisonOperator::Less => lhs.group_lt_op(&rhs, &expr.group_operator),
-        ComparisonOperator::LessEqual => lhs.group_lte_op(&rhs, &expr.group_operator),
-        ComparisonOperator::Equal => lhs.group_eq_op(&rhs, &expr.group_operator),
-        ComparisonOperator::NotEqual => lhs.group_bang_eq_op(&rhs, &expr.group_operator),
-        ComparisonOperator::NullSafeEqual => lhs.group_null_safe_eq_op(&rhs, &expr.group_operator),
+
+    let group_comparison: GroupComparisonFunction = match expr.comparison_operator {
+        ComparisonOperator::Greater => Value::group_gt_op,
+        ComparisonOperator::GreaterEqual => Value::group_gte_op,
+        ComparisonOperator::Less => Value::group_lt_op,
+        ComparisonOperator::LessEqual => Value::group_lte_op,
+        ComparisonOperator::Equal => Value::group_eq_op,
+        ComparisonOperator::NotEqual => Value::group_bang_eq_op,
+        ComparisonOperator::NullSafeEqual => {
+            return Ok(lhs.group_null_safe_eq_op(&rhs, &expr.group_operator)?);
+        }
+    };
+
+    // Comparing unknown value or unknown group is also unknown
+    if lhs.is_null() || rhs.is_null() {
+        return Ok(Box::new(NullValue));
     }
+
+    // NULL elements can't be compared, so compare with the other elements only,
+    // and if they can't decide the result it will be unknown
+    let mut has_null_elements = false;
+    let rhs: Box<dyn Value> = match rhs.as_any().downcast_ref::<ArrayValue>() {
+        Some(array) if array.values.iter().any(|element| element.is_null()) => {
+            has_null_elements = true;
+            let elements = array
+                .values
+                .iter()
+                .filter(|element| !element.is_null())
+                .cloned()
+                .collect();
+            Box::new(ArrayValue::new(elements, array.base_type.clone()))
+        }
+        _ => rhs,
+    };
+
+    let result = group_comparison(lhs.as_ref(), &rhs, &expr.group_operator)?;
+
+    if has_null_elements {
+        if let Some(is_true) = result.as_bool() {
+            let is_decided = match expr.group_operator {
+                GroupComparisonOperator::Any => is_true,
+                GroupComparisonOperator::All => !is_true,
+            };
+
+            if !is_decided {
+                return Ok(Box::new(NullValue));
+            }
+        }
+    }
+
+    Ok(result)
 }
 
 fn evaluate_contains(
@@ -387,10 +538,10 @@ fn evaluate_contains(
     expr: &ContainsExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    lhs.contains_op(&rhs)
+    Ok(lhs.contains_op(&rhs)?)
 }
 
 fn evaluate_contained_by(
@@ -398,10 +549,10 @@ fn evaluate_contained_by(
     expr: &ContainedByExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    rhs.contains_op(&lhs)
+    Ok(rhs.contains_op(&lhs)?)
 }
 
 fn evaluate_like(
@@ -409,10 +560,53 @@ fn evaluate_like(
     expr: &LikeExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let input = evaluate_expression(env, &expr.input, titles, object)?;
     let pattern = evaluate_expression(env, &expr.pattern, titles, object)?;
-    input.like_op(&pattern)
+    Ok(input.like_op(&pattern)?)
+}
+
+fn evaluate_group_like(
+    env: &mut Environment,
+    expr: &GroupLikeExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    let input = evaluate_expression(env, &expr.input, titles, object)?;
+    let patterns = evaluate_expression(env, &expr.patterns, titles, object)?;
+    if input.is_null() || patterns.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let patterns = match patterns.as_any().downcast_ref::<ArrayValue>() {
+        Some(array) => &array.values,
+        None => {
+            return Err("Expect `LIKE ANY` and `LIKE ALL` patterns to be an Array"
+                .to_string()
+                .into())
+        }
+    };
+
+    // NULL patterns can't be matched, so if the other patterns can't decide the result it will be unknown
+    let is_any = expr.group_operator == GroupComparisonOperator::Any;
+    let mut has_null_patterns = false;
+    for pattern in patterns.iter() {
+        if pattern.is_null() {
+            has_null_patterns = true;
+            continue;
+        }
+
+        let is_matched = input.like_op(pattern)?.as_bool().unwrap_or(false);
+        if is_matched == is_any {
+            return Ok(Box::new(BoolValue::new(is_any)));
+        }
+    }
+
+    if has_null_patterns {
+        return Ok(Box::new(NullValue));
+    }
+
+    Ok(Box::new(BoolValue::new(!is_any)))
 }
 
 fn evaluate_regex(
@@ -420,10 +614,24 @@ fn evaluate_regex(
     expr: &RegexExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let input = evaluate_expression(env, &expr.input, titles, object)?;
     let pattern = evaluate_expression(env, &expr.pattern, titles, object)?;
-    input.regexp_op(&pattern)
+    input
+        .regexp_op(&pattern)
+        .map_err(|error| RuntimeError::from(error).with_location(expr.location))
+}
+
+fn evaluate_concat(
+    env: &mut Environment,
+    expr: &ConcatExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    let lhs = evaluate_expression(env, &expr.left, titles, object)?;
+    let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    let value = format!("{}{}", lhs, rhs);
+    Ok(Box::new(TextValue { value }))
 }
 
 fn evaluate_glob(
@@ -431,10 +639,10 @@ fn evaluate_glob(
     expr: &GlobExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let input = evaluate_expression(env, &expr.input, titles, object)?;
     let pattern = evaluate_expression(env, &expr.pattern, titles, object)?;
-    input.glob_op(&pattern)
+    Ok(input.glob_op(&pattern)?)
 }
 
 fn evaluate_logical(
@@ -442,14 +650,15 @@ fn evaluate_logical(
     expr: &LogicalExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    match expr.operator {
+    let result = match expr.operator {
         BinaryLogicalOperator::And => lhs.logical_and_op(&rhs),
         BinaryLogicalOperator::Or => lhs.logical_or_op(&rhs),
         BinaryLogicalOperator::Xor => lhs.logical_xor_op(&rhs),
-    }
+    };
+    Ok(result?)
 }
 
 fn evaluate_bitwise(
@@ -457,16 +666,17 @@ fn evaluate_bitwise(
     expr: &BitwiseExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let lhs = evaluate_expression(env, &expr.left, titles, object)?;
     let rhs = evaluate_expression(env, &expr.right, titles, object)?;
-    match expr.operator {
+    let result = match expr.operator {
         BinaryBitwiseOperator::Or => lhs.or_op(&rhs),
         BinaryBitwiseOperator::And => lhs.and_op(&rhs),
         BinaryBitwiseOperator::Xor => lhs.xor_op(&rhs),
         BinaryBitwiseOperator::RightShift => lhs.shr_op(&rhs),
         BinaryBitwiseOperator::LeftShift => lhs.shl_op(&rhs),
-    }
+    };
+    Ok(result?)
 }
 
 fn evaluate_call(
@@ -474,14 +684,21 @@ fn evaluate_call(
     expr: &CallExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let function_name = expr.function_name.as_str();
     let mut arguments = Vec::with_capacity(expr.arguments.len());
     for arg in expr.arguments.iter() {
         arguments.push(evaluate_expression(env, arg, titles, object)?);
     }
     let function = env.std_function(function_name).unwrap();
-    Ok(function(&arguments))
+    let value = function(&arguments);
+
+    // Standard functions report runtime errors by returning an error value
+    if let Some(message) = value.as_error() {
+        return Err(message.into());
+    }
+
+    Ok(value)
 }
 
 fn evaluate_benchmark_call(
@@ -489,7 +706,7 @@ fn evaluate_benchmark_call(
     expr: &BenchmarkCallExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let number_of_execution = evaluate_expression(env, &expr.count, titles, object)?;
     if let Some(number) = number_of_execution.as_any().downcast_ref::<IntValue>() {
         for _ in 0..number.value {
@@ -504,7 +721,7 @@ fn evaluate_between(
     expr: &BetweenExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let value = evaluate_expression(env, &expr.value, titles, object)?;
     let range_start = evaluate_expression(env, &expr.range_start, titles, object)?;
     let range_end = evaluate_expression(env, &expr.range_end, titles, object)?;
@@ -534,10 +751,12 @@ fn evaluate_case(
     expr: &CaseExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let conditions = &expr.conditions;
     let values = &expr.values;
 
+    // Conditions are evaluated in order and only the value of the first matched branch is evaluated,
+    // so branches like `CASE WHEN b != 0 THEN a / b ELSE 0 END` can guard against runtime errors
     for i in 0..conditions.len() {
         let condition = evaluate_expression(env, &conditions[i], titles, object)?;
         if let Some(bool_value) = condition.as_any().downcast_ref::<BoolValue>() {
@@ -549,8 +768,24 @@ fn evaluate_case(
 
     match &expr.default_value {
         Some(default_value) => evaluate_expression(env, default_value, titles, object),
-        _ => Err("Invalid case statement".to_owned()),
+        _ => Err("Invalid case statement".to_owned().into()),
+    }
+}
+
+fn evaluate_coalesce(
+    env: &mut Environment,
+    expr: &CoalesceExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    // Values are evaluated in order and the remaining values are not evaluated after the first non NULL one
+    for value_expr in &expr.values {
+        let value = evaluate_expression(env, value_expr, titles, object)?;
+        if !value.is_null() {
+            return Ok(value);
+        }
     }
+    Ok(Box::new(NullValue))
 }
 
 fn evaluate_in(
@@ -558,14 +793,57 @@ fn evaluate_in(
     expr: &InExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let argument = evaluate_expression(env, &expr.argument, titles, object)?;
+
+    // Same as `IN` subquery, the result is unknown if the argument is NULL
+    // or it's not found and one of the values is NULL
+    if argument.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    let mut has_null_value = false;
     for value_expr in &expr.values {
         let value = evaluate_expression(env, value_expr, titles, object)?;
         if argument.equals(&value) {
             return Ok(Box::new(BoolValue::new(!expr.has_not_keyword)));
         }
+        has_null_value |= value.is_null();
+    }
+
+    if has_null_value {
+        return Ok(Box::new(NullValue));
+    }
+
+    Ok(Box::new(BoolValue::new(expr.has_not_keyword)))
+}
+
+fn evaluate_in_subquery(
+    env: &mut Environment,
+    expr: &InSubqueryExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    let argument = evaluate_expression(env, &expr.argument, titles, object)?;
+    let Some(values) = env.subqueries_values.get(expr.subquery_index) else {
+        return Err("Invalid `IN` subquery".to_string().into());
+    };
+
+    // Same as comparing with NULL, the result is unknown if the argument is NULL
+    // or it's not found and the subquery selected NULL value
+    if argument.is_null() {
+        return Ok(Box::new(NullValue));
+    }
+
+    if values.contains(&argument.hash_key()) {
+        return Ok(Box::new(BoolValue::new(!expr.has_not_keyword)));
     }
+
+    let null_value: Box<dyn Value> = Box::new(NullValue);
+    if values.contains(&null_value.hash_key()) {
+        return Ok(Box::new(NullValue));
+    }
+
     Ok(Box::new(BoolValue::new(expr.has_not_keyword)))
 }
 
@@ -574,21 +852,49 @@ fn evaluate_is_null(
     expr: &IsNullExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let argument = evaluate_expression(env, &expr.argument, titles, object)?;
     let is_null = argument.as_any().downcast_ref::<NullValue>().is_some();
     let result = if expr.has_not { !is_null } else { is_null };
     Ok(Box::new(BoolValue::new(result)))
 }
 
+fn evaluate_is_distinct(
+    env: &mut Environment,
+    expr: &IsDistinctExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    let lhs = evaluate_expression(env, &expr.left, titles, object)?;
+    let rhs = evaluate_expression(env, &expr.right, titles, object)?;
+    let is_distinct = match (lhs.is_null(), rhs.is_null()) {
+        (true, true) => false,
+        (true, false) | (false, true) => true,
+        (false, false) => {
+            let is_equals = lhs.eq_op(&rhs)?;
+            !is_equals.as_bool().unwrap_or(false)
+        }
+    };
+    let result = if expr.has_not {
+        !is_distinct
+    } else {
+        is_distinct
+    };
+    Ok(Box::new(BoolValue::new(result)))
+}
+
 fn evaluate_cast(
     env: &mut Environment,
     expr: &CastExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let value = evaluate_expression(env, &expr.value, titles, object)?;
-    value.cast_op(&expr.result_type)
+    let result = value.cast_op(&expr.result_type);
+    match expr.location {
+        Some(location) => result.map_err(|error| RuntimeError::from(error).with_location(location)),
+        None => Ok(result?),
+    }
 }
 
 fn evaluate_grouping(
@@ -596,7 +902,7 @@ fn evaluate_grouping(
     expr: &GroupExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let value = evaluate_expression(env, &expr.expr, titles, object)?;
     Ok(value)
 }
@@ -606,11 +912,353 @@ fn evaluate_member_access(
     expr: &MemberAccessExpr,
     titles: &[String],
     object: &Vec<Box<dyn Value>>,
-) -> Result<Box<dyn Value>, String> {
+) -> Result<Box<dyn Value>, RuntimeError> {
     let value = evaluate_expression(env, &expr.composite, titles, object)?;
     if let Some(composite_value) = value.as_any().downcast_ref::<CompositeValue>() {
         let member_name = &expr.member_name;
         return Ok(composite_value.members.get(member_name).unwrap().clone());
     }
-    Err("Invalid value for Member access expression".to_owned())
+    Err("Invalid value for Member access expression"
+        .to_owned()
+        .into())
+}
+
+fn evaluate_json_access(
+    env: &mut Environment,
+    expr: &JsonAccessExpr,
+    titles: &[String],
+    object: &Vec<Box<dyn Value>>,
+) -> Result<Box<dyn Value>, RuntimeError> {
+    let value = evaluate_expression(env, &expr.value, titles, object)?;
+    let key = evaluate_expression(env, &expr.key, titles, object)?;
+
+    let Some(json_text) = value.as_text() else {
+        return Ok(Box::new(NullValue));
+    };
+
+    // Value that is not a valid JSON text has no keys or elements to access
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&json_text) else {
+        return Ok(Box::new(NullValue));
+    };
+
+    let element = if let Some(key) = key.as_text() {
+        json.get(key)
+    } else if let Some(index) = key.as_int() {
+        // Negative index is counted from the end of the array
+        let array_len = json.as_array().map_or(0, |array| array.len()) as i64;
+        let index = if index < 0 { array_len + index } else { index };
+        if index < 0 {
+            None
+        } else {
+            json.get(index as usize)
+        }
+    } else {
+        None
+    };
+
+    // `->` returns the element as JSON text, `->>` returns strings unquoted and JSON null as NULL
+    match element {
+        Some(serde_json::Value::String(text)) if expr.as_text => {
+            Ok(Box::new(TextValue::new(text.to_string())))
+        }
+        Some(serde_json::Value::Null) if expr.as_text => Ok(Box::new(NullValue)),
+        Some(element) => Ok(Box::new(TextValue::new(element.to_string()))),
+        None => Ok(Box::new(NullValue)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::expression::NullExpr;
+    use gitql_ast::location::SourceLocation;
+    use gitql_ast::types::boolean::BoolType;
+    use gitql_ast::types::integer::IntType;
+    use gitql_core::schema::Schema;
+
+    use super::*;
+
+    fn evaluate_is_distinct_from(
+        left: Box<dyn Expr>,
+        right: Box<dyn Expr>,
+        has_not: bool,
+    ) -> Option<bool> {
+        let mut env = Environment::new(Schema::default());
+        let expression: Box<dyn Expr> = Box::new(IsDistinctExpr {
+            left,
+            right,
+            has_not,
+        });
+        let value = evaluate_expression(&mut env, &expression, &[], &vec![]).ok()?;
+        value.as_bool()
+    }
+
+    fn int(value: i64) -> Box<dyn Expr> {
+        Box::new(NumberExpr {
+            value: Number::Int(value),
+        })
+    }
+
+    fn group_comparison(
+        left: Box<dyn Expr>,
+        comparison_operator: ComparisonOperator,
+        group_operator: GroupComparisonOperator,
+        elements: Vec<Box<dyn Expr>>,
+    ) -> Box<dyn Expr> {
+        Box::new(GroupComparisonExpr {
+            left,
+            comparison_operator,
+            group_operator,
+            right: Box::new(ArrayExpr {
+                values: elements,
+                element_type: Box::new(IntType),
+            }),
+        })
+    }
+
+    fn evaluate_not_any_and_not_equal_all(
+        value: fn() -> Box<dyn Expr>,
+        elements: fn() -> Vec<Box<dyn Expr>>,
+    ) -> (Box<dyn Value>, Box<dyn Value>) {
+        let mut env = Environment::new(Schema::default());
+        let not_any: Box<dyn Expr> = Box::new(UnaryExpr {
+            right: group_comparison(
+                value(),
+                ComparisonOperator::Equal,
+                GroupComparisonOperator::Any,
+                elements(),
+            ),
+            operator: PrefixUnaryOperator::Bang,
+            result_type: Box::new(BoolType),
+        });
+        let not_equal_all = group_comparison(
+            value(),
+            ComparisonOperator::NotEqual,
+            GroupComparisonOperator::All,
+            elements(),
+        );
+
+        let not_any_value = evaluate_expression(&mut env, &not_any, &[], &vec![]);
+        let not_equal_all_value = evaluate_expression(&mut env, &not_equal_all, &[], &vec![]);
+        (
+            not_any_value.ok().unwrap(),
+            not_equal_all_value.ok().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_not_group_comparison_with_null_values() {
+        let (not_any, not_equal_all) =
+            evaluate_not_any_and_not_equal_all(|| int(1), || vec![int(1), int(2)]);
+        assert_eq!(not_any.as_bool(), Some(false));
+        assert_eq!(not_equal_all.as_bool(), Some(false));
+
+        let (not_any, not_equal_all) =
+            evaluate_not_any_and_not_equal_all(|| int(3), || vec![int(1), int(2)]);
+        assert_eq!(not_any.as_bool(), Some(true));
+        assert_eq!(not_equal_all.as_bool(), Some(true));
+
+        // A match decides the result even if the group has NULL elements
+        let (not_any, not_equal_all) =
+            evaluate_not_any_and_not_equal_all(|| int(1), || vec![int(1), Box::new(NullExpr)]);
+        assert_eq!(not_any.as_bool(), Some(false));
+        assert_eq!(not_equal_all.as_bool(), Some(false));
+
+        // Without a match the NULL element makes both of them unknown
+        let (not_any, not_equal_all) =
+            evaluate_not_any_and_not_equal_all(|| int(3), || vec![int(1), Box::new(NullExpr)]);
+        assert!(not_any.is_null());
+        assert!(not_equal_all.is_null());
+
+        let (not_any, not_equal_all) =
+            evaluate_not_any_and_not_equal_all(|| Box::new(NullExpr), || vec![int(1)]);
+        assert!(not_any.is_null());
+        assert!(not_equal_all.is_null());
+    }
+
+    #[test]
+    fn test_is_distinct_from_null_values() {
+        assert_eq!(
+            evaluate_is_distinct_from(Box::new(NullExpr), int(1), false),
+            Some(true)
+        );
+        assert_eq!(
+            evaluate_is_distinct_from(int(1), Box::new(NullExpr), false),
+            Some(true)
+        );
+        assert_eq!(
+            evaluate_is_distinct_from(Box::new(NullExpr), Box::new(NullExpr), false),
+            Some(false)
+        );
+        assert_eq!(
+            evaluate_is_distinct_from(int(1), int(1), false),
+            Some(false)
+        );
+        assert_eq!(evaluate_is_distinct_from(int(1), int(2), false), Some(true));
+
+        assert_eq!(
+            evaluate_is_distinct_from(Box::new(NullExpr), Box::new(NullExpr), true),
+            Some(true)
+        );
+        assert_eq!(
+            evaluate_is_distinct_from(Box::new(NullExpr), int(1), true),
+            Some(false)
+        );
+        assert_eq!(evaluate_is_distinct_from(int(2), int(2), true), Some(true));
+    }
+
+    fn evaluate_null_check(expression: Box<dyn Expr>) -> Box<dyn Value> {
+        let mut env = Environment::new(Schema::default());
+        evaluate_expression(&mut env, &expression, &[], &vec![])
+            .ok()
+            .unwrap()
+    }
+
+    fn comparison(left: Box<dyn Expr>, operator: ComparisonOperator) -> Box<dyn Expr> {
+        Box::new(ComparisonExpr {
+            left,
+            operator,
+            right: Box::new(NullExpr),
+        })
+    }
+
+    fn is_null(argument: Box<dyn Expr>, has_not: bool) -> Box<dyn Expr> {
+        Box::new(IsNullExpr { argument, has_not })
+    }
+
+    #[test]
+    fn test_comparison_with_null_is_null() {
+        assert!(evaluate_null_check(comparison(int(1), ComparisonOperator::Equal)).is_null());
+        assert!(evaluate_null_check(comparison(int(1), ComparisonOperator::NotEqual)).is_null());
+        assert!(evaluate_null_check(comparison(int(1), ComparisonOperator::Less)).is_null());
+        assert!(
+            evaluate_null_check(comparison(Box::new(NullExpr), ComparisonOperator::Equal))
+                .is_null()
+        );
+    }
+
+    #[test]
+    fn test_prefix_unary_with_null_is_null() {
+        let operators = [
+            PrefixUnaryOperator::Negative,
+            PrefixUnaryOperator::Bang,
+            PrefixUnaryOperator::Not,
+        ];
+
+        for operator in operators {
+            let unary: Box<dyn Expr> = Box::new(UnaryExpr {
+                right: Box::new(NullExpr),
+                operator,
+                result_type: Box::new(BoolType),
+            });
+            assert!(evaluate_null_check(unary).is_null());
+        }
+    }
+
+    #[test]
+    fn test_null_value_and_is_null() {
+        assert_eq!(std::mem::size_of::<NullValue>(), 0);
+        assert!(evaluate_null_check(Box::new(NullExpr)).is_null());
+
+        let null_is_null = is_null(Box::new(NullExpr), false);
+        assert_eq!(evaluate_null_check(null_is_null).as_bool(), Some(true));
+
+        let int_is_not_null = is_null(int(1), true);
+        assert_eq!(evaluate_null_check(int_is_not_null).as_bool(), Some(true));
+
+        let comparison_is_null = is_null(comparison(int(1), ComparisonOperator::Equal), false);
+        assert_eq!(
+            evaluate_null_check(comparison_is_null).as_bool(),
+            Some(true)
+        );
+    }
+
+    fn evaluate_json_access(json: &str, key: Box<dyn Expr>, as_text: bool) -> Box<dyn Value> {
+        let mut env = Environment::new(Schema::default());
+        let expression: Box<dyn Expr> = Box::new(JsonAccessExpr {
+            value: Box::new(StringExpr {
+                value: json.to_string(),
+            }),
+            key,
+            as_text,
+        });
+        evaluate_expression(&mut env, &expression, &[], &vec![])
+            .ok()
+            .unwrap()
+    }
+
+    fn text(value: &str) -> Box<dyn Expr> {
+        Box::new(StringExpr {
+            value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_json_access_object_key() {
+        let json =
+            r#"{"name": "gitql", "tags": ["sql", "git"], "owner": {"id": 1}, "empty": null}"#;
+
+        assert_eq!(
+            evaluate_json_access(json, text("name"), false).literal(),
+            "\"gitql\""
+        );
+        assert_eq!(
+            evaluate_json_access(json, text("name"), true).literal(),
+            "gitql"
+        );
+        assert_eq!(
+            evaluate_json_access(json, text("owner"), true).literal(),
+            r#"{"id":1}"#
+        );
+        assert_eq!(
+            evaluate_json_access(json, text("empty"), false).literal(),
+            "null"
+        );
+        assert!(evaluate_json_access(json, text("empty"), true).is_null());
+        assert!(evaluate_json_access(json, text("missing"), false).is_null());
+        assert!(evaluate_json_access("not json", text("name"), false).is_null());
+    }
+
+    #[test]
+    fn test_json_access_array_index() {
+        let json = r#"["sql", 2, true]"#;
+
+        assert_eq!(
+            evaluate_json_access(json, int(0), false).literal(),
+            "\"sql\""
+        );
+        assert_eq!(evaluate_json_access(json, int(0), true).literal(), "sql");
+        assert_eq!(evaluate_json_access(json, int(1), true).literal(), "2");
+        assert_eq!(evaluate_json_access(json, int(-1), true).literal(), "true");
+        assert!(evaluate_json_access(json, int(3), true).is_null());
+        assert!(evaluate_json_access(json, int(-4), true).is_null());
+        assert!(evaluate_json_access(json, text("key"), true).is_null());
+    }
+
+    #[test]
+    fn test_explicit_cast_error_location() {
+        let mut env = Environment::new(Schema::default());
+        let location = SourceLocation::new(1, 1, 4, 8);
+        let cast: Box<dyn Expr> = Box::new(CastExpr {
+            value: Box::new(StringExpr {
+                value: "gitql".to_string(),
+            }),
+            result_type: Box::new(IntType),
+            location: Some(location),
+        });
+
+        let error = evaluate_expression(&mut env, &cast, &[], &vec![])
+            .err()
+            .unwrap();
+        assert_eq!(error.message, "Unexpected value to perform `CAST` with");
+        assert_eq!(error.location.unwrap().column_start, 4);
+
+        // Implicit casts has no location, so the location of the parent expression is used
+        let mut implicit_cast = cast.as_any().downcast_ref::<CastExpr>().unwrap().clone();
+        implicit_cast.location = None;
+        let implicit_cast: Box<dyn Expr> = Box::new(implicit_cast);
+        let error = evaluate_expression(&mut env, &implicit_cast, &[], &vec![])
+            .err()
+            .unwrap();
+        assert!(error.location.is_none());
+    }
 }
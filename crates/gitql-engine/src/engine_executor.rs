@@ -1,6 +1,8 @@
 use std::cmp;
 use std::collections::HashMap;
+use std::collections::HashSet;
 
+use gitql_ast::expression::BooleanExpr;
 use gitql_ast::expression::Expr;
 use gitql_ast::expression::ExprKind;
 use gitql_ast::statement::AggregateValue;
@@ -22,6 +24,7 @@ use gitql_core::environment::Environment;
 use gitql_core::object::GitQLObject;
 use gitql_core::object::Group;
 use gitql_core::object::Row;
+use gitql_core::values::error::ErrorValue;
 use gitql_core::values::null::NullValue;
 use gitql_core::values::Value;
 
@@ -29,10 +32,12 @@ use crate::data_provider::DataProvider;
 use crate::engine_evaluator::evaluate_expression;
 use crate::engine_filter::apply_filter_operation;
 use crate::engine_group::execute_group_by_statement;
+use crate::engine_hoisting::hoist_row_independent_expressions;
 use crate::engine_join::apply_join_operation;
 use crate::engine_ordering::execute_order_by_statement;
 use crate::engine_output_into::execute_into_statement;
 use crate::engine_window_functions::execute_window_functions_statement;
+use crate::runtime_error::RuntimeError;
 
 #[allow(clippy::borrowed_box)]
 pub fn execute_statement(
@@ -43,7 +48,7 @@ pub fn execute_statement(
     alias_table: &mut HashMap<String, String>,
     hidden_selection: &HashMap<String, Vec<String>>,
     has_group_by_statement: bool,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     match statement.kind() {
         Do => {
             let statement = statement.as_any().downcast_ref::<DoStatement>().unwrap();
@@ -144,7 +149,7 @@ fn execute_do_statement(
     env: &mut Environment,
     statement: &DoStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     let row_values = &gitql_object.groups[0].rows[0].values;
     evaluate_expression(env, &statement.expression, &gitql_object.titles, row_values)?;
     Ok(())
@@ -158,7 +163,7 @@ fn execute_select_statement(
     data_provider: &Box<dyn DataProvider>,
     gitql_object: &mut GitQLObject,
     hidden_selections: &HashMap<String, Vec<String>>,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     let mut selected_rows_per_table: HashMap<String, Vec<Row>> = HashMap::new();
     let mut hidden_selection_count_per_table: HashMap<String, usize> = HashMap::new();
 
@@ -168,11 +173,12 @@ fn execute_select_statement(
     for table_selection in &statement.table_selections {
         // Select objects from the target table
         let table_name = &table_selection.table_name;
+        let reference_name = table_selection.reference_name();
         let selected_columns = &mut table_selection.columns_names.to_owned();
 
         // Insert Hidden selection items for this table first
         let mut hidden_selection_count = 0;
-        if let Some(table_hidden_selection) = hidden_selections.get(table_name) {
+        if let Some(table_hidden_selection) = hidden_selections.get(reference_name) {
             for hidden_selection in table_hidden_selection {
                 if !selected_columns.contains(hidden_selection) {
                     selected_columns.insert(0, hidden_selection.to_string());
@@ -181,7 +187,7 @@ fn execute_select_statement(
             }
         }
 
-        hidden_selection_count_per_table.insert(table_name.to_string(), hidden_selection_count);
+        hidden_selection_count_per_table.insert(reference_name.to_string(), hidden_selection_count);
 
         // Calculate list of titles once per table
         let mut table_titles = vec![];
@@ -193,10 +199,21 @@ fn execute_select_statement(
         let selected_rows: Vec<Row> = if table_name.is_empty() {
             vec![Row { values: vec![] }]
         } else {
-            data_provider.provide(table_name, selected_columns)?
+            // Qualified columns `table.column` are provided by the column name
+            let qualifier = format!("{}.", reference_name);
+            let provided_columns: Vec<String> = selected_columns
+                .iter()
+                .map(|column| {
+                    column
+                        .strip_prefix(&qualifier)
+                        .unwrap_or(column)
+                        .to_string()
+                })
+                .collect();
+            data_provider.provide(table_name, &provided_columns)?
         };
 
-        selected_rows_per_table.insert(table_name.to_string(), selected_rows);
+        selected_rows_per_table.insert(reference_name.to_string(), selected_rows);
 
         // Append hidden selection in the right position
         // at the end all hidden selections will be first
@@ -250,7 +267,7 @@ fn execute_expression_selection(
     object_titles: &[String],
     selected_expr_titles: &[String],
     selected_expr: &[Box<dyn Expr>],
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     // Cache the index of each expression position to provide fast insertion
     let mut titles_index_map: HashMap<String, usize> = HashMap::new();
     for expr_column_title in selected_expr_titles {
@@ -261,7 +278,7 @@ fn execute_expression_selection(
         titles_index_map.insert(expr_column_title.to_string(), expr_title_index);
     }
 
-    for row in selected_rows.iter_mut() {
+    for (row_index, row) in selected_rows.iter_mut().enumerate() {
         for (index, expr) in selected_expr.iter().enumerate() {
             let expr_title = &selected_expr_titles[index];
             let value_index = *titles_index_map.get(expr_title).unwrap();
@@ -274,7 +291,20 @@ fn execute_expression_selection(
             let value = if expr.kind() == ExprKind::Symbol {
                 Box::new(NullValue)
             } else {
-                evaluate_expression(env, expr, object_titles, &row.values)?
+                match evaluate_expression(env, expr, object_titles, &row.values) {
+                    Ok(value) => value,
+                    // In lenient mode only this cell is marked as failed and the query continue
+                    Err(error) if env.lenient_evaluation => {
+                        env.warnings.push(format!(
+                            "Failed to evaluate `{}` in row {}: {}",
+                            expr_title,
+                            row_index + 1,
+                            error
+                        ));
+                        Box::new(ErrorValue::new(error.message))
+                    }
+                    Err(error) => return Err(error),
+                }
             };
 
             if index >= row.values.len() {
@@ -291,26 +321,35 @@ fn execute_where_statement(
     env: &mut Environment,
     statement: &WhereStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() {
         return Ok(());
     }
 
-    apply_filter_operation(
+    // Row independent parts of the condition are evaluated once instead of once per row
+    let condition = hoist_row_independent_expressions(env, &statement.condition);
+    if !env.hoisted_values.is_empty() {
+        gitql_object
+            .applied_optimizations
+            .push("hoisting row independent expressions".to_string());
+    }
+
+    let result = apply_filter_operation(
         env,
-        &statement.condition,
+        &condition,
         &gitql_object.titles,
         &mut gitql_object.groups[0].rows,
-    )?;
+    );
 
-    Ok(())
+    env.hoisted_values.clear();
+    result
 }
 
 fn execute_having_statement(
     env: &mut Environment,
     statement: &HavingStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() {
         return Ok(());
     }
@@ -319,6 +358,18 @@ fn execute_having_statement(
         gitql_object.flat()
     }
 
+    // Constant condition is folded by the parser, no need to evaluate it for each group
+    if let Some(condition) = statement.condition.as_any().downcast_ref::<BooleanExpr>() {
+        gitql_object
+            .applied_optimizations
+            .push("constant folding on having".to_string());
+
+        if !condition.is_true {
+            gitql_object.groups[0].rows.clear();
+        }
+        return Ok(());
+    }
+
     // Perform where command only on the first group
     // because group by command not executed yet
     apply_filter_operation(
@@ -334,7 +385,7 @@ fn execute_having_statement(
 fn execute_limit_statement(
     statement: &LimitStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() {
         return Ok(());
     }
@@ -354,7 +405,7 @@ fn execute_limit_statement(
 fn execute_offset_statement(
     statement: &OffsetStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() {
         return Ok(());
     }
@@ -364,9 +415,15 @@ fn execute_offset_statement(
     }
 
     let main_group: &mut Group = &mut gitql_object.groups[0];
-    main_group
-        .rows
-        .drain(0..cmp::min(statement.count, main_group.len()));
+
+    // Percentage offset depends on the number of rows after evaluating previous statements
+    let count = if statement.is_percentage {
+        main_group.len() * statement.count / 100
+    } else {
+        statement.count
+    };
+
+    main_group.rows.drain(0..cmp::min(count, main_group.len()));
 
     Ok(())
 }
@@ -377,13 +434,33 @@ fn execute_aggregation_functions_statement(
     gitql_object: &mut GitQLObject,
     alias_table: &HashMap<String, String>,
     is_query_has_group_by: bool,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     // Make sure you have at least one aggregation function to calculate
     let aggregations_map = &statement.aggregations;
     if aggregations_map.is_empty() {
         return Ok(());
     }
 
+    // Resolve the aggregations functions and their result columns indices once for all groups
+    let mut aggregations_functions = Vec::with_capacity(aggregations_map.len());
+    for (result_column_name, aggregation) in aggregations_map {
+        let (function, arguments, is_distinct) = match aggregation {
+            AggregateValue::Function(function, arguments) => (function, arguments, false),
+            AggregateValue::DistinctFunction(function, arguments) => (function, arguments, true),
+            AggregateValue::Expression(_) => continue,
+        };
+
+        // Get alias name if exists or column name by default
+        let column_name = resolve_actual_column_name(alias_table, result_column_name);
+        let column_index = gitql_object
+            .titles
+            .iter()
+            .position(|r| r.eq(&column_name))
+            .unwrap();
+
+        aggregations_functions.push((function, arguments, is_distinct, column_index));
+    }
+
     // We should run aggregation function for each group
     for group in &mut gitql_object.groups {
         // No need to apply all aggregation if there is no selected elements
@@ -391,48 +468,51 @@ fn execute_aggregation_functions_statement(
             continue;
         }
 
-        // Resolve all aggregations functions first
-        for (result_column_name, aggregation) in aggregations_map {
-            if let AggregateValue::Function(function, arguments) = aggregation {
-                // Get alias name if exists or column name by default
-                let column_name = resolve_actual_column_name(alias_table, result_column_name);
-                let column_index = gitql_object
-                    .titles
-                    .iter()
-                    .position(|r| r.eq(&column_name))
-                    .unwrap();
+        // Create a new accumulator for each aggregation function in this group
+        let mut accumulators = Vec::with_capacity(aggregations_functions.len());
+        for (function, _, _, _) in &aggregations_functions {
+            accumulators.push(env.aggregation_accumulator(function).unwrap());
+        }
 
-                // Evaluate the Arguments to Values
-                let mut group_arguments: Vec<Vec<Box<dyn Value>>> =
-                    Vec::with_capacity(group.rows.len());
-                for object in &mut group.rows {
-                    let mut row_values: Vec<Box<dyn Value>> =
-                        Vec::with_capacity(object.values.len());
-                    for argument in arguments {
-                        let value = evaluate_expression(
-                            env,
-                            argument,
-                            &gitql_object.titles,
-                            &object.values,
-                        )?;
-
-                        row_values.push(value);
-                    }
+        // Update the accumulators of all aggregations functions in a single pass over the group rows
+        let mut distinct_seen_values: Vec<HashSet<Vec<String>>> =
+            vec![HashSet::new(); aggregations_functions.len()];
+        for object in &group.rows {
+            for (index, (_, arguments, is_distinct, _)) in aggregations_functions.iter().enumerate()
+            {
+                let mut row_values: Vec<Box<dyn Value>> = Vec::with_capacity(arguments.len());
+                for argument in arguments.iter() {
+                    let value =
+                        evaluate_expression(env, argument, &gitql_object.titles, &object.values)?;
+
+                    row_values.push(value);
+                }
 
-                    group_arguments.push(row_values);
+                // Accumulate only the first row of each distinct combination of arguments values
+                if *is_distinct {
+                    let literals = row_values.iter().map(|value| value.literal()).collect();
+                    if !distinct_seen_values[index].insert(literals) {
+                        continue;
+                    }
                 }
 
-                // Get the target aggregation function
-                let aggregation_function = env.aggregation_function(function).unwrap();
-                let result = &aggregation_function(&group_arguments);
+                accumulators[index].accumulate(row_values);
+            }
+        }
 
-                // Insert the calculated value in the group objects
-                for object in &mut group.rows {
-                    if column_index < object.values.len() {
-                        object.values[column_index] = result.clone();
-                    } else {
-                        object.values.push(result.clone());
-                    }
+        // Resolve all aggregations functions first
+        for ((_, _, _, column_index), mut accumulator) in
+            aggregations_functions.iter().zip(accumulators)
+        {
+            let column_index = *column_index;
+            let result = &accumulator.finish();
+
+            // Insert the calculated value in the group objects
+            for object in &mut group.rows {
+                if column_index < object.values.len() {
+                    object.values[column_index] = result.clone();
+                } else {
+                    object.values.push(result.clone());
                 }
             }
         }
@@ -474,7 +554,7 @@ fn execute_aggregation_functions_statement(
 pub fn execute_global_variable_statement(
     env: &mut Environment,
     statement: &GlobalVariableStatement,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     let value = evaluate_expression(env, &statement.value, &[], &vec![])?;
     env.globals.insert(statement.name.to_string(), value);
     Ok(())
@@ -488,3 +568,36 @@ pub fn resolve_actual_column_name(alias_table: &HashMap<String, String>, name: &
 
     name.to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::integer::IntValue;
+
+    use super::*;
+
+    #[test]
+    fn test_offset_percentage_statement() {
+        let rows = (0..10)
+            .map(|i| Row {
+                values: vec![Box::new(IntValue::new(i))],
+            })
+            .collect();
+
+        let mut gitql_object = GitQLObject {
+            titles: vec!["id".to_string()],
+            groups: vec![Group { rows }],
+            applied_optimizations: vec![],
+        };
+
+        let statement = OffsetStatement {
+            count: 30,
+            is_percentage: true,
+        };
+
+        assert!(execute_offset_statement(&statement, &mut gitql_object).is_ok());
+
+        let rows = &gitql_object.groups[0].rows;
+        assert_eq!(rows.len(), 7);
+        assert_eq!(rows[0].values[0].as_int(), Some(3));
+    }
+}
@@ -4,6 +4,7 @@ use gitql_core::object::Row;
 use gitql_core::values::boolean::BoolValue;
 
 use crate::engine_evaluator::evaluate_expression;
+use crate::runtime_error::RuntimeError;
 
 #[inline(always)]
 #[allow(clippy::borrowed_box)]
@@ -12,14 +13,18 @@ pub(crate) fn apply_filter_operation(
     condition: &Box<dyn Expr>,
     titles: &[String],
     rows: &mut Vec<Row>,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     let mut positions_to_delete = vec![];
     for (index, row) in rows.iter().enumerate() {
+        // Only rows that match the condition are kept, unknown `NULL` condition is not a match
         let expression = evaluate_expression(env, condition, titles, &row.values)?;
-        if let Some(bool_value) = expression.as_any().downcast_ref::<BoolValue>() {
-            if !bool_value.value {
-                positions_to_delete.push(index);
-            }
+        let is_match = expression
+            .as_any()
+            .downcast_ref::<BoolValue>()
+            .is_some_and(|bool_value| bool_value.value);
+
+        if !is_match {
+            positions_to_delete.push(index);
         }
     }
 
@@ -11,12 +11,13 @@ use gitql_core::object::GitQLObject;
 use gitql_core::object::Group;
 
 use crate::engine_evaluator::evaluate_expression;
+use crate::runtime_error::RuntimeError;
 
 pub(crate) fn execute_group_by_statement(
     env: &mut Environment,
     statement: &GroupByStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() {
         return Ok(());
     }
@@ -40,17 +41,34 @@ pub(crate) fn execute_group_by_statement(
         vec![(0..values_count).collect()]
     };
 
+    // If the group by elements is one and ROLLUP is enabled
+    // For example: SELECT ... FROM <TABLE> GROUP BY X WITH ROLLUP
+    // Should append the the main group at the end
+    let is_main_group_appended =
+        is_roll_up_enabled && indexes_combinations.len() == 1 && indexes_combinations[0].len() == 1;
+
+    // Rows are moved to their groups, they are only cloned if the main group is appended too
+    let (rows, main_group) = if is_main_group_appended {
+        (main_group.rows.clone(), Some(main_group))
+    } else {
+        (main_group.rows, None)
+    };
+
     // For each row should check the group by values combinations to build multi groups
-    for row in main_group.rows.iter() {
+    let last_combination_index = indexes_combinations.len().saturating_sub(1);
+    for row in rows {
+        let mut row = Some(row);
+
         // Create all combination of values for each row
-        for indexes in indexes_combinations.iter() {
+        for (combination_index, indexes) in indexes_combinations.iter().enumerate() {
+            let values = &row.as_ref().unwrap().values;
             let mut row_values: Vec<String> = Vec::with_capacity(indexes.len());
             for index in indexes {
                 let value = evaluate_expression(
                     env,
                     &statement.values[*index],
                     &gitql_object.titles,
-                    &row.values,
+                    values,
                 )?;
                 row_values.push(value.literal());
             }
@@ -60,12 +78,20 @@ pub(crate) fn execute_group_by_statement(
             row_values.hash(&mut hasher);
             let values_hash = hasher.finish();
 
+            // With ROLLUP the same row belongs to a group for each combination,
+            // so it's cloned for all of them except the last one that take it
+            let group_row = if combination_index == last_combination_index {
+                row.take().unwrap()
+            } else {
+                row.as_ref().unwrap().clone()
+            };
+
             // Push a new group for this unique value and update the next index
             if let Vacant(e) = groups_map.entry(values_hash) {
                 e.insert(next_group_index);
                 next_group_index += 1;
                 gitql_object.groups.push(Group {
-                    rows: vec![row.clone()],
+                    rows: vec![group_row],
                 });
                 continue;
             }
@@ -73,14 +99,11 @@ pub(crate) fn execute_group_by_statement(
             // If there is an existing group for this value, append current object to it
             let index = *groups_map.get(&values_hash).unwrap();
             let target_group = &mut gitql_object.groups[index];
-            target_group.rows.push(row.clone());
+            target_group.rows.push(group_row);
         }
     }
 
-    // If the group by elements is one and ROLLUP is enabled
-    // For example: SELECT ... FROM <TABLE> GROUP BY X WITH ROLLUP
-    // Should append the the main group at the end
-    if is_roll_up_enabled && indexes_combinations.len() == 1 && indexes_combinations[0].len() == 1 {
+    if let Some(main_group) = main_group {
         gitql_object.groups.push(main_group);
     }
 
@@ -0,0 +1,254 @@
+use gitql_ast::expression::ArithmeticExpr;
+use gitql_ast::expression::BetweenExpr;
+use gitql_ast::expression::CallExpr;
+use gitql_ast::expression::CastExpr;
+use gitql_ast::expression::CoalesceExpr;
+use gitql_ast::expression::ComparisonExpr;
+use gitql_ast::expression::ConcatExpr;
+use gitql_ast::expression::Expr;
+use gitql_ast::expression::ExprKind;
+use gitql_ast::expression::GroupExpr;
+use gitql_ast::expression::HoistedExpr;
+use gitql_ast::expression::InExpr;
+use gitql_ast::expression::IsNullExpr;
+use gitql_ast::expression::LikeExpr;
+use gitql_ast::expression::LogicalExpr;
+use gitql_ast::expression::UnaryExpr;
+use gitql_core::environment::Environment;
+
+use crate::engine_evaluator::evaluate_expression;
+
+/// Replace the row independent sub expressions with [`HoistedExpr`] that reference
+/// their values, which are evaluated once and stored in the environment hoisted values
+///
+/// If the evaluation of a sub expression failed, it's kept as is so the error is
+/// reported by the normal evaluation with the row that caused it
+#[allow(clippy::borrowed_box)]
+pub(crate) fn hoist_row_independent_expressions(
+    env: &mut Environment,
+    expression: &Box<dyn Expr>,
+) -> Box<dyn Expr> {
+    if !is_leaf_expression(expression) && is_row_independent(env, expression) {
+        if let Ok(value) = evaluate_expression(env, expression, &[], &vec![]) {
+            env.hoisted_values.push(value);
+            return Box::new(HoistedExpr {
+                index: env.hoisted_values.len() - 1,
+                result_type: expression.expr_type(),
+            });
+        }
+        return expression.clone();
+    }
+
+    match expression.kind() {
+        ExprKind::Arithmetic => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<ArithmeticExpr>()
+                .unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.left = hoist_row_independent_expressions(env, &expr.left);
+            hoisted.right = hoist_row_independent_expressions(env, &expr.right);
+            Box::new(hoisted)
+        }
+        ExprKind::Comparison => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<ComparisonExpr>()
+                .unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.left = hoist_row_independent_expressions(env, &expr.left);
+            hoisted.right = hoist_row_independent_expressions(env, &expr.right);
+            Box::new(hoisted)
+        }
+        ExprKind::Concat => {
+            let expr = expression.as_any().downcast_ref::<ConcatExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.left = hoist_row_independent_expressions(env, &expr.left);
+            hoisted.right = hoist_row_independent_expressions(env, &expr.right);
+            Box::new(hoisted)
+        }
+        ExprKind::Logical => {
+            let expr = expression.as_any().downcast_ref::<LogicalExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.left = hoist_row_independent_expressions(env, &expr.left);
+            hoisted.right = hoist_row_independent_expressions(env, &expr.right);
+            Box::new(hoisted)
+        }
+        ExprKind::PrefixUnary => {
+            let expr = expression.as_any().downcast_ref::<UnaryExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.right = hoist_row_independent_expressions(env, &expr.right);
+            Box::new(hoisted)
+        }
+        ExprKind::Cast => {
+            let expr = expression.as_any().downcast_ref::<CastExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.value = hoist_row_independent_expressions(env, &expr.value);
+            Box::new(hoisted)
+        }
+        ExprKind::Grouping => {
+            let expr = expression.as_any().downcast_ref::<GroupExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.expr = hoist_row_independent_expressions(env, &expr.expr);
+            Box::new(hoisted)
+        }
+        ExprKind::Call => {
+            let expr = expression.as_any().downcast_ref::<CallExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            if !env.is_volatile_function(&expr.function_name) {
+                hoisted.arguments = expr
+                    .arguments
+                    .iter()
+                    .map(|argument| hoist_row_independent_expressions(env, argument))
+                    .collect();
+            }
+            Box::new(hoisted)
+        }
+        ExprKind::Between => {
+            let expr = expression.as_any().downcast_ref::<BetweenExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.value = hoist_row_independent_expressions(env, &expr.value);
+            hoisted.range_start = hoist_row_independent_expressions(env, &expr.range_start);
+            hoisted.range_end = hoist_row_independent_expressions(env, &expr.range_end);
+            Box::new(hoisted)
+        }
+        ExprKind::Coalesce => {
+            let expr = expression.as_any().downcast_ref::<CoalesceExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.values = expr
+                .values
+                .iter()
+                .map(|value| hoist_row_independent_expressions(env, value))
+                .collect();
+            Box::new(hoisted)
+        }
+        ExprKind::In => {
+            let expr = expression.as_any().downcast_ref::<InExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.argument = hoist_row_independent_expressions(env, &expr.argument);
+            hoisted.values = expr
+                .values
+                .iter()
+                .map(|value| hoist_row_independent_expressions(env, value))
+                .collect();
+            Box::new(hoisted)
+        }
+        ExprKind::IsNull => {
+            let expr = expression.as_any().downcast_ref::<IsNullExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.argument = hoist_row_independent_expressions(env, &expr.argument);
+            Box::new(hoisted)
+        }
+        ExprKind::Like => {
+            let expr = expression.as_any().downcast_ref::<LikeExpr>().unwrap();
+            let mut hoisted = expr.clone();
+            hoisted.input = hoist_row_independent_expressions(env, &expr.input);
+            hoisted.pattern = hoist_row_independent_expressions(env, &expr.pattern);
+            Box::new(hoisted)
+        }
+        _ => expression.clone(),
+    }
+}
+
+/// Return true if the expression is cheap to evaluate, so there is no benefit from hoisting it
+#[allow(clippy::borrowed_box)]
+fn is_leaf_expression(expression: &Box<dyn Expr>) -> bool {
+    matches!(
+        expression.kind(),
+        ExprKind::Number
+            | ExprKind::String
+            | ExprKind::Boolean
+            | ExprKind::Null
+            | ExprKind::Interval
+            | ExprKind::Parameter
+            | ExprKind::GlobalVariable
+            | ExprKind::Hoisted
+    )
+}
+
+/// Return true if the expression has the same value for every row,
+/// it must not reference any column or call volatile functions
+#[allow(clippy::borrowed_box)]
+fn is_row_independent(env: &Environment, expression: &Box<dyn Expr>) -> bool {
+    match expression.kind() {
+        ExprKind::Number
+        | ExprKind::String
+        | ExprKind::Boolean
+        | ExprKind::Null
+        | ExprKind::Interval
+        | ExprKind::Parameter
+        | ExprKind::GlobalVariable
+        | ExprKind::Hoisted => true,
+        ExprKind::Arithmetic => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<ArithmeticExpr>()
+                .unwrap();
+            is_row_independent(env, &expr.left) && is_row_independent(env, &expr.right)
+        }
+        ExprKind::Comparison => {
+            let expr = expression
+                .as_any()
+                .downcast_ref::<ComparisonExpr>()
+                .unwrap();
+            is_row_independent(env, &expr.left) && is_row_independent(env, &expr.right)
+        }
+        ExprKind::Concat => {
+            let expr = expression.as_any().downcast_ref::<ConcatExpr>().unwrap();
+            is_row_independent(env, &expr.left) && is_row_independent(env, &expr.right)
+        }
+        ExprKind::Logical => {
+            let expr = expression.as_any().downcast_ref::<LogicalExpr>().unwrap();
+            is_row_independent(env, &expr.left) && is_row_independent(env, &expr.right)
+        }
+        ExprKind::PrefixUnary => {
+            let expr = expression.as_any().downcast_ref::<UnaryExpr>().unwrap();
+            is_row_independent(env, &expr.right)
+        }
+        ExprKind::Cast => {
+            let expr = expression.as_any().downcast_ref::<CastExpr>().unwrap();
+            is_row_independent(env, &expr.value)
+        }
+        ExprKind::Grouping => {
+            let expr = expression.as_any().downcast_ref::<GroupExpr>().unwrap();
+            is_row_independent(env, &expr.expr)
+        }
+        ExprKind::Call => {
+            let expr = expression.as_any().downcast_ref::<CallExpr>().unwrap();
+            !env.is_volatile_function(&expr.function_name)
+                && expr
+                    .arguments
+                    .iter()
+                    .all(|argument| is_row_independent(env, argument))
+        }
+        ExprKind::Between => {
+            let expr = expression.as_any().downcast_ref::<BetweenExpr>().unwrap();
+            is_row_independent(env, &expr.value)
+                && is_row_independent(env, &expr.range_start)
+                && is_row_independent(env, &expr.range_end)
+        }
+        ExprKind::Coalesce => {
+            let expr = expression.as_any().downcast_ref::<CoalesceExpr>().unwrap();
+            expr.values
+                .iter()
+                .all(|value| is_row_independent(env, value))
+        }
+        ExprKind::In => {
+            let expr = expression.as_any().downcast_ref::<InExpr>().unwrap();
+            is_row_independent(env, &expr.argument)
+                && expr
+                    .values
+                    .iter()
+                    .all(|value| is_row_independent(env, value))
+        }
+        ExprKind::IsNull => {
+            let expr = expression.as_any().downcast_ref::<IsNullExpr>().unwrap();
+            is_row_independent(env, &expr.argument)
+        }
+        ExprKind::Like => {
+            let expr = expression.as_any().downcast_ref::<LikeExpr>().unwrap();
+            is_row_independent(env, &expr.input) && is_row_independent(env, &expr.pattern)
+        }
+        _ => false,
+    }
+}
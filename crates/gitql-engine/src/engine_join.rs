@@ -11,6 +11,7 @@ use gitql_core::values::null::NullValue;
 use gitql_core::values::Value;
 
 use crate::engine_evaluator::evaluate_expression;
+use crate::runtime_error::RuntimeError;
 
 #[inline(always)]
 pub(crate) fn apply_join_operation(
@@ -21,12 +22,12 @@ pub(crate) fn apply_join_operation(
     selected_rows_per_table: &mut HashMap<String, Vec<Row>>,
     hidden_selection_per_table: &HashMap<String, usize>,
     titles: &[String],
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     // If no join, just merge them, can be optimized to append only the first value in the map
     if joins.is_empty() {
         for table_selection in tables_selections {
             let table_rows = selected_rows_per_table
-                .get_mut(&table_selection.table_name)
+                .get_mut(table_selection.reference_name())
                 .unwrap();
             all_rows.append(table_rows);
         }
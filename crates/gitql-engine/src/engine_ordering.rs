@@ -1,23 +1,41 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::BinaryHeap;
 
+use gitql_ast::expression::SymbolExpr;
+use gitql_ast::statement::Collation;
 use gitql_ast::statement::NullsOrderPolicy;
 use gitql_ast::statement::OrderByStatement;
 use gitql_ast::statement::SortingOrder;
 use gitql_core::environment::Environment;
 use gitql_core::object::GitQLObject;
 use gitql_core::object::Group;
+use gitql_core::object::Row;
 use gitql_core::values::null::NullValue;
+use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
 use crate::engine_evaluator::evaluate_expression;
+use crate::runtime_error::RuntimeError;
 
 pub(crate) fn execute_order_by_statement(
     env: &mut Environment,
     statement: &OrderByStatement,
     gitql_object: &mut GitQLObject,
     group_index: usize,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
+    execute_order_by_statement_with_limit(env, statement, gitql_object, group_index, None)
+}
+
+/// Order the group rows, and if the limit is known only keep the first `limit` ordered rows
+/// using a bounded heap instead of sorting all of them, the result is the same as the full stable sort
+pub(crate) fn execute_order_by_statement_with_limit(
+    env: &mut Environment,
+    statement: &OrderByStatement,
+    gitql_object: &mut GitQLObject,
+    group_index: usize,
+    limit: Option<usize>,
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() || group_index >= gitql_object.len() {
         return Ok(());
     }
@@ -29,78 +47,409 @@ pub(crate) fn execute_order_by_statement(
 
     let rows_len = main_group.rows.len();
     let arguments_len = statement.arguments.len();
-    let main_group_rows = &main_group.rows;
     let titles = &gitql_object.titles;
 
-    let mut eval_map: HashMap<usize, Vec<Box<dyn Value>>> = HashMap::with_capacity(rows_len);
-
-    for row in main_group_rows.iter() {
-        let row_addr = row.values.as_ptr() as usize;
-        let mut arguments_values: Vec<Box<dyn Value>> = Vec::with_capacity(arguments_len);
-        for argument in statement.arguments.iter() {
-            // No need to compare if the ordering argument is constants
-            if argument.is_const() {
-                arguments_values.push(Box::new(NullValue));
-                continue;
+    // Column arguments are compared by reference to the row values without cloning them,
+    // except with `NOCASE` collation because they are compared by a new lowercase value
+    let arguments_columns: Vec<Option<usize>> = statement
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(arg_index, argument)| {
+            if statement.collations[arg_index] == Collation::NoCase {
+                return None;
             }
 
-            let value = &evaluate_expression(env, argument, titles, &row.values)?;
-            arguments_values.push(value.to_owned());
-        }
+            let symbol = argument.as_any().downcast_ref::<SymbolExpr>()?;
+            titles.iter().position(|title| symbol.value.eq(title))
+        })
+        .collect();
 
-        eval_map.insert(row_addr, arguments_values);
-    }
+    // Constant arguments are never compared, so they can share the same placeholder value
+    let constant_placeholder: Box<dyn Value> = Box::new(NullValue);
 
-    main_group.rows.sort_by(|a, b| {
-        for arg_index in 0..arguments_len {
-            let argument = &statement.arguments[arg_index];
+    // Pre evaluate the ordering arguments for each row, using the row index as key
+    let mut evaluated_rows: Vec<Vec<Cow<Box<dyn Value>>>> = Vec::with_capacity(rows_len);
+    for row in main_group.rows.iter() {
+        let mut arguments_values: Vec<Cow<Box<dyn Value>>> = Vec::with_capacity(arguments_len);
+        for (arg_index, argument) in statement.arguments.iter().enumerate() {
             // No need to compare if the ordering argument is constants
             if argument.is_const() {
+                arguments_values.push(Cow::Borrowed(&constant_placeholder));
                 continue;
             }
 
-            // Use the Memory address of A, B as Map keys
-            let a_addr = a.values.as_ptr() as usize;
-            let b_addr = b.values.as_ptr() as usize;
-
-            // Get pre evaluated values from the eval map using addr as key, arg index as offset
-            let a_value = &eval_map.get(&a_addr).unwrap()[arg_index];
-            let b_value = &eval_map.get(&b_addr).unwrap()[arg_index];
-
-            let null_ordering_policy = &statement.nulls_order_policies[arg_index];
-            if a_value.is_null() {
-                return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
-                    Ordering::Less
-                } else {
-                    Ordering::Greater
-                };
+            if let Some(column_index) = arguments_columns[arg_index] {
+                arguments_values.push(Cow::Borrowed(&row.values[column_index]));
+                continue;
             }
 
-            if b_value.is_null() {
-                return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
-                    Ordering::Greater
-                } else {
-                    Ordering::Less
-                };
-            }
+            let value = evaluate_expression(env, argument, titles, &row.values)?;
 
-            // Calculate the ordering
-            if let Some(order) = a_value.compare(b_value) {
-                if order == Ordering::Equal {
+            // Text values with `NOCASE` collation are compared by their lowercase version
+            if statement.collations[arg_index] == Collation::NoCase {
+                if let Some(text) = value.as_text() {
+                    let lowercase: Box<dyn Value> = Box::new(TextValue::new(text.to_lowercase()));
+                    arguments_values.push(Cow::Owned(lowercase));
                     continue;
                 }
+            }
+
+            arguments_values.push(Cow::Owned(value));
+        }
+
+        evaluated_rows.push(arguments_values);
+    }
+
+    let ordered_indices: Vec<usize> = match limit {
+        Some(limit) if limit < rows_len => {
+            // Keep the smallest `limit` rows in a max heap, ties are ordered by the row index to be stable
+            let mut heap: BinaryHeap<OrderedRow> = BinaryHeap::with_capacity(limit + 1);
+            for (index, values) in evaluated_rows.iter().enumerate() {
+                heap.push(OrderedRow {
+                    index,
+                    values,
+                    statement,
+                });
 
-                // Reverse the order if DESC order
-                return if statement.sorting_orders[arg_index] == SortingOrder::Descending {
-                    order.reverse()
-                } else {
-                    order
-                };
+                if heap.len() > limit {
+                    heap.pop();
+                }
             }
+
+            heap.into_sorted_vec().iter().map(|row| row.index).collect()
+        }
+        _ => {
+            let mut indices: Vec<usize> = (0..rows_len).collect();
+            indices
+                .sort_by(|a, b| compare_rows(statement, &evaluated_rows[*a], &evaluated_rows[*b]));
+            indices
         }
+    };
 
-        Ordering::Equal
-    });
+    let mut rows: Vec<Option<Row>> = main_group.rows.drain(..).map(Some).collect();
+    main_group.rows = ordered_indices
+        .iter()
+        .map(|index| rows[*index].take().unwrap())
+        .collect();
 
     Ok(())
 }
+
+/// Row pre evaluated ordering values with its original index, ordered like the stable sort
+struct OrderedRow<'a> {
+    index: usize,
+    values: &'a [Cow<'a, Box<dyn Value>>],
+    statement: &'a OrderByStatement,
+}
+
+impl Ord for OrderedRow<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_rows(self.statement, self.values, other.values).then(self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for OrderedRow<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for OrderedRow<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedRow<'_> {}
+
+fn compare_rows(
+    statement: &OrderByStatement,
+    a_values: &[Cow<Box<dyn Value>>],
+    b_values: &[Cow<Box<dyn Value>>],
+) -> Ordering {
+    for arg_index in 0..statement.arguments.len() {
+        let argument = &statement.arguments[arg_index];
+        // No need to compare if the ordering argument is constants
+        if argument.is_const() {
+            continue;
+        }
+
+        let a_value = a_values[arg_index].as_ref();
+        let b_value = b_values[arg_index].as_ref();
+
+        // Two NULL values are equal in ordering, so continue to the next argument
+        if a_value.is_null() && b_value.is_null() {
+            continue;
+        }
+
+        let null_ordering_policy = &statement.nulls_order_policies[arg_index];
+        if a_value.is_null() {
+            return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+
+        if b_value.is_null() {
+            return if null_ordering_policy.eq(&NullsOrderPolicy::NullsFirst) {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+
+        // Calculate the ordering, Int and Float values in the same column are compared as Float
+        let order = a_value.compare(b_value).or_else(|| {
+            let a_key = a_value.numeric_sort_key()?;
+            let b_key = b_value.numeric_sort_key()?;
+            a_key.partial_cmp(&b_key)
+        });
+
+        if let Some(order) = order {
+            if order == Ordering::Equal {
+                continue;
+            }
+
+            // Reverse the order if DESC order
+            return if statement.sorting_orders[arg_index] == SortingOrder::Descending {
+                order.reverse()
+            } else {
+                order
+            };
+        }
+    }
+
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::expression::Expr;
+    use gitql_ast::expression::SymbolExpr;
+    use gitql_ast::expression::SymbolFlag;
+    use gitql_ast::types::integer::IntType;
+    use gitql_core::schema::Schema;
+    use gitql_core::values::float::FloatValue;
+    use gitql_core::values::integer::IntValue;
+
+    use super::*;
+
+    fn symbol(name: &str) -> Box<dyn Expr> {
+        Box::new(SymbolExpr {
+            value: name.to_string(),
+            expr_type: Box::new(IntType),
+            flag: SymbolFlag::None,
+        })
+    }
+
+    #[test]
+    fn test_order_by_mixed_int_and_float() {
+        let values: Vec<Box<dyn Value>> = vec![
+            Box::new(FloatValue::new(2.5)),
+            Box::new(IntValue::new(3)),
+            Box::new(IntValue::new(1)),
+            Box::new(FloatValue::new(-0.5)),
+            Box::new(IntValue::new(2)),
+        ];
+
+        let rows = values
+            .into_iter()
+            .map(|value| Row {
+                values: vec![value],
+            })
+            .collect();
+
+        let mut gitql_object = GitQLObject {
+            titles: vec!["a".to_string()],
+            groups: vec![Group { rows }],
+            applied_optimizations: vec![],
+        };
+
+        let statement = OrderByStatement {
+            arguments: vec![symbol("a")],
+            sorting_orders: vec![SortingOrder::Ascending],
+            nulls_order_policies: vec![NullsOrderPolicy::NullsLast],
+            collations: vec![Collation::Binary],
+        };
+
+        let mut env = Environment::new(Schema::default());
+        assert!(execute_order_by_statement(&mut env, &statement, &mut gitql_object, 0).is_ok());
+
+        let ordered: Vec<String> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| row.values[0].literal())
+            .collect();
+
+        assert_eq!(ordered, vec!["-0.5", "1", "2", "2.5", "3"]);
+    }
+
+    fn int_or_null(value: Option<i64>) -> Box<dyn Value> {
+        match value {
+            Some(value) => Box::new(IntValue::new(value)),
+            None => Box::new(NullValue),
+        }
+    }
+
+    #[test]
+    fn test_order_by_direction_per_argument() {
+        let rows = [
+            (Some(1), 2),
+            (Some(2), 2),
+            (Some(2), 1),
+            (None, 4),
+            (None, 3),
+        ]
+        .into_iter()
+        .map(|(a, b)| Row {
+            values: vec![int_or_null(a), int_or_null(Some(b))],
+        })
+        .collect();
+
+        let mut gitql_object = GitQLObject {
+            titles: vec!["a".to_string(), "b".to_string()],
+            groups: vec![Group { rows }],
+            applied_optimizations: vec![],
+        };
+
+        // ORDER BY a DESC, b
+        let statement = OrderByStatement {
+            arguments: vec![symbol("a"), symbol("b")],
+            sorting_orders: vec![SortingOrder::Descending, SortingOrder::Ascending],
+            nulls_order_policies: vec![NullsOrderPolicy::NullsFirst, NullsOrderPolicy::NullsLast],
+            collations: vec![Collation::Binary, Collation::Binary],
+        };
+
+        let mut env = Environment::new(Schema::default());
+        assert!(execute_order_by_statement(&mut env, &statement, &mut gitql_object, 0).is_ok());
+
+        let ordered: Vec<(Option<i64>, Option<i64>)> = gitql_object.groups[0]
+            .rows
+            .iter()
+            .map(|row| (row.values[0].as_int(), row.values[1].as_int()))
+            .collect();
+
+        assert_eq!(
+            ordered,
+            vec![
+                (None, Some(3)),
+                (None, Some(4)),
+                (Some(2), Some(1)),
+                (Some(2), Some(2)),
+                (Some(1), Some(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_order_by_text_collation() {
+        let names = ["bob", "Alice", "carol", "Bob", "alice"];
+        let order_names = |collation: Collation, sorting_order: SortingOrder| -> Vec<String> {
+            let rows = names
+                .iter()
+                .map(|name| Row {
+                    values: vec![Box::new(TextValue::new(name.to_string()))],
+                })
+                .collect();
+
+            let mut gitql_object = GitQLObject {
+                titles: vec!["name".to_string()],
+                groups: vec![Group { rows }],
+                applied_optimizations: vec![],
+            };
+
+            let statement = OrderByStatement {
+                arguments: vec![symbol("name")],
+                sorting_orders: vec![sorting_order],
+                nulls_order_policies: vec![NullsOrderPolicy::NullsLast],
+                collations: vec![collation],
+            };
+
+            let mut env = Environment::new(Schema::default());
+            assert!(execute_order_by_statement(&mut env, &statement, &mut gitql_object, 0).is_ok());
+            gitql_object.groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].literal())
+                .collect()
+        };
+
+        assert_eq!(
+            order_names(Collation::Binary, SortingOrder::Ascending),
+            vec!["Alice", "Bob", "alice", "bob", "carol"]
+        );
+
+        // Sorting is stable, so equal names keep their original order
+        assert_eq!(
+            order_names(Collation::NoCase, SortingOrder::Ascending),
+            vec!["Alice", "alice", "bob", "Bob", "carol"]
+        );
+
+        assert_eq!(
+            order_names(Collation::NoCase, SortingOrder::Descending),
+            vec!["carol", "bob", "Bob", "Alice", "alice"]
+        );
+    }
+
+    #[test]
+    fn test_order_by_with_limit_same_as_full_sort() {
+        // Many ties and NULL values to make sure the bounded selection is stable like the full sort
+        let rows_count = 2000;
+        let create_gitql_object = || {
+            let rows = (0..rows_count)
+                .map(|index| {
+                    let key = (index * 7919) % 97;
+                    let key = if key % 10 == 0 { None } else { Some(key) };
+                    Row {
+                        values: vec![int_or_null(key), int_or_null(Some(index))],
+                    }
+                })
+                .collect();
+
+            GitQLObject {
+                titles: vec!["key".to_string(), "index".to_string()],
+                groups: vec![Group { rows }],
+                applied_optimizations: vec![],
+            }
+        };
+
+        let statement = OrderByStatement {
+            arguments: vec![symbol("key")],
+            sorting_orders: vec![SortingOrder::Descending],
+            nulls_order_policies: vec![NullsOrderPolicy::NullsFirst],
+            collations: vec![Collation::Binary],
+        };
+
+        let mut env = Environment::new(Schema::default());
+        let mut full_sorted = create_gitql_object();
+        assert!(execute_order_by_statement(&mut env, &statement, &mut full_sorted, 0).is_ok());
+        let full_sorted: Vec<Option<i64>> = full_sorted.groups[0]
+            .rows
+            .iter()
+            .map(|row| row.values[1].as_int())
+            .collect();
+
+        for limit in [0, 1, 7, 100, 1999, 2000, 5000] {
+            let mut gitql_object = create_gitql_object();
+            assert!(execute_order_by_statement_with_limit(
+                &mut env,
+                &statement,
+                &mut gitql_object,
+                0,
+                Some(limit)
+            )
+            .is_ok());
+
+            let top_n: Vec<Option<i64>> = gitql_object.groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[1].as_int())
+                .collect();
+
+            let expected_len = limit.min(rows_count as usize);
+            assert_eq!(top_n, full_sorted[..expected_len]);
+        }
+    }
+}
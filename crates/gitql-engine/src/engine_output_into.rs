@@ -5,10 +5,12 @@ use gitql_ast::statement::IntoStatement;
 use gitql_core::object::GitQLObject;
 use gitql_core::values::Value;
 
+use crate::runtime_error::RuntimeError;
+
 pub(crate) fn execute_into_statement(
     statement: &IntoStatement,
     gitql_object: &mut GitQLObject,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     let mut buffer = String::new();
 
     let line_terminated_by = &statement.lines_terminated;
@@ -35,13 +37,13 @@ pub(crate) fn execute_into_statement(
 
     let file_result = File::create(statement.file_path.clone());
     if let Err(error) = file_result {
-        return Err(error.to_string());
+        return Err(error.to_string().into());
     }
 
     let mut file = file_result.ok().unwrap();
     let write_result = file.write_all(buffer.as_bytes());
     if let Err(error) = write_result {
-        return Err(error.to_string());
+        return Err(error.to_string().into());
     }
 
     Ok(())
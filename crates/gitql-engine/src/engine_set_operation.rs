@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use gitql_ast::statement::SetOperator;
+use gitql_core::object::GitQLObject;
+use gitql_core::object::Group;
+use gitql_core::object::Row;
+
+/// Combine the rows of two `SELECT` queries results depending on the set operator,
+/// the result has the titles of the left query and all rows in one group
+///
+/// `UNION ALL` keeps all rows, other operators keep only the first row of each unique row values
+pub(crate) fn apply_set_operation(
+    operator: &SetOperator,
+    mut left: GitQLObject,
+    mut right: GitQLObject,
+) -> GitQLObject {
+    let left_rows = take_all_rows(&mut left);
+    let right_rows = take_all_rows(&mut right);
+
+    let rows = match operator {
+        SetOperator::UnionAll => left_rows.into_iter().chain(right_rows).collect(),
+        SetOperator::Union => unique_rows(left_rows.into_iter().chain(right_rows)),
+        SetOperator::Intersect => {
+            let right_keys: HashSet<Vec<String>> = right_rows.iter().map(row_key).collect();
+            unique_rows(
+                left_rows
+                    .into_iter()
+                    .filter(|row| right_keys.contains(&row_key(row))),
+            )
+        }
+        SetOperator::Except => {
+            let right_keys: HashSet<Vec<String>> = right_rows.iter().map(row_key).collect();
+            unique_rows(
+                left_rows
+                    .into_iter()
+                    .filter(|row| !right_keys.contains(&row_key(row))),
+            )
+        }
+    };
+
+    left.groups = vec![Group { rows }];
+    left
+}
+
+/// Take the rows of all groups in the object
+fn take_all_rows(object: &mut GitQLObject) -> Vec<Row> {
+    object
+        .groups
+        .iter_mut()
+        .flat_map(|group| std::mem::take(&mut group.rows))
+        .collect()
+}
+
+/// Keep only the first row of each unique row values
+fn unique_rows(rows: impl Iterator<Item = Row>) -> Vec<Row> {
+    let mut seen_keys: HashSet<Vec<String>> = HashSet::new();
+    rows.filter(|row| seen_keys.insert(row_key(row))).collect()
+}
+
+fn row_key(row: &Row) -> Vec<String> {
+    row.values.iter().map(|value| value.hash_key()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::integer::IntValue;
+
+    use super::*;
+
+    fn numbers_object(numbers: &[i64]) -> GitQLObject {
+        let rows = numbers
+            .iter()
+            .map(|number| Row {
+                values: vec![Box::new(IntValue::new(*number))],
+            })
+            .collect();
+
+        GitQLObject {
+            titles: vec!["number".to_string()],
+            groups: vec![Group { rows }],
+            applied_optimizations: vec![],
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let cases = [
+            (SetOperator::UnionAll, vec![1, 2, 2, 3, 2, 3, 4]),
+            (SetOperator::Union, vec![1, 2, 3, 4]),
+            (SetOperator::Intersect, vec![2, 3]),
+            (SetOperator::Except, vec![1]),
+        ];
+
+        for (operator, expected) in cases {
+            let left = numbers_object(&[1, 2, 2, 3]);
+            let right = numbers_object(&[2, 3, 4]);
+            let result = apply_set_operation(&operator, left, right);
+            let numbers: Vec<i64> = result.groups[0]
+                .rows
+                .iter()
+                .map(|row| row.values[0].as_int().unwrap())
+                .collect();
+            assert_eq!(result.titles, vec!["number"]);
+            assert_eq!(numbers, expected);
+        }
+    }
+}
@@ -12,13 +12,14 @@ use crate::engine_evaluator::evaluate_expression;
 use crate::engine_executor::resolve_actual_column_name;
 use crate::engine_group::execute_group_by_statement;
 use crate::engine_ordering::execute_order_by_statement;
+use crate::runtime_error::RuntimeError;
 
 pub(crate) fn execute_window_functions_statement(
     env: &mut Environment,
     statement: &WindowFunctionsStatement,
     gitql_object: &mut GitQLObject,
     alias_table: &HashMap<String, String>,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     if gitql_object.is_empty() {
         return Ok(());
     }
@@ -119,7 +120,7 @@ fn apply_window_definition_on_gitql_object(
     env: &mut Environment,
     gitql_object: &mut GitQLObject,
     window_definition: &WindowDefinition,
-) -> Result<(), String> {
+) -> Result<(), RuntimeError> {
     // Apply partitioning on the main group
     if let Some(partition_by) = &window_definition.partitioning_clause {
         let group_by = GroupByStatement {
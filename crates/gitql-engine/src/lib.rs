@@ -5,7 +5,10 @@ pub mod engine_evaluator;
 pub mod engine_executor;
 pub mod engine_filter;
 pub mod engine_group;
+pub mod engine_hoisting;
 pub mod engine_join;
 pub mod engine_ordering;
 pub mod engine_output_into;
+pub mod engine_set_operation;
 pub mod engine_window_functions;
+pub mod runtime_error;
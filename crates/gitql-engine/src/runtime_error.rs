@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use gitql_ast::location::SourceLocation;
+
+/// Error reported while evaluating the queries, with the location of the expression that failed if it's known
+#[derive(Clone, Debug)]
+pub struct RuntimeError {
+    pub message: String,
+    pub location: Option<SourceLocation>,
+}
+
+impl RuntimeError {
+    /// Create Instance of [`RuntimeError`] without location
+    pub fn new(message: String) -> Self {
+        RuntimeError {
+            message,
+            location: None,
+        }
+    }
+
+    /// Set the location only if it's not set yet, so the inner most failed expression is reported
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        if self.location.is_none() {
+            self.location = Some(location);
+        }
+        self
+    }
+}
+
+impl From<String> for RuntimeError {
+    fn from(message: String) -> Self {
+        RuntimeError::new(message)
+    }
+}
+
+impl Display for RuntimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
+use std::ops::Range;
 
 use gitql_ast::statement::AggregateValue;
+use gitql_ast::statement::GQLQuery;
 use gitql_ast::statement::WindowDefinition;
 use gitql_ast::statement::WindowValue;
 
@@ -17,10 +19,29 @@ pub struct ParserContext {
     pub selected_fields: Vec<String>,
     pub hidden_selections: Vec<String>,
 
+    /// Tokens range of each computed selected expression mapped to its column title
+    pub selected_expr_spans: Vec<(Range<usize>, String)>,
+
+    /// Titles of the selected columns in the selection order, used to resolve `ORDER BY` positions
+    pub selected_columns_titles: Vec<String>,
+
+    /// Selected tables referenced by their alias if exists or their name
     pub selected_tables: Vec<String>,
+    /// Tables aliases mapped to the original tables names
+    pub table_alias_table: HashMap<String, String>,
+    /// Qualified columns `table.column` used before `FROM` that are checked after parsing the tables
+    pub qualified_columns: Vec<(String, String, SourceLocation)>,
     pub projection_names: Vec<String>,
     pub projection_locations: Vec<SourceLocation>,
 
+    /// Generated names of the selected `ROWNUM()` fields
+    pub row_number_fields: Vec<String>,
+
+    /// Uncorrelated subqueries used in the query expressions like `IN (SELECT ...)`
+    pub subqueries: Vec<GQLQuery>,
+    /// Number of the outer queries that this query is nested inside as a subquery
+    pub subquery_depth: usize,
+
     pub name_alias_table: HashMap<String, String>,
     pub name_generator: NameGenerator,
 
@@ -32,4 +53,7 @@ pub struct ParserContext {
     pub inside_having: bool,
     pub inside_order_by: bool,
     pub inside_over_clauses: bool,
+    pub inside_join_predicate: bool,
+
+    pub current_clause: Option<&'static str>,
 }
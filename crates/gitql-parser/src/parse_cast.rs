@@ -84,6 +84,7 @@ fn cast_expression_or_error(
         return Ok(Box::new(CastExpr {
             value: expr,
             result_type: target_type,
+            location: Some(location),
         }));
     }
 
@@ -96,20 +97,55 @@ fn cast_expression_or_error(
             let casting = Box::new(CastExpr {
                 value: expr,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             return Ok(Box::new(CastExpr {
                 value: casting,
                 result_type: target_type,
+                location: Some(location),
             }));
         }
     }
 
-    Err(Diagnostic::error(&format!(
+    let mut diagnostic = Diagnostic::error(&format!(
         "Unsupported `CAST` operator from type `{}` to type `{}`",
         value_type.literal(),
         target_type.literal(),
-    ))
-    .with_location(location)
-    .as_boxed())
+    ));
+
+    if !value_expected_types.is_empty() {
+        let supported_types: Vec<String> = value_expected_types
+            .iter()
+            .map(|data_type| format!("`{}`", data_type.literal()))
+            .collect();
+        diagnostic = diagnostic.add_note(&format!(
+            "Type `{}` can be explicitly casted only to {}",
+            value_type.literal(),
+            supported_types.join(", ")
+        ));
+    }
+
+    // Text can be casted to date and time types only if it's a literal with a valid format
+    if value_type.is_text() {
+        let format = if target_type.is_date() {
+            Some("YYYY-MM-DD")
+        } else if target_type.is_time() {
+            Some("HH:MM:SS")
+        } else if target_type.is_date_time() {
+            Some("YYYY-MM-DD HH:MM:SS")
+        } else {
+            None
+        };
+
+        if let Some(format) = format {
+            diagnostic = diagnostic.add_help(&format!(
+                "Only Text literal with format `{}` can be casted to `{}`",
+                format,
+                target_type.literal()
+            ));
+        }
+    }
+
+    Err(diagnostic.with_location(location).as_boxed())
 }
@@ -1,7 +1,10 @@
+use gitql_ast::expression::BooleanExpr;
 use gitql_ast::expression::CastExpr;
 use gitql_ast::expression::ComparisonExpr;
 use gitql_ast::expression::Expr;
 use gitql_ast::expression::GroupComparisonExpr;
+use gitql_ast::expression::Number;
+use gitql_ast::expression::NumberExpr;
 use gitql_ast::operator::ComparisonOperator;
 use gitql_ast::operator::GroupComparisonOperator;
 use gitql_core::environment::Environment;
@@ -19,7 +22,7 @@ pub(crate) fn parse_comparison_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let lhs = parse_contains_expression(context, env, tokens, position)?;
+    let mut lhs = parse_contains_expression(context, env, tokens, position)?;
 
     if is_comparison_operator(tokens, position) {
         let operator = &tokens[*position];
@@ -45,7 +48,7 @@ pub(crate) fn parse_comparison_expression(
             )?;
         }
 
-        let rhs = parse_contains_expression(context, env, tokens, position)?;
+        let mut rhs = parse_contains_expression(context, env, tokens, position)?;
 
         // Consume `)` after Group operator expression if exists
         if has_group_op {
@@ -60,6 +63,11 @@ pub(crate) fn parse_comparison_expression(
             )?;
         }
 
+        // Check for Boolean compared with `0` or `1` integer literal
+        if !has_group_op && matches!(operator.kind, TokenKind::Equal | TokenKind::BangEqual) {
+            resolve_boolean_compared_with_integer(env, &mut lhs, &mut rhs, operator)?;
+        }
+
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
@@ -91,6 +99,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -117,6 +126,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -171,6 +181,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -197,6 +208,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -252,6 +264,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -278,6 +291,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -325,6 +339,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -351,6 +366,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -398,6 +414,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -424,6 +441,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -471,6 +489,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -497,6 +516,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -544,6 +564,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -570,6 +591,7 @@ pub(crate) fn parse_comparison_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 return Ok(create_comparison_expression(
@@ -593,7 +615,7 @@ pub(crate) fn parse_comparison_expression(
     Ok(lhs)
 }
 
-fn parse_optional_group_operator(
+pub(crate) fn parse_optional_group_operator(
     tokens: &[Token],
     position: &mut usize,
 ) -> Option<GroupComparisonOperator> {
@@ -636,6 +658,53 @@ fn create_comparison_expression(
     })
 }
 
+/// Replace `0` or `1` integer literal compared with Boolean by `FALSE` or `TRUE` if the coercion is enabled,
+/// otherwise report error that suggest using the Boolean literal
+fn resolve_boolean_compared_with_integer(
+    env: &Environment,
+    lhs: &mut Box<dyn Expr>,
+    rhs: &mut Box<dyn Expr>,
+    operator: &Token,
+) -> Result<(), Box<Diagnostic>> {
+    let (boolean_type, literal) = if lhs.expr_type().is_bool() {
+        (lhs.expr_type(), rhs)
+    } else if rhs.expr_type().is_bool() {
+        (rhs.expr_type(), lhs)
+    } else {
+        return Ok(());
+    };
+
+    let is_true = match literal.as_any().downcast_ref::<NumberExpr>() {
+        Some(NumberExpr {
+            value: Number::Int(1),
+        }) => true,
+        Some(NumberExpr {
+            value: Number::Int(0),
+        }) => false,
+        _ => return Ok(()),
+    };
+
+    if env.boolean_integer_coercion {
+        *literal = Box::new(BooleanExpr { is_true });
+        return Ok(());
+    }
+
+    let boolean_literal = if is_true { "TRUE" } else { "FALSE" };
+    Err(Diagnostic::error(&format!(
+        "Operator `{}` can't be performed between types `{}` and `{}`",
+        operator,
+        boolean_type,
+        literal.expr_type()
+    ))
+    .add_help(&format!(
+        "Try to use `{} {}` instead of `{} {}`",
+        operator, boolean_literal, operator, is_true as i64
+    ))
+    .add_note("Comparing Boolean with `0` and `1` can be enabled by the Boolean integer coercion")
+    .with_location(operator.location)
+    .as_boxed())
+}
+
 #[inline(always)]
 fn is_comparison_operator(tokens: &[Token], position: &usize) -> bool {
     *position < tokens.len()
@@ -1,5 +1,9 @@
 use gitql_ast::expression::CallExpr;
+use gitql_ast::expression::CaseExpr;
+use gitql_ast::expression::CastExpr;
+use gitql_ast::expression::CoalesceExpr;
 use gitql_ast::expression::Expr;
+use gitql_ast::expression::StringExpr;
 use gitql_ast::expression::SymbolExpr;
 use gitql_ast::expression::SymbolFlag;
 use gitql_ast::statement::AggregateValue;
@@ -11,20 +15,69 @@ use gitql_ast::statement::WindowOrderingClause;
 use gitql_ast::statement::WindowPartitioningClause;
 use gitql_ast::statement::WindowValue;
 use gitql_core::environment::Environment;
+use gitql_std::datetime::DATE_DIFF_UNITS;
+use gitql_std::datetime::DATE_PART_FIELDS;
 
 use crate::context::ParserContext;
 use crate::diagnostic::Diagnostic;
+use crate::parser::calculate_safe_location;
 use crate::parser::consume_token_or_error;
+use crate::parser::expression_literal;
 use crate::parser::is_current_token;
 use crate::parser::is_current_token_with_condition;
 use crate::parser::parse_expression;
 use crate::parser::parse_member_access_expression;
 use crate::parser::parse_order_by_statement;
 use crate::parser::parse_zero_or_more_values_with_comma_between;
+use crate::token::SourceLocation;
 use crate::token::Token;
 use crate::token::TokenKind;
 use crate::type_checker::check_function_call_arguments;
 use crate::type_checker::resolve_dynamic_data_type;
+use crate::type_checker::resolve_values_common_type;
+
+type SpecialFormParser = fn(
+    &mut ParserContext,
+    &mut Environment,
+    &[Token],
+    &mut usize,
+    SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>>;
+
+type SpecialFormFunction = (&'static str, fn(&[Token], usize) -> bool, SpecialFormParser);
+
+/// Functions with a special call form, parsed by their own parser when the form check
+/// on the tokens starting at `(` passes, otherwise they are parsed as normal calls
+const SPECIAL_FORM_FUNCTIONS: [SpecialFormFunction; 5] = [
+    ("rownum", is_any_call_form, parse_rownum_function_expression),
+    (
+        "substring",
+        is_function_from_form,
+        parse_substring_from_expression,
+    ),
+    (
+        "extract",
+        is_function_from_form,
+        parse_extract_from_expression,
+    ),
+    ("if", is_any_call_form, parse_if_function_expression),
+    (
+        "coalesce",
+        is_any_call_form,
+        parse_coalesce_function_expression,
+    ),
+];
+
+type ArgumentsChecker = fn(&[Box<dyn Expr>], SourceLocation) -> Result<(), Box<Diagnostic>>;
+
+/// Extra checks on the Std functions literal arguments after type checking them
+const STD_FUNCTIONS_ARGUMENTS_CHECKERS: [(&str, ArgumentsChecker); 5] = [
+    ("format", check_format_placeholders_count),
+    ("like_match", check_like_match_pattern),
+    ("datediff", check_date_diff_unit),
+    ("timestampdiff", check_date_diff_unit),
+    ("date_part", check_date_part_field),
+];
 
 pub(crate) fn parse_function_call_expression(
     context: &mut ParserContext,
@@ -44,6 +97,37 @@ pub(crate) fn parse_function_call_expression(
         // Consume function name
         *position += 1;
 
+        // `DISTINCT` modifier is only meaningful for aggregation functions arguments,
+        // unknown functions are reported later as unknown names
+        let is_known_non_aggregation_function =
+            env.is_std_function(function_name) || env.is_window_function(function_name);
+        if is_known_non_aggregation_function
+            && is_current_token(tokens, &(*position + 1), TokenKind::Distinct)
+        {
+            return Err(Diagnostic::error(&format!(
+                "Function `{}` doesn't support `DISTINCT` modifier",
+                function_name
+            ))
+            .add_note("`DISTINCT` can be used only inside aggregation functions arguments")
+            .add_help(&format!(
+                "Remove `DISTINCT` keyword from `{}` arguments",
+                function_name
+            ))
+            .with_location(tokens[*position + 1].location)
+            .as_boxed());
+        }
+
+        // Check for functions with a special call form like `EXTRACT(<field> FROM <date>)`
+        if let Some((_, _, parse_special_form)) =
+            SPECIAL_FORM_FUNCTIONS
+                .iter()
+                .find(|(name, is_special_form, _)| {
+                    name == function_name && is_special_form(tokens, *position)
+                })
+        {
+            return parse_special_form(context, env, tokens, position, function_name_location);
+        }
+
         // Check if this function is a Standard library functions
         if env.is_std_function(function_name) {
             let mut arguments = parse_zero_or_more_values_with_comma_between(
@@ -62,6 +146,13 @@ pub(crate) fn parse_function_call_expression(
                     function_name_location,
                 )?;
 
+                for (_, check_arguments) in STD_FUNCTIONS_ARGUMENTS_CHECKERS
+                    .iter()
+                    .filter(|(name, _)| name == function_name)
+                {
+                    check_arguments(&arguments, function_name_location)?;
+                }
+
                 let return_type = resolve_dynamic_data_type(
                     &signature.parameters,
                     &arguments,
@@ -89,22 +180,59 @@ pub(crate) fn parse_function_call_expression(
 
         // Check if this function is an Aggregation functions
         if env.is_aggregation_function(function_name) {
-            let mut arguments = parse_zero_or_more_values_with_comma_between(
-                context,
-                env,
-                tokens,
-                position,
-                "Aggregation function",
-            )?;
+            let is_distinct = is_current_token(tokens, &(*position + 1), TokenKind::Distinct);
+            let is_count_all = function_name == "count"
+                && is_current_token(tokens, &(*position + 1), TokenKind::Star)
+                && is_current_token(tokens, &(*position + 2), TokenKind::RightParen);
+
+            let mut arguments = if is_count_all {
+                // Consume `(`, `*` and `)`, `COUNT(*)` is equal to `COUNT()` that count all rows
+                *position += 3;
+                vec![]
+            } else if is_distinct {
+                parse_distinct_aggregation_arguments(context, env, tokens, position)?
+            } else {
+                parse_zero_or_more_values_with_comma_between(
+                    context,
+                    env,
+                    tokens,
+                    position,
+                    "Aggregation function",
+                )?
+            };
 
             if let Some(signature) = env.aggregation_signature(function_name.as_str()) {
-                // Perform type checking and implicit casting if needed for function arguments
-                check_function_call_arguments(
-                    &mut arguments,
-                    &signature.parameters,
-                    function_name.to_string(),
-                    function_name_location,
-                )?;
+                if is_distinct && arguments.len() > 1 {
+                    if function_name != "count" {
+                        return Err(Diagnostic::error(
+                            "Only `COUNT` supports `DISTINCT` with more than one argument",
+                        )
+                        .with_location(function_name_location)
+                        .as_boxed());
+                    }
+
+                    // Type check each argument of `COUNT(DISTINCT ...)` independently
+                    let mut checked_arguments = Vec::with_capacity(arguments.len());
+                    for argument in arguments {
+                        let mut single_argument = vec![argument];
+                        check_function_call_arguments(
+                            &mut single_argument,
+                            &signature.parameters,
+                            function_name.to_string(),
+                            function_name_location,
+                        )?;
+                        checked_arguments.append(&mut single_argument);
+                    }
+                    arguments = checked_arguments;
+                } else {
+                    // Perform type checking and implicit casting if needed for function arguments
+                    check_function_call_arguments(
+                        &mut arguments,
+                        &signature.parameters,
+                        function_name.to_string(),
+                        function_name_location,
+                    )?;
+                }
 
                 let column_name = context.name_generator.generate_column_name();
                 context.hidden_selections.push(column_name.to_string());
@@ -129,6 +257,14 @@ pub(crate) fn parse_function_call_expression(
                     .as_boxed());
                 }
 
+                if is_used_as_window_function && is_distinct {
+                    return Err(Diagnostic::error(
+                        "`DISTINCT` is not supported in aggregation used as window function",
+                    )
+                    .with_location(function_name_location)
+                    .as_boxed());
+                }
+
                 let mut flag = SymbolFlag::AggregationReference;
                 if is_used_as_window_function {
                     // Consume `OVER` token
@@ -150,7 +286,11 @@ pub(crate) fn parse_function_call_expression(
 
                     flag = SymbolFlag::WindowReference;
                 } else {
-                    let function = AggregateValue::Function(function_name.to_string(), arguments);
+                    let function = if is_distinct {
+                        AggregateValue::DistinctFunction(function_name.to_string(), arguments)
+                    } else {
+                        AggregateValue::Function(function_name.to_string(), arguments)
+                    };
                     context.aggregations.insert(column_name.clone(), function);
                 }
 
@@ -292,6 +432,486 @@ pub(crate) fn parse_function_call_expression(
     parse_member_access_expression(context, env, tokens, position)
 }
 
+/// Parse `(DISTINCT <expr>, ...)` arguments of aggregation function
+fn parse_distinct_aggregation_arguments(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Vec<Box<dyn Expr>>, Box<Diagnostic>> {
+    // Consume `(` token
+    *position += 1;
+
+    // Consume `DISTINCT` keyword
+    let distinct_location = tokens[*position].location;
+    *position += 1;
+
+    let mut arguments: Vec<Box<dyn Expr>> = vec![];
+    while !is_current_token(tokens, position, TokenKind::RightParen) {
+        let argument = parse_expression(context, env, tokens, position)?;
+        if let Some(argument_literal) = expression_literal(&argument) {
+            context.hidden_selections.push(argument_literal);
+        }
+
+        arguments.push(argument);
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume `,` token
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    if arguments.is_empty() {
+        return Err(
+            Diagnostic::error("Expect one or more arguments after `DISTINCT`")
+                .with_location(distinct_location)
+                .as_boxed(),
+        );
+    }
+
+    // Consume `)` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of aggregation function arguments",
+    )?;
+
+    Ok(arguments)
+}
+
+/// Match every call form of the function
+fn is_any_call_form(_tokens: &[Token], _position: usize) -> bool {
+    true
+}
+
+/// `ROWNUM()` is only valid as a selected field, see `parse_row_number_field`
+fn parse_rownum_function_expression(
+    _context: &mut ParserContext,
+    _env: &mut Environment,
+    _tokens: &[Token],
+    _position: &mut usize,
+    function_name_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    Err(
+        Diagnostic::error("`ROWNUM()` can only be used as a selected field")
+            .add_note("Row numbers are assigned after `ORDER BY`, `OFFSET` and `LIMIT`")
+            .add_help("Use `ROW_NUMBER() OVER (...)` to number rows inside expressions")
+            .with_location(function_name_location)
+            .as_boxed(),
+    )
+}
+
+/// Check if the arguments starting at `(` contains `FROM` keyword in the top level
+fn is_function_from_form(tokens: &[Token], position: usize) -> bool {
+    let mut depth = 0;
+    for token in tokens.iter().skip(position) {
+        match token.kind {
+            TokenKind::LeftParen => depth += 1,
+            TokenKind::RightParen => {
+                depth -= 1;
+                if depth == 0 {
+                    return false;
+                }
+            }
+            TokenKind::From if depth == 1 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Parse `SUBSTRING(<text> FROM <pattern>)` as a call to `regexp_extract(<text>, <pattern>)`
+fn parse_substring_from_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    function_name_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    // Consume `(` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after SUBSTRING",
+    )?;
+
+    let input = parse_expression(context, env, tokens, position)?;
+
+    // Consume `FROM` keyword
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::From,
+        "Expect `FROM` after SUBSTRING input",
+    )?;
+
+    let pattern_location = calculate_safe_location(tokens, *position);
+    let pattern = parse_expression(context, env, tokens, position)?;
+    if !pattern.expr_type().is_text() {
+        return Err(Diagnostic::error(&format!(
+            "SUBSTRING `FROM` expect a Text pattern but got `{}`",
+            pattern.expr_type().literal()
+        ))
+        .add_help("Use SUBSTRING(text, start, length) to extract characters by position")
+        .with_location(pattern_location)
+        .as_boxed());
+    }
+
+    // Consume `)` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of SUBSTRING",
+    )?;
+
+    let function_name = "regexp_extract";
+    let mut arguments = vec![input, pattern];
+    if let Some(signature) = env.std_signature(function_name) {
+        check_function_call_arguments(
+            &mut arguments,
+            &signature.parameters,
+            "substring".to_string(),
+            function_name_location,
+        )?;
+
+        let return_type = signature.return_type.clone();
+        env.define(function_name.to_string(), return_type.clone());
+
+        return Ok(Box::new(CallExpr {
+            function_name: function_name.to_string(),
+            arguments,
+            return_type,
+        }));
+    }
+
+    Err(Diagnostic::error(&format!(
+        "Can't find signature for function with name {}",
+        function_name
+    ))
+    .with_location(function_name_location)
+    .as_boxed())
+}
+
+/// Make sure that the `FORMAT` literal format has one `%s` placeholder for each argument
+fn check_format_placeholders_count(
+    arguments: &[Box<dyn Expr>],
+    function_name_location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    let Some(format) = arguments[0].as_any().downcast_ref::<StringExpr>() else {
+        return Ok(());
+    };
+
+    let mut placeholders_count = 0;
+    let mut chars = format.value.chars();
+    while let Some(char) = chars.next() {
+        if char == '%' && chars.next() == Some('s') {
+            placeholders_count += 1;
+        }
+    }
+
+    let arguments_count = arguments.len() - 1;
+    if placeholders_count != arguments_count {
+        return Err(Diagnostic::error(&format!(
+            "FORMAT has {} `%s` placeholders but got {} arguments",
+            placeholders_count, arguments_count
+        ))
+        .add_help("Pass one argument for each `%s` placeholder")
+        .add_note("Use `%%` to write a literal `%` in the format")
+        .with_location(function_name_location)
+        .as_boxed());
+    }
+
+    Ok(())
+}
+
+/// Make sure that the `DATEDIFF` literal unit is one of the supported units
+fn check_date_diff_unit(
+    arguments: &[Box<dyn Expr>],
+    function_name_location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    let Some(unit) = arguments[0].as_any().downcast_ref::<StringExpr>() else {
+        return Ok(());
+    };
+
+    if !DATE_DIFF_UNITS.contains(&unit.value.to_lowercase().as_str()) {
+        return Err(
+            Diagnostic::error(&format!("Unknown DATEDIFF unit `{}`", unit.value))
+                .add_help(&format!(
+                    "Supported units are {}",
+                    names_list_literal(&DATE_DIFF_UNITS)
+                ))
+                .with_location(function_name_location)
+                .as_boxed(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Make sure that the `DATE_PART` or `EXTRACT` literal field is one of the supported fields
+fn check_date_part_field(
+    arguments: &[Box<dyn Expr>],
+    location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    let Some(field) = arguments[0].as_any().downcast_ref::<StringExpr>() else {
+        return Ok(());
+    };
+
+    if !DATE_PART_FIELDS.contains(&field.value.to_lowercase().as_str()) {
+        return Err(
+            Diagnostic::error(&format!("Unknown date part field `{}`", field.value))
+                .add_help(&format!(
+                    "Supported fields are {}",
+                    names_list_literal(&DATE_PART_FIELDS)
+                ))
+                .with_location(location)
+                .as_boxed(),
+        );
+    }
+
+    Ok(())
+}
+
+/// Format names as "`a`, `b` and `c`" to list them in diagnostics help
+fn names_list_literal(names: &[&str]) -> String {
+    let quoted: Vec<String> = names.iter().map(|name| format!("`{}`", name)).collect();
+    match quoted.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+        None => String::new(),
+    }
+}
+
+/// Make sure that the `LIKE_MATCH` literal pattern has exactly one `%` wildcard and no `_`
+fn check_like_match_pattern(
+    arguments: &[Box<dyn Expr>],
+    function_name_location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    let Some(pattern) = arguments[1].as_any().downcast_ref::<StringExpr>() else {
+        return Ok(());
+    };
+
+    if pattern.value.contains('_') || pattern.value.matches('%').count() != 1 {
+        return Err(Diagnostic::error(
+            "LIKE_MATCH pattern must have exactly one `%` wildcard and no `_` wildcard",
+        )
+        .add_help("Use a pattern like `prefix%suffix` to extract the text between them")
+        .add_note("For other patterns use `LIKE` to match or `REGEXP_SUBSTR` to extract")
+        .with_location(function_name_location)
+        .as_boxed());
+    }
+
+    Ok(())
+}
+
+/// Parse `EXTRACT(<field> FROM <date>)` as a call to `date_part('<field>', <date>)`
+fn parse_extract_from_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    function_name_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    // Consume `(` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after EXTRACT",
+    )?;
+
+    let field_location = calculate_safe_location(tokens, *position);
+    let field = match &tokens[*position].kind {
+        TokenKind::Symbol(field) | TokenKind::String(field) => field.to_lowercase(),
+        _ => {
+            return Err(
+                Diagnostic::error("EXTRACT expect a field name before `FROM`")
+                    .add_help("Try to use field name like `year`, `month` or `day`")
+                    .with_location(field_location)
+                    .as_boxed(),
+            );
+        }
+    };
+
+    // Consume field name
+    *position += 1;
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::From,
+        "Expect `FROM` after EXTRACT field name",
+    )?;
+
+    let input = parse_expression(context, env, tokens, position)?;
+
+    // Consume `)` token
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of EXTRACT",
+    )?;
+
+    let function_name = "date_part";
+    let mut arguments: Vec<Box<dyn Expr>> = vec![Box::new(StringExpr { value: field }), input];
+    if let Some(signature) = env.std_signature(function_name) {
+        check_function_call_arguments(
+            &mut arguments,
+            &signature.parameters,
+            "extract".to_string(),
+            function_name_location,
+        )?;
+        check_date_part_field(&arguments, field_location)?;
+
+        let return_type = signature.return_type.clone();
+        env.define(function_name.to_string(), return_type.clone());
+
+        return Ok(Box::new(CallExpr {
+            function_name: function_name.to_string(),
+            arguments,
+            return_type,
+        }));
+    }
+
+    Err(Diagnostic::error(&format!(
+        "Can't find signature for function with name {}",
+        function_name
+    ))
+    .with_location(function_name_location)
+    .as_boxed())
+}
+
+/// Parse `IF(<condition>, <then>, <else>)` as `CASE WHEN <condition> THEN <then> ELSE <else> END`
+fn parse_if_function_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    function_name_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let mut arguments =
+        parse_zero_or_more_values_with_comma_between(context, env, tokens, position, "IF")?;
+
+    if arguments.len() != 3 {
+        return Err(Diagnostic::error(&format!(
+            "Function `if` expects `3` arguments but got `{}`",
+            arguments.len()
+        ))
+        .add_help("Use IF(condition, then_value, else_value)")
+        .with_location(function_name_location)
+        .as_boxed());
+    }
+
+    let mut else_value = arguments.pop().unwrap();
+    let mut then_value = arguments.pop().unwrap();
+    let condition = arguments.pop().unwrap();
+
+    let condition_type = condition.expr_type();
+    if !condition_type.is_bool() {
+        return Err(Diagnostic::error(&format!(
+            "Function `if` condition must be a Boolean but got `{}`",
+            condition_type.literal()
+        ))
+        .with_location(function_name_location)
+        .as_boxed());
+    }
+
+    // Unify the two branches types, Integer and Float branches are always unified to Float
+    // otherwise one branch must be implicitly castable to the type of the other
+    let then_type = then_value.expr_type();
+    let else_type = else_value.expr_type();
+    let values_type = if then_type.equals(&else_type) || else_type.is_null() {
+        then_type
+    } else if then_type.is_null() {
+        else_type
+    } else if then_type.is_float() && else_type.is_int() {
+        else_value = Box::new(CastExpr {
+            value: else_value,
+            result_type: then_type.clone(),
+            location: None,
+        });
+        then_type
+    } else if then_type.is_int() && else_type.is_float() {
+        then_value = Box::new(CastExpr {
+            value: then_value,
+            result_type: else_type.clone(),
+            location: None,
+        });
+        else_type
+    } else if then_type.has_implicit_cast_from(&else_value) {
+        else_value = Box::new(CastExpr {
+            value: else_value,
+            result_type: then_type.clone(),
+            location: None,
+        });
+        then_type
+    } else if else_type.has_implicit_cast_from(&then_value) {
+        then_value = Box::new(CastExpr {
+            value: then_value,
+            result_type: else_type.clone(),
+            location: None,
+        });
+        else_type
+    } else {
+        return Err(Diagnostic::error(&format!(
+            "Function `if` values must have the same type but got `{}` and `{}`",
+            then_type.literal(),
+            else_type.literal()
+        ))
+        .with_location(function_name_location)
+        .as_boxed());
+    };
+
+    Ok(Box::new(CaseExpr {
+        conditions: vec![condition],
+        values: vec![then_value],
+        default_value: Some(else_value),
+        values_type,
+    }))
+}
+
+fn parse_coalesce_function_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    function_name_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let values =
+        parse_zero_or_more_values_with_comma_between(context, env, tokens, position, "COALESCE")?;
+
+    if values.len() < 2 {
+        return Err(Diagnostic::error(&format!(
+            "Function `coalesce` expects at least `2` arguments but got `{}`",
+            values.len()
+        ))
+        .add_help("Use COALESCE(value, fallback_value, ...)")
+        .with_location(function_name_location)
+        .as_boxed());
+    }
+
+    let values_type = resolve_values_common_type(values.iter()).map_err(|i| {
+        Diagnostic::error(&format!(
+            "Function `coalesce` argument {} has different type than the other arguments",
+            i + 1
+        ))
+        .add_note("All arguments of `COALESCE` must has the same Type or NULL")
+        .with_location(function_name_location)
+        .as_boxed()
+    })?;
+
+    Ok(Box::new(CoalesceExpr {
+        values,
+        values_type,
+    }))
+}
+
 pub(crate) fn parse_over_window_definition(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -1,4 +1,6 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::ops::Range;
 use std::vec;
 
 use gitql_ast::expression::ArithmeticExpr;
@@ -10,12 +12,14 @@ use gitql_ast::expression::*;
 use gitql_ast::operator::ArithmeticOperator;
 use gitql_ast::operator::BinaryBitwiseOperator;
 use gitql_ast::operator::BinaryLogicalOperator;
+use gitql_ast::operator::ComparisonOperator;
 use gitql_ast::operator::PrefixUnaryOperator;
 use gitql_ast::statement::*;
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::array::ArrayType;
 use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::composite::CompositeType;
+use gitql_ast::types::integer::IntType;
 use gitql_ast::types::undefined::UndefType;
 use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
@@ -25,13 +29,18 @@ use crate::diagnostic::Diagnostic;
 use crate::parse_cast::parse_cast_call_expression;
 use crate::parse_cast::parse_cast_operator_expression;
 use crate::parse_comparisons::parse_comparison_expression;
+use crate::parse_comparisons::parse_optional_group_operator;
 use crate::parse_function_call::parse_function_call_expression;
 use crate::parse_function_call::parse_over_window_definition;
 use crate::parse_interval::parse_interval_expression;
 use crate::token::SourceLocation;
 use crate::token::Token;
 use crate::token::TokenKind;
-use crate::type_checker::check_all_values_are_same_type;
+use crate::type_checker::ambiguous_column_error;
+use crate::type_checker::resolve_table_name;
+use crate::type_checker::resolve_values_common_type;
+use crate::type_checker::split_qualified_column;
+use crate::type_checker::tables_with_column;
 use crate::type_checker::type_check_and_classify_selected_fields;
 use crate::type_checker::type_check_projection_symbols;
 
@@ -42,14 +51,29 @@ pub fn parse_gql(tokens: Vec<Token>, env: &mut Environment) -> Result<Vec<Query>
     while position < tokens.len() {
         env.clear_session();
 
+        // Each statement is parsed in its own scope, so its definitions never leak to the next ones
+        env.push_scope();
+        let statement_position = position;
         let query = match &tokens[position].kind {
             TokenKind::Do => parse_do_query(env, &tokens, &mut position),
             TokenKind::Set => parse_set_query(env, &tokens, &mut position),
-            TokenKind::Select => parse_select_query(env, &tokens, &mut position),
+            TokenKind::Select => parse_select_or_compound_query(env, &tokens, &mut position),
             TokenKind::Describe => parse_describe_query(env, &tokens, &mut position),
             TokenKind::Show => parse_show_query(&tokens, &mut position),
             _ => Err(un_expected_statement_error(&tokens, &mut position)),
         }?;
+        env.pop_scope();
+
+        // `LIMIT` and `OFFSET` are parsed as part of `SELECT` query, so it's un expected after other statements
+        if is_current_token_with_condition(&tokens, &position, |token| {
+            matches!(token.kind, TokenKind::Limit | TokenKind::Offset)
+        }) {
+            return Err(un_expected_content_after_correct_statement(
+                &tokens[statement_position].to_string(),
+                &tokens,
+                &mut position,
+            ));
+        }
 
         // Consume optional `;` at the end of valid statement
         if let Some(last_token) = tokens.get(position) {
@@ -79,6 +103,7 @@ fn parse_do_query(
     position: &mut usize,
 ) -> Result<Query, Box<Diagnostic>> {
     // Consume Do keyword
+    let do_token = &tokens[*position];
     *position += 1;
 
     if *position >= tokens.len() {
@@ -91,9 +116,27 @@ fn parse_do_query(
 
     let mut context = ParserContext::default();
     let expression = parse_expression(&mut context, env, tokens, position)?;
+    check_subqueries_inside_select_query(&context, do_token)?;
     Ok(Query::Do(DoStatement { expression }))
 }
 
+/// Subqueries are evaluated before the query that uses them, so they are only supported in `SELECT` queries
+fn check_subqueries_inside_select_query(
+    context: &ParserContext,
+    statement_token: &Token,
+) -> Result<(), Box<Diagnostic>> {
+    if !context.subqueries.is_empty() {
+        return Err(Diagnostic::error(&format!(
+            "Subqueries can't be used inside `{}` statement",
+            statement_token
+        ))
+        .add_note("Subqueries can be used only inside `SELECT` queries")
+        .with_location(statement_token.location)
+        .as_boxed());
+    }
+    Ok(())
+}
+
 fn parse_set_query(
     env: &mut Environment,
     tokens: &[Token],
@@ -103,6 +146,7 @@ fn parse_set_query(
     let mut context = ParserContext::default();
 
     // Consume Set keyword
+    let set_token = &tokens[*position];
     *position += 1;
 
     if !is_current_token_with_condition(tokens, position, |token| {
@@ -144,6 +188,7 @@ fn parse_set_query(
         );
     }
 
+    check_subqueries_inside_select_query(&context, set_token)?;
     env.define_global(name.to_string(), value.expr_type());
 
     Ok(Query::GlobalVariableDeclaration(GlobalVariableStatement {
@@ -207,14 +252,233 @@ fn parse_show_query(tokens: &[Token], position: &mut usize) -> Result<Query, Box
     Ok(Query::ShowTables)
 }
 
+/// Parse `SELECT` query and the optional set operations after it like
+/// `SELECT ... UNION [ALL] SELECT ...`, `INTERSECT` and `EXCEPT`
+fn parse_select_or_compound_query(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Query, Box<Diagnostic>> {
+    let query = parse_select_query(env, tokens, position)?;
+    if current_set_operator_precedence(tokens, position).is_none() {
+        return Ok(query);
+    }
+
+    let mut columns_types = match &query {
+        Query::Select(gql_query) => {
+            check_set_operation_without_into(gql_query, &tokens[*position])?;
+            selected_columns_types(env, gql_query)
+        }
+        _ => vec![],
+    };
+
+    parse_set_operations(env, tokens, position, query, &mut columns_types, 0)
+}
+
+/// Return the precedence of the current set operator, `INTERSECT` binds tighter than
+/// `UNION` and `EXCEPT` the same as in standard SQL, or None if it's not a set operator
+fn current_set_operator_precedence(tokens: &[Token], position: &usize) -> Option<u8> {
+    match tokens.get(*position)?.kind {
+        TokenKind::Union | TokenKind::Except => Some(1),
+        TokenKind::Intersect => Some(2),
+        _ => None,
+    }
+}
+
+/// Parse the set operators with precedence at least `min_precedence` after the left query,
+/// operators with the same precedence are combined from left to right
+fn parse_set_operations(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    left: Query,
+    columns_types: &mut Vec<Box<dyn DataType>>,
+    min_precedence: u8,
+) -> Result<Query, Box<Diagnostic>> {
+    let mut query = left;
+    while let Some(precedence) = current_set_operator_precedence(tokens, position) {
+        if precedence < min_precedence {
+            break;
+        }
+
+        let operator_token = &tokens[*position];
+
+        // Consume Set operator keyword
+        *position += 1;
+
+        let operator = match operator_token.kind {
+            TokenKind::Union if is_current_token(tokens, position, TokenKind::All) => {
+                // Consume `ALL` keyword
+                *position += 1;
+                SetOperator::UnionAll
+            }
+            TokenKind::Union => SetOperator::Union,
+            TokenKind::Intersect => SetOperator::Intersect,
+            _ => SetOperator::Except,
+        };
+
+        let mut right = Query::Select(parse_set_operation_query(
+            env,
+            tokens,
+            position,
+            operator_token,
+            columns_types,
+        )?);
+
+        // Operators with higher precedence take the right query as their left query
+        if current_set_operator_precedence(tokens, position)
+            .is_some_and(|next_precedence| next_precedence > precedence)
+        {
+            right =
+                parse_set_operations(env, tokens, position, right, columns_types, precedence + 1)?;
+        }
+
+        query = Query::Compound(CompoundQuery {
+            left: Box::new(query),
+            right: Box::new(right),
+            operator,
+        });
+    }
+
+    Ok(query)
+}
+
+/// Parse the `SELECT` query after a set operator and check that its columns match the previous queries
+fn parse_set_operation_query(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    operator_token: &Token,
+    columns_types: &mut [Box<dyn DataType>],
+) -> Result<GQLQuery, Box<Diagnostic>> {
+    let operator_literal = operator_token.to_string();
+    if !is_current_token(tokens, position, TokenKind::Select) {
+        return Err(Diagnostic::error(&format!(
+            "Expect `SELECT` query after `{}`",
+            operator_literal
+        ))
+        .add_note("Set operations can be used only between two `SELECT` queries")
+        .with_location(operator_token.location)
+        .as_boxed());
+    }
+
+    // Each query has its own tables and columns
+    env.pop_scope();
+    env.push_scope();
+
+    let query = match parse_select_query(env, tokens, position)? {
+        Query::Select(gql_query) => gql_query,
+        _ => unreachable!(),
+    };
+
+    check_set_operation_without_into(&query, operator_token)?;
+
+    let query_columns_types = selected_columns_types(env, &query);
+    if columns_types.len() != query_columns_types.len() {
+        return Err(Diagnostic::error(&format!(
+            "Each `{}` query must have the same number of columns",
+            operator_literal
+        ))
+        .add_note(&format!(
+            "Left query has {} columns but right query has {} columns",
+            columns_types.len(),
+            query_columns_types.len()
+        ))
+        .with_location(operator_token.location)
+        .as_boxed());
+    }
+
+    for (index, (left_type, right_type)) in columns_types
+        .iter()
+        .zip(query_columns_types.iter())
+        .enumerate()
+    {
+        let is_compatible = left_type.equals(right_type)
+            || left_type.is_null()
+            || right_type.is_null()
+            || left_type.is_undefined()
+            || right_type.is_undefined();
+
+        if !is_compatible {
+            return Err(Diagnostic::error(&format!(
+                "`{}` types `{}` and `{}` of column number {} can't be matched",
+                operator_literal,
+                left_type.literal(),
+                right_type.literal(),
+                index + 1
+            ))
+            .add_help("Use `CAST` to convert the column values to the same type")
+            .with_location(operator_token.location)
+            .as_boxed());
+        }
+    }
+
+    // Null columns take the type of the other query column
+    for (left_type, right_type) in columns_types.iter_mut().zip(query_columns_types) {
+        if left_type.is_null() || left_type.is_undefined() {
+            *left_type = right_type;
+        }
+    }
+
+    Ok(query)
+}
+
+/// Set operations results can't be stored in a file using `INTO` from any of its queries
+fn check_set_operation_without_into(
+    query: &GQLQuery,
+    operator_token: &Token,
+) -> Result<(), Box<Diagnostic>> {
+    if query.statements.contains_key("into") {
+        return Err(Diagnostic::error(&format!(
+            "Can't use `INTO` statement with `{}` set operation",
+            operator_token
+        ))
+        .add_note("`INTO` statement can't be used inside queries of set operations")
+        .with_location(operator_token.location)
+        .as_boxed());
+    }
+    Ok(())
+}
+
+/// Return the types of the selected columns in the same order of the query result titles
+fn selected_columns_types(env: &mut Environment, query: &GQLQuery) -> Vec<Box<dyn DataType>> {
+    let Some(statement) = query.statements.get("select") else {
+        return vec![];
+    };
+
+    let select_statement = statement
+        .as_any()
+        .downcast_ref::<SelectStatement>()
+        .unwrap();
+
+    select_statement
+        .table_selections
+        .iter()
+        .flat_map(|table_selection| table_selection.columns_names.iter())
+        .map(|column_name| resolve_symbol_type_or_undefine(env, column_name))
+        .collect()
+}
+
 fn parse_select_query(
     env: &mut Environment,
     tokens: &[Token],
     position: &mut usize,
+) -> Result<Query, Box<Diagnostic>> {
+    parse_select_query_with_depth(env, tokens, position, 0)
+}
+
+fn parse_select_query_with_depth(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    subquery_depth: usize,
 ) -> Result<Query, Box<Diagnostic>> {
     let len = tokens.len();
 
-    let mut context = ParserContext::default();
+    let mut context = ParserContext {
+        subquery_depth,
+        ..ParserContext::default()
+    };
     let mut statements: HashMap<&'static str, Box<dyn Statement>> = HashMap::new();
 
     while *position < len {
@@ -229,7 +493,8 @@ fn parse_select_query(
                         .with_location(token.location)
                         .as_boxed());
                 }
-                let statement = parse_select_statement(&mut context, env, tokens, position)?;
+                let statement = parse_select_statement(&mut context, env, tokens, position)
+                    .map_err(|diagnostic| with_clause_note(&context, diagnostic))?;
                 statements.insert("select", statement);
                 context.is_single_value_query = !context.aggregations.is_empty();
                 context.has_select_statement = true;
@@ -242,7 +507,8 @@ fn parse_select_query(
                         .as_boxed());
                 }
 
-                let statement = parse_where_statement(&mut context, env, tokens, position)?;
+                let statement = parse_where_statement(&mut context, env, tokens, position)
+                    .map_err(|diagnostic| with_clause_note(&context, diagnostic))?;
                 statements.insert("where", statement);
             }
             TokenKind::Group => {
@@ -253,7 +519,8 @@ fn parse_select_query(
                         .as_boxed());
                 }
 
-                let statement = parse_group_by_statement(&mut context, env, tokens, position)?;
+                let statement = parse_group_by_statement(&mut context, env, tokens, position)
+                    .map_err(|diagnostic| with_clause_note(&context, diagnostic))?;
                 statements.insert("group", statement);
             }
             TokenKind::Having => {
@@ -275,7 +542,8 @@ fn parse_select_query(
                     .as_boxed());
                 }
 
-                let statement = parse_having_statement(&mut context, env, tokens, position)?;
+                let statement = parse_having_statement(&mut context, env, tokens, position)
+                    .map_err(|diagnostic| with_clause_note(&context, diagnostic))?;
                 statements.insert("having", statement);
             }
             TokenKind::Limit => {
@@ -329,7 +597,14 @@ fn parse_select_query(
                             }
 
                             let count = integer as usize;
-                            statements.insert("offset", Box::new(OffsetStatement { count }));
+                            let is_percentage = false;
+                            statements.insert(
+                                "offset",
+                                Box::new(OffsetStatement {
+                                    count,
+                                    is_percentage,
+                                }),
+                            );
                         }
                         _ => {
                             return Err(Diagnostic::error("`OFFSET` integer value is invalid")
@@ -362,7 +637,8 @@ fn parse_select_query(
                         .as_boxed());
                 }
 
-                let statement = parse_order_by_statement(&mut context, env, tokens, position)?;
+                let statement = parse_order_by_statement(&mut context, env, tokens, position)
+                    .map_err(|diagnostic| with_clause_note(&context, diagnostic))?;
                 statements.insert("order", statement);
             }
             TokenKind::Into => {
@@ -422,23 +698,30 @@ fn parse_select_query(
         );
     }
 
-    // Remove all selected fields from hidden selection
-    let hidden_selections: Vec<String> = context
-        .hidden_selections
-        .iter()
-        .filter(|n| !context.selected_fields.contains(n))
-        .cloned()
-        .collect();
+    // Remove all selected fields and duplicated symbols from hidden selection
+    let mut hidden_selections: Vec<String> = Vec::with_capacity(context.hidden_selections.len());
+    for hidden_selection in context.hidden_selections.iter() {
+        if !context.selected_fields.contains(hidden_selection)
+            && !hidden_selections.contains(hidden_selection)
+        {
+            hidden_selections.push(hidden_selection.to_string());
+        }
+    }
 
     type_check_projection_symbols(
         env,
         &context.selected_tables,
+        &context.table_alias_table,
         &context.projection_names,
         &context.projection_locations,
     )?;
 
-    let hidden_selection_per_table =
-        classify_hidden_selection(env, &context.selected_tables, &hidden_selections);
+    let hidden_selection_per_table = classify_hidden_selection(
+        env,
+        &context.selected_tables,
+        &context.table_alias_table,
+        &hidden_selections,
+    );
 
     Ok(Query::Select(GQLQuery {
         statements,
@@ -446,13 +729,25 @@ fn parse_select_query(
         has_group_by_statement: context.has_group_by_statement,
         hidden_selections: hidden_selection_per_table,
         alias_table: context.name_alias_table,
+        subqueries: context.subqueries,
     }))
 }
 
+/// Attach a note with the clause that was being parsed when the error is reported
+fn with_clause_note(context: &ParserContext, diagnostic: Box<Diagnostic>) -> Box<Diagnostic> {
+    match context.current_clause {
+        Some(clause) => diagnostic
+            .add_note(&format!("While parsing `{}` clause", clause))
+            .as_boxed(),
+        None => diagnostic,
+    }
+}
+
 /// Classify hidden selection per table
 fn classify_hidden_selection(
     env: &mut Environment,
     tables: &[String],
+    tables_aliases: &HashMap<String, String>,
     hidden_selections: &[String],
 ) -> HashMap<String, Vec<String>> {
     let mut table_hidden_selections: HashMap<String, Vec<String>> = HashMap::new();
@@ -461,9 +756,19 @@ fn classify_hidden_selection(
     }
 
     for hidden_selection in hidden_selections {
+        // Qualified column is a hidden selection of the table that it references
+        if let Some((qualifier, _)) = split_qualified_column(tables, hidden_selection) {
+            let hidden_selection_for_table = table_hidden_selections.get_mut(qualifier).unwrap();
+            if !hidden_selection_for_table.contains(hidden_selection) {
+                hidden_selection_for_table.push(hidden_selection.to_string());
+            }
+            continue;
+        }
+
         let mut is_resolved = false;
         for table in tables {
-            let table_columns = env.schema.tables_fields_names.get(table.as_str()).unwrap();
+            let table_name = resolve_table_name(tables_aliases, table);
+            let table_columns = env.schema.tables_fields_names.get(table_name).unwrap();
             if table_columns.contains(&hidden_selection.as_str()) {
                 let hidden_selection_for_table = table_hidden_selections.get_mut(table).unwrap();
                 if !hidden_selection_for_table.contains(hidden_selection) {
@@ -477,10 +782,10 @@ fn classify_hidden_selection(
 
         // If this symbol is not column name, maybe generated column
         if !is_resolved && !table_hidden_selections.is_empty() {
-            table_hidden_selections
-                .get_mut(&tables[0])
-                .unwrap()
-                .push(hidden_selection.to_string());
+            let hidden_selection_for_table = table_hidden_selections.get_mut(&tables[0]).unwrap();
+            if !hidden_selection_for_table.contains(hidden_selection) {
+                hidden_selection_for_table.push(hidden_selection.to_string());
+            }
         }
     }
 
@@ -493,6 +798,8 @@ fn parse_select_statement(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Statement>, Box<Diagnostic>> {
+    context.current_clause = Some("SELECT");
+
     // Consume `SELECT` keyword
     *position += 1;
 
@@ -524,6 +831,32 @@ fn parse_select_statement(
         &mut selected_expr,
         &mut is_select_all,
     )?;
+
+    // Parse optional `EXCLUDE (...)` and `REPLACE (...)` after `SELECT *`
+    let mut excluded_fields = vec![];
+    let mut replaced_fields = vec![];
+    let mut explicit_projections_start = context.projection_names.len();
+    if is_select_all {
+        excluded_fields = parse_select_all_exclude_option(tokens, position)?;
+        replaced_fields = parse_select_all_replace_option(context, env, tokens, position)?;
+        explicit_projections_start = context.projection_names.len();
+
+        // Parse optional explicit expressions after `SELECT *, ...`
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume `,`
+            *position += 1;
+            parse_select_all_or_expressions(
+                context,
+                env,
+                tokens,
+                position,
+                &mut fields_names,
+                &mut selected_expr_titles,
+                &mut selected_expr,
+                &mut is_select_all,
+            )?;
+        }
+    }
     context.inside_selections = false;
 
     // Parse optional `FROM` with one or more tables and joins
@@ -538,6 +871,11 @@ fn parse_select_statement(
         position,
     )?;
 
+    // Make sure the qualified columns used in the selections are columns of the selected tables
+    for (qualifier, column_name, location) in std::mem::take(&mut context.qualified_columns) {
+        check_qualified_column(context, env, &qualifier, &column_name, location)?;
+    }
+
     // Make sure Aggregated functions are used with tables only
     if tables_to_select_from.is_empty() && !context.aggregations.is_empty() {
         return Err(
@@ -568,32 +906,147 @@ fn parse_select_statement(
     }
 
     // If it `select *` make all table fields selectable
+    let mut replaced_fields_names: HashMap<String, String> = HashMap::new();
     if is_select_all {
-        select_all_table_fields(
-            env,
-            &tables_to_select_from,
-            &mut context.selected_fields,
-            &mut fields_names,
-        );
+        let mut star_fields_names =
+            select_all_table_fields(env, &context.table_alias_table, &tables_to_select_from);
+
+        // Remove the fields that are excluded by `EXCLUDE (...)`
+        for (excluded_field, location) in excluded_fields {
+            if !star_fields_names.contains(&excluded_field) {
+                return Err(Diagnostic::error(&format!(
+                    "Can't exclude `{}` because it's not a column in the selected tables",
+                    excluded_field
+                ))
+                .add_help("Make sure the excluded name is a column in one of the selected tables")
+                .with_location(location)
+                .as_boxed());
+            }
+
+            star_fields_names.retain(|field_name| field_name != &excluded_field);
+        }
+
+        // Make sure the explicit columns after `*` are not already selected by the star expansion
+        for field_name in fields_names.iter() {
+            if star_fields_names.contains(field_name) {
+                let location = context.projection_names[explicit_projections_start..]
+                    .iter()
+                    .position(|name| name == field_name)
+                    .map(|index| context.projection_locations[explicit_projections_start + index])
+                    .unwrap_or_else(|| calculate_safe_location(tokens, *position - 1));
+
+                return Err(Diagnostic::error(&format!(
+                    "Column `{}` is selected twice",
+                    field_name
+                ))
+                .add_note("Column is already selected by `*`")
+                .add_help("Remove the explicit column or exclude it using `SELECT * EXCLUDE (...)`")
+                .with_location(location)
+                .as_boxed());
+            }
+        }
+
+        // Star columns come first then the explicit columns in the same order
+        context.selected_fields.extend(star_fields_names.clone());
+        star_fields_names.append(&mut fields_names);
+        fields_names = star_fields_names;
+
+        // Replace the columns with the generated fields of `REPLACE (...)` expressions in the same position
+        for (generated_field, replaced_field, location, expression) in replaced_fields {
+            let Some(index) = fields_names.iter().position(|name| name == &replaced_field) else {
+                return Err(Diagnostic::error(&format!(
+                    "Can't replace `{}` because it's not a column in the selected tables",
+                    replaced_field
+                ))
+                .add_help("Make sure the replaced name is a column in one of the selected tables")
+                .with_location(location)
+                .as_boxed());
+            };
+
+            fields_names[index] = generated_field.to_string();
+            context
+                .selected_fields
+                .retain(|field_name| field_name != &replaced_field);
+            context.selected_fields.push(generated_field.to_string());
+
+            selected_expr_titles.push(generated_field.to_string());
+            selected_expr.push(expression);
+            replaced_fields_names.insert(generated_field, replaced_field);
+        }
+
+        if fields_names.is_empty() {
+            return Err(
+                Diagnostic::error("`SELECT * EXCLUDE (...)` can't exclude all columns")
+                    .add_help("Make sure to keep at least one column selected")
+                    .with_location(calculate_safe_location(tokens, *position - 1))
+                    .as_boxed(),
+            );
+        }
     }
 
+    context.selected_columns_titles = fields_names
+        .iter()
+        .map(|name| {
+            context
+                .name_alias_table
+                .get(name)
+                .unwrap_or(name)
+                .to_string()
+        })
+        .collect();
+
     // Type check all selected fields has type registered in type table
     let table_selections = type_check_and_classify_selected_fields(
         env,
         &tables_to_select_from,
+        &context.table_alias_table,
         &fields_names,
         calculate_safe_location(tokens, *position),
     )?;
 
+    context.current_clause = None;
     Ok(Box::new(SelectStatement {
         table_selections,
         joins,
         selected_expr_titles,
         selected_expr,
         distinct,
+        replaced_fields: replaced_fields_names,
+        row_number_fields: context.row_number_fields.clone(),
     }))
 }
 
+/// Return true if the tokens at the current position are `ROWNUM()`
+fn is_row_number_call(tokens: &[Token], position: usize) -> bool {
+    position + 2 < tokens.len()
+        && matches!(&tokens[position].kind, TokenKind::Symbol(name) if name.eq_ignore_ascii_case("rownum"))
+        && tokens[position + 1].kind == TokenKind::LeftParen
+        && tokens[position + 2].kind == TokenKind::RightParen
+}
+
+/// Parse selected `ROWNUM()` as a generated Integer field, its value is the 1-based position
+/// of each row that is assigned by the engine after `ORDER BY`, `OFFSET` and `LIMIT`
+fn parse_row_number_field(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    position: &mut usize,
+) -> Box<dyn Expr> {
+    // Consume `ROWNUM`, `(` and `)`
+    *position += 3;
+
+    let column_name = context.name_generator.generate_column_name();
+    env.define(column_name.to_string(), Box::new(IntType));
+    context.row_number_fields.push(column_name.to_string());
+
+    Box::new(SymbolExpr {
+        value: column_name,
+        expr_type: Box::new(IntType),
+        flag: SymbolFlag::None,
+    })
+}
+
+/// Parse `DISTINCT` or `DISTINCT ON(...)`, the fields can be qualified by table name like `table.field`
+/// and because tables are parsed later the qualified fields are validated after `FROM` like other qualified columns
 fn parse_select_distinct_option(
     context: &mut ParserContext,
     tokens: &[Token],
@@ -620,18 +1073,41 @@ fn parse_select_distinct_option(
             let mut distinct_fields: Vec<String> = vec![];
             while !is_current_token(tokens, position, TokenKind::RightParen) {
                 let field_token = &tokens[*position];
-                let literal = &field_token.to_string();
+                let mut literal = field_token.to_string();
                 let location = field_token.location;
 
-                distinct_fields.push(literal.to_string());
-
-                context.hidden_selections.push(literal.to_string());
-                context.projection_names.push(literal.to_string());
-                context.projection_locations.push(location);
-
                 // Consume field name
                 *position += 1;
 
+                // Parse qualified field name `table.field`
+                if is_current_token(tokens, position, TokenKind::Dot) {
+                    // Consume `.` token
+                    *position += 1;
+
+                    let table_name = literal;
+                    let column_name = consume_conditional_token_or_errors(
+                        tokens,
+                        position,
+                        |token| matches!(token.kind, TokenKind::Symbol(_)),
+                        "Expect column name after `.` in `DISTINCT ON` field",
+                    )?
+                    .to_string();
+
+                    // Keep the qualified name so the same column name of different tables is not ambiguous
+                    literal = format!("{}.{}", table_name, column_name);
+                    context
+                        .qualified_columns
+                        .push((table_name, column_name, location));
+
+                    distinct_fields.push(literal.to_string());
+                    context.hidden_selections.push(literal);
+                } else {
+                    distinct_fields.push(literal.to_string());
+                    context.hidden_selections.push(literal.to_string());
+                    context.projection_names.push(literal);
+                    context.projection_locations.push(location);
+                }
+
                 if is_current_token(tokens, position, TokenKind::Comma) {
                     // Consume `,`
                     *position += 1;
@@ -676,68 +1152,228 @@ fn parse_select_distinct_option(
     Ok(Distinct::None)
 }
 
-#[allow(clippy::too_many_arguments)]
-fn parse_select_all_or_expressions(
-    context: &mut ParserContext,
-    env: &mut Environment,
+fn parse_select_all_exclude_option(
     tokens: &[Token],
     position: &mut usize,
-    fields_names: &mut Vec<String>,
-    selected_expr_titles: &mut Vec<String>,
-    selected_expr: &mut Vec<Box<dyn Expr>>,
-    is_select_all: &mut bool,
-) -> Result<(), Box<Diagnostic>> {
-    // Check if it `SELECT *`
-    if is_current_token(tokens, position, TokenKind::Star) {
-        // Consume `*`
-        *position += 1;
-        *is_select_all = true;
-        return Ok(());
+) -> Result<Vec<(String, SourceLocation)>, Box<Diagnostic>> {
+    let mut excluded_fields: Vec<(String, SourceLocation)> = vec![];
+    if !is_current_token(tokens, position, TokenKind::Exclude) {
+        return Ok(excluded_fields);
     }
 
-    // Parse list of expression separated by `,` or until end of file
-    while !is_current_token(tokens, position, TokenKind::From) {
-        let expression = parse_expression(context, env, tokens, position)?;
-        let expr_type = expression.expr_type().clone();
-        let field_name = expression_literal(&expression)
-            .unwrap_or_else(|| context.name_generator.generate_column_name());
+    // Consume `EXCLUDE` keyword
+    *position += 1;
 
-        // Assert that each selected field is unique
-        if fields_names.contains(&field_name) {
-            return Err(Diagnostic::error("Can't select the same field twice")
-                .with_location(calculate_safe_location(tokens, *position - 1))
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::LeftParen,
+        "Expect `(` after `EXCLUDE` keyword",
+    )?;
+
+    loop {
+        let field_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::Symbol(_)),
+            "Expect column name inside `EXCLUDE (...)`",
+        )?;
+
+        let field_name = field_token.to_string();
+        if excluded_fields.iter().any(|(name, _)| name == &field_name) {
+            return Err(Diagnostic::error("Can't exclude the same column twice")
+                .with_location(field_token.location)
                 .as_boxed());
         }
 
-        // Check for Field name alias
-        if is_current_token(tokens, position, TokenKind::As) {
-            // Consume `as` keyword
+        excluded_fields.push((field_name, field_token.location));
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume `,`
             *position += 1;
+        } else {
+            break;
+        }
+    }
 
-            // Parse and consume Symbol as Elias name
-            let alias_name = consume_conditional_token_or_errors(
-                tokens,
-                position,
-                |token| matches!(token.kind, TokenKind::Symbol(_) | TokenKind::String(_)),
-                "Expect `Symbol` or `Text` as field alias name",
-            )?
-            .to_string();
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after `EXCLUDE` columns",
+    )?;
 
-            // TODO [#120, #121]: Remove this check
-            if env
-                .schema
-                .tables_fields_types
-                .contains_key(alias_name.as_str())
-            {
-                return Err(Diagnostic::error("Can't use column name as Alias")
-                    .add_note("Until supporting `table.column` you should use different alias name")
-                    .with_location(tokens[*position - 1].location)
-                    .as_boxed());
-            }
+    Ok(excluded_fields)
+}
 
-            // No need to do checks or add alias
-            // `SELECT C AS C` is equal to `SELECT C`
-            if field_name != alias_name {
+type ReplacedField = (String, String, SourceLocation, Box<dyn Expr>);
+
+fn parse_select_all_replace_option(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Vec<ReplacedField>, Box<Diagnostic>> {
+    let mut replaced_fields: Vec<ReplacedField> = vec![];
+
+    // `REPLACE` is not a reserved keyword because it's also a standard function name
+    let is_replace_keyword = is_current_token_with_condition(
+        tokens,
+        position,
+        |token| matches!(&token.kind, TokenKind::Symbol(symbol) if symbol.eq_ignore_ascii_case("replace")),
+    );
+
+    if !is_replace_keyword || !is_next_token(tokens, position, TokenKind::LeftParen) {
+        return Ok(replaced_fields);
+    }
+
+    // Consume `REPLACE` keyword and `(`
+    *position += 2;
+
+    loop {
+        let expression = parse_expression(context, env, tokens, position)?;
+
+        consume_token_or_error(
+            tokens,
+            position,
+            TokenKind::As,
+            "Expect `AS` and column name after `REPLACE` expression",
+        )?;
+
+        let field_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::Symbol(_)),
+            "Expect column name after `AS` keyword in `REPLACE (...)`",
+        )?;
+
+        let replaced_field = field_token.to_string();
+        let location = field_token.location;
+        if replaced_fields
+            .iter()
+            .any(|(_, name, _, _)| name == &replaced_field)
+        {
+            return Err(Diagnostic::error("Can't replace the same column twice")
+                .with_location(location)
+                .as_boxed());
+        }
+
+        let generated_field = context.name_generator.generate_column_name();
+        env.define(generated_field.to_string(), expression.expr_type());
+        replaced_fields.push((generated_field, replaced_field, location, expression));
+
+        if is_current_token(tokens, position, TokenKind::Comma) {
+            // Consume `,`
+            *position += 1;
+        } else {
+            break;
+        }
+    }
+
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` after `REPLACE` expressions",
+    )?;
+
+    Ok(replaced_fields)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_select_all_or_expressions(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    fields_names: &mut Vec<String>,
+    selected_expr_titles: &mut Vec<String>,
+    selected_expr: &mut Vec<Box<dyn Expr>>,
+    is_select_all: &mut bool,
+) -> Result<(), Box<Diagnostic>> {
+    // Check if it `SELECT *`
+    if is_current_token(tokens, position, TokenKind::Star) {
+        if *is_select_all {
+            return Err(
+                Diagnostic::error("Can't use `*` more than once in `SELECT`")
+                    .with_location(calculate_safe_location(tokens, *position))
+                    .as_boxed(),
+            );
+        }
+
+        // Consume `*`
+        *position += 1;
+        *is_select_all = true;
+        return Ok(());
+    }
+
+    // Parse list of expression separated by `,` or until end of file
+    while !is_current_token(tokens, position, TokenKind::From) {
+        let expression_start = *position;
+        let aggregations_count = context.aggregations.len();
+        let window_functions_count = context.window_functions.len();
+        let expression = if is_row_number_call(tokens, *position) {
+            parse_row_number_field(context, env, position)
+        } else {
+            parse_expression(context, env, tokens, position)?
+        };
+        let expression_span = expression_start..*position;
+
+        // Computed expressions without aggregations or window functions can be reused by `ORDER BY`
+        let is_reusable_expression = expression.kind() != ExprKind::Symbol
+            && !expression.is_const()
+            && aggregations_count == context.aggregations.len()
+            && window_functions_count == context.window_functions.len();
+        let expr_type = expression.expr_type().clone();
+        let field_name = expression_literal(&expression)
+            .unwrap_or_else(|| context.name_generator.generate_column_name());
+
+        // Assert that each selected field is unique
+        if fields_names.contains(&field_name) {
+            return Err(Diagnostic::error("Can't select the same field twice")
+                .with_location(calculate_safe_location(tokens, *position - 1))
+                .as_boxed());
+        }
+
+        // Check for Field name alias with `AS` keyword or implicit alias `SELECT <expr> <alias>`,
+        // clauses keywords like `FROM` or `WHERE` are never tokenized as Symbol
+        let has_alias_keyword = is_current_token(tokens, position, TokenKind::As);
+        let has_implicit_alias = is_current_token_with_condition(tokens, position, |token| {
+            matches!(token.kind, TokenKind::Symbol(_))
+        });
+
+        if has_alias_keyword || has_implicit_alias {
+            if has_alias_keyword {
+                // Consume `as` keyword
+                *position += 1;
+            }
+
+            // Parse and consume Symbol as Elias name
+            let alias_name = consume_conditional_token_or_errors(
+                tokens,
+                position,
+                |token| matches!(token.kind, TokenKind::Symbol(_) | TokenKind::String(_)),
+                "Expect `Symbol` or `Text` as field alias name",
+            )?
+            .to_string();
+
+            // TODO [#120, #121]: Remove this check
+            if env
+                .schema
+                .tables_fields_types
+                .contains_key(alias_name.as_str())
+            {
+                return Err(Diagnostic::error("Can't use column name as Alias")
+                    .add_note(
+                        "Alias with the same name as a column can hide the column in other clauses",
+                    )
+                    .with_location(tokens[*position - 1].location)
+                    .as_boxed());
+            }
+
+            // No need to do checks or add alias
+            // `SELECT C AS C` is equal to `SELECT C`
+            if field_name != alias_name {
                 if context.selected_fields.contains(&alias_name)
                     || context.name_alias_table.contains_key(&alias_name)
                 {
@@ -763,6 +1399,11 @@ fn parse_select_all_or_expressions(
             selected_expr_titles.push(field_name.to_owned());
         }
 
+        if is_reusable_expression {
+            let title = selected_expr_titles.last().unwrap().to_string();
+            context.selected_expr_spans.push((expression_span, title));
+        }
+
         // Register field type
         env.define(field_name.to_string(), expr_type);
 
@@ -815,19 +1456,25 @@ fn parse_from_option(
                 .as_boxed());
         }
 
-        // Register the table
-        tables_to_select_from.push(table_name.to_string());
-        context.selected_tables.push(table_name.to_string());
+        // Register the table by its alias if exists or its name
+        let table_reference = parse_table_alias_option(context, tokens, position, &table_name)?;
+        tables_to_select_from.push(table_reference.to_string());
+        context.selected_tables.push(table_reference.to_string());
         register_current_table_fields_types(env, &table_name)?;
 
-        // Parse Joins
+        // Parse Joins, comma separated tables `FROM a, b` are implicit cross joins
         let mut number_previous_of_joins = 0;
-        while is_join_or_join_type_token(tokens, position) {
+        while is_join_or_join_type_token(tokens, position)
+            || is_current_token(tokens, position, TokenKind::Comma)
+        {
             let join_token = &tokens[*position];
 
             // The default join type now is cross join because we don't support `ON` Condition
             let mut join_kind = JoinKind::Default;
-            if join_token.kind != TokenKind::Join {
+            let is_implicit_cross_join = join_token.kind == TokenKind::Comma;
+            if is_implicit_cross_join {
+                join_kind = JoinKind::Cross;
+            } else if join_token.kind != TokenKind::Join {
                 join_kind = match join_token.kind {
                     TokenKind::Left => JoinKind::Left,
                     TokenKind::Right => JoinKind::Right,
@@ -862,13 +1509,18 @@ fn parse_from_option(
                 }
             }
 
-            // Consume `JOIN` keyword
+            // Consume `JOIN` keyword or `,`
             let join_location = tokens[*position].location;
             *position += 1;
 
             if *position >= tokens.len() || !matches!(tokens[*position].kind, TokenKind::Symbol(_))
             {
-                return Err(Diagnostic::error("Expect table name after `JOIN` keyword")
+                let message = if is_implicit_cross_join {
+                    "Expect table name after `,` in `FROM` tables"
+                } else {
+                    "Expect table name after `JOIN` keyword"
+                };
+                return Err(Diagnostic::error(message)
                     .with_location(calculate_safe_location(tokens, *position))
                     .as_boxed());
             }
@@ -876,28 +1528,74 @@ fn parse_from_option(
             let other_table = &tokens[*position];
             let other_table_name = &other_table.to_string();
 
-            // Make sure the RIGHT and LEFT tables names are not the same
-            if number_previous_of_joins == 0 && table_name.eq(other_table_name) {
+            if !env
+                .schema
+                .tables_fields_names
+                .contains_key(other_table_name.as_str())
+            {
+                return Err(Diagnostic::error("Unresolved table name")
+                    .add_help("You can use the `SHOW TABLES` query to get list of current tables")
+                    .add_help("Check the documentations to see available tables")
+                    .with_location(other_table.location)
+                    .as_boxed());
+            }
+
+            // Consume Other table name
+            *position += 1;
+
+            // Make sure each joined table has a unique name or alias, self join must use aliases
+            let other_table_reference =
+                parse_table_alias_option(context, tokens, position, other_table_name)?;
+            if context.selected_tables.contains(&other_table_reference) {
                 return Err(Diagnostic::error(
                     "The two tables of join must be unique or have different alias",
                 )
-                .with_location(calculate_safe_location(tokens, *position))
+                .add_help(
+                    "Use different aliases to join the table with itself like `t1 JOIN t AS t2`",
+                )
+                .with_location(calculate_safe_location(tokens, *position - 1))
                 .as_boxed());
             }
 
-            tables_to_select_from.push(other_table_name.to_string());
-            context.selected_tables.push(other_table_name.to_string());
+            tables_to_select_from.push(other_table_reference.to_string());
+            context
+                .selected_tables
+                .push(other_table_reference.to_string());
             register_current_table_fields_types(env, other_table_name)?;
 
-            // Consume Other table name
-            *position += 1;
-
-            // Parse the `ON` predicate
+            // Parse the `ON` predicate, implicit cross join is filtered by the `WHERE` condition
             let mut predicate: Option<Box<dyn Expr>> = None;
-            if is_current_token(tokens, position, TokenKind::On) {
+            if !is_implicit_cross_join && is_current_token(tokens, position, TokenKind::On) {
                 // Consume `ON` keyword
                 *position += 1;
-                predicate = Some(parse_expression(context, env, tokens, position)?);
+                let predicate_location = calculate_safe_location(tokens, *position);
+                context.inside_join_predicate = true;
+                let mut predicate_expr = parse_expression(context, env, tokens, position)?;
+                context.inside_join_predicate = false;
+
+                // Make sure that the predicate type is boolean, or can implicit cast to boolean.
+                if !predicate_expr.expr_type().is_bool() {
+                    let expected_type: Box<dyn DataType> = Box::new(BoolType);
+                    if !expected_type.has_implicit_cast_from(&predicate_expr) {
+                        return Err(Diagnostic::error(&format!(
+                            "Expect `JOIN` predicate to be type {} but got {}",
+                            "Boolean",
+                            predicate_expr.expr_type().literal()
+                        ))
+                        .add_note("`ON` predicate condition must be Boolean")
+                        .with_location(predicate_location)
+                        .as_boxed());
+                    }
+
+                    // Implicit cast the predicate to boolean
+                    predicate_expr = Box::new(CastExpr {
+                        value: predicate_expr,
+                        result_type: expected_type.clone(),
+                        location: None,
+                    });
+                }
+
+                predicate = Some(predicate_expr);
             }
 
             // Make sure user set predicate condition for LEFT or RIGHT JOIN
@@ -910,9 +1608,12 @@ fn parse_from_option(
             }
 
             let join_operand = if number_previous_of_joins == 0 {
-                JoinOperand::OuterAndInner(table_name.to_string(), other_table_name.to_string())
+                JoinOperand::OuterAndInner(
+                    table_reference.to_string(),
+                    other_table_reference.to_string(),
+                )
             } else {
-                JoinOperand::Inner(other_table_name.to_string())
+                JoinOperand::Inner(other_table_reference.to_string())
             };
 
             joins.push(Join {
@@ -927,12 +1628,53 @@ fn parse_from_option(
     Ok(())
 }
 
+/// Parse optional table alias `table AS alias` or `table alias` and return the name
+/// that is used to reference the table in the query, the alias if exists or the table name
+fn parse_table_alias_option(
+    context: &mut ParserContext,
+    tokens: &[Token],
+    position: &mut usize,
+    table_name: &str,
+) -> Result<String, Box<Diagnostic>> {
+    let has_alias_keyword = is_current_token(tokens, position, TokenKind::As);
+    let has_implicit_alias = is_current_token_with_condition(tokens, position, |token| {
+        matches!(token.kind, TokenKind::Symbol(_))
+    });
+
+    if !has_alias_keyword && !has_implicit_alias {
+        return Ok(table_name.to_string());
+    }
+
+    if has_alias_keyword {
+        // Consume `AS` keyword
+        *position += 1;
+    }
+
+    let alias_name = consume_conditional_token_or_errors(
+        tokens,
+        position,
+        |token| matches!(token.kind, TokenKind::Symbol(_)),
+        "Expect `Symbol` as table alias name",
+    )?
+    .to_string();
+
+    if alias_name != table_name {
+        context
+            .table_alias_table
+            .insert(alias_name.to_string(), table_name.to_string());
+    }
+
+    Ok(alias_name)
+}
+
 fn parse_where_statement(
     context: &mut ParserContext,
     env: &mut Environment,
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Statement>, Box<Diagnostic>> {
+    context.current_clause = Some("WHERE");
+
     *position += 1;
     if *position >= tokens.len() {
         return Err(Diagnostic::error("Expect expression after `WHERE` keyword")
@@ -966,6 +1708,7 @@ fn parse_where_statement(
         condition = Box::new(CastExpr {
             value: condition,
             result_type: expected_type.clone(),
+            location: None,
         })
     }
 
@@ -980,6 +1723,7 @@ fn parse_where_statement(
         );
     }
 
+    context.current_clause = None;
     Ok(Box::new(WhereStatement { condition }))
 }
 
@@ -989,6 +1733,8 @@ fn parse_group_by_statement(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Statement>, Box<Diagnostic>> {
+    context.current_clause = Some("GROUP BY");
+
     // Consume `Group` keyword
     *position += 1;
 
@@ -1029,6 +1775,7 @@ fn parse_group_by_statement(
     }
 
     context.has_group_by_statement = true;
+    context.current_clause = None;
     Ok(Box::new(GroupByStatement {
         values,
         has_with_roll_up: has_with_rollup,
@@ -1042,6 +1789,7 @@ fn parse_having_statement(
     position: &mut usize,
 ) -> Result<Box<dyn Statement>, Box<Diagnostic>> {
     context.inside_having = true;
+    context.current_clause = Some("HAVING");
 
     // Consume `HAVING` token
     *position += 1;
@@ -1078,13 +1826,94 @@ fn parse_having_statement(
         condition = Box::new(CastExpr {
             value: condition,
             result_type: expected_type.clone(),
+            location: None,
         })
     }
 
+    // Fold constant condition like `HAVING 1 = 1` so it's not evaluated for each group
+    if let Some(is_true) = fold_constant_condition(&condition) {
+        condition = Box::new(BooleanExpr { is_true });
+    }
+
     context.inside_having = false;
+    context.current_clause = None;
     Ok(Box::new(HavingStatement { condition }))
 }
 
+/// Evaluate the condition at parse time if it's composed only from constants
+#[allow(clippy::borrowed_box)]
+fn fold_constant_condition(condition: &Box<dyn Expr>) -> Option<bool> {
+    let expression = condition.as_any();
+
+    if let Some(boolean) = expression.downcast_ref::<BooleanExpr>() {
+        return Some(boolean.is_true);
+    }
+
+    if let Some(unary) = expression.downcast_ref::<UnaryExpr>() {
+        if unary.operator == PrefixUnaryOperator::Bang {
+            return fold_constant_condition(&unary.right).map(|is_true| !is_true);
+        }
+        return None;
+    }
+
+    if let Some(logical) = expression.downcast_ref::<LogicalExpr>() {
+        let lhs = fold_constant_condition(&logical.left)?;
+        let rhs = fold_constant_condition(&logical.right)?;
+        return Some(match logical.operator {
+            BinaryLogicalOperator::Or => lhs || rhs,
+            BinaryLogicalOperator::And => lhs && rhs,
+            BinaryLogicalOperator::Xor => lhs ^ rhs,
+        });
+    }
+
+    if let Some(comparison) = expression.downcast_ref::<ComparisonExpr>() {
+        let ordering = compare_constant_expressions(&comparison.left, &comparison.right)?;
+        return match comparison.operator {
+            ComparisonOperator::Greater => Some(ordering.is_gt()),
+            ComparisonOperator::GreaterEqual => Some(ordering.is_ge()),
+            ComparisonOperator::Less => Some(ordering.is_lt()),
+            ComparisonOperator::LessEqual => Some(ordering.is_le()),
+            ComparisonOperator::Equal => Some(ordering.is_eq()),
+            ComparisonOperator::NotEqual => Some(ordering.is_ne()),
+            ComparisonOperator::NullSafeEqual => None,
+        };
+    }
+
+    None
+}
+
+#[allow(clippy::borrowed_box)]
+fn compare_constant_expressions(lhs: &Box<dyn Expr>, rhs: &Box<dyn Expr>) -> Option<Ordering> {
+    let (lhs, rhs) = (lhs.as_any(), rhs.as_any());
+
+    if let (Some(lhs), Some(rhs)) = (
+        lhs.downcast_ref::<NumberExpr>(),
+        rhs.downcast_ref::<NumberExpr>(),
+    ) {
+        return match (&lhs.value, &rhs.value) {
+            (Number::Int(lhs), Number::Int(rhs)) => Some(lhs.cmp(rhs)),
+            (Number::Float(lhs), Number::Float(rhs)) => lhs.partial_cmp(rhs),
+            _ => None,
+        };
+    }
+
+    if let (Some(lhs), Some(rhs)) = (
+        lhs.downcast_ref::<StringExpr>(),
+        rhs.downcast_ref::<StringExpr>(),
+    ) {
+        return Some(lhs.value.cmp(&rhs.value));
+    }
+
+    if let (Some(lhs), Some(rhs)) = (
+        lhs.downcast_ref::<BooleanExpr>(),
+        rhs.downcast_ref::<BooleanExpr>(),
+    ) {
+        return Some(lhs.is_true.cmp(&rhs.is_true));
+    }
+
+    None
+}
+
 fn parse_limit_statement(
     tokens: &[Token],
     position: &mut usize,
@@ -1148,8 +1977,28 @@ fn parse_offset_statement(
                 );
             }
 
+            // Consume optional `PERCENT` keyword, it's not reserved so it can still be used as column name
+            let is_percentage = tokens.get(*position).is_some_and(|token| {
+                matches!(&token.kind, TokenKind::Symbol(name) if name.eq_ignore_ascii_case("percent"))
+            });
+            if is_percentage {
+                *position += 1;
+
+                // Make sure offset percentage is in the range of result rows
+                if integer > 100 {
+                    return Err(Diagnostic::error(
+                        "Expect `OFFSET` percentage to be between 0 and 100",
+                    )
+                    .with_location(calculate_safe_location(tokens, *position - 2))
+                    .as_boxed());
+                }
+            }
+
             let count = integer as usize;
-            Ok(Box::new(OffsetStatement { count }))
+            Ok(Box::new(OffsetStatement {
+                count,
+                is_percentage,
+            }))
         }
         _ => Err(Diagnostic::error("Expect number after `OFFSET` keyword")
             .with_location(calculate_safe_location(tokens, *position - 1))
@@ -1168,6 +2017,9 @@ pub(crate) fn parse_order_by_statement(
 
     context.inside_order_by = true;
 
+    // `ORDER BY` can be nested inside the `OVER` clause, so restore the outer clause after parsing
+    let outer_clause = context.current_clause.replace("ORDER BY");
+
     // Consume `BY` keyword
     consume_token_or_error(
         tokens,
@@ -1179,15 +2031,52 @@ pub(crate) fn parse_order_by_statement(
     let mut arguments: Vec<Box<dyn Expr>> = vec![];
     let mut sorting_orders: Vec<SortingOrder> = vec![];
     let mut null_ordering_policies: Vec<NullsOrderPolicy> = vec![];
+    let mut collations: Vec<Collation> = vec![];
 
     loop {
-        let argument = parse_expression(context, env, tokens, position)?;
+        let argument_location = calculate_safe_location(tokens, *position);
+        let argument_start = *position;
+        let mut argument = parse_expression(context, env, tokens, position)?;
+
+        // Reuse the selected column instead of computing the same expression again,
+        // or resolve the column by its position in the selections like `ORDER BY 1`
+        let selected_title = if context.inside_over_clauses {
+            None
+        } else if let Some(column_position) =
+            order_by_column_position(tokens, argument_start..*position)
+        {
+            Some(resolve_order_by_column_position(
+                context,
+                column_position,
+                argument_location,
+            )?)
+        } else {
+            find_selected_expression_title(context, tokens, argument_start..*position)
+        };
+
+        if let Some(title) = selected_title {
+            let expr_type = if argument.kind() == ExprKind::Number {
+                resolve_symbol_type_or_undefine(env, &title)
+            } else {
+                argument.expr_type()
+            };
+
+            argument = Box::new(SymbolExpr {
+                value: title,
+                expr_type,
+                flag: SymbolFlag::None,
+            });
+        }
+
+        let collation =
+            parse_order_by_collation(tokens, position, argument.as_ref(), argument_location)?;
         let sorting_order = parse_sorting_order(tokens, position)?;
         let null_ordering_policy = parse_order_by_nulls_policy(tokens, position, &sorting_order)?;
 
         arguments.push(argument);
         sorting_orders.push(sorting_order);
         null_ordering_policies.push(null_ordering_policy);
+        collations.push(collation);
 
         if is_current_token(tokens, position, TokenKind::Comma) {
             // Consume `,` keyword
@@ -1198,14 +2087,114 @@ pub(crate) fn parse_order_by_statement(
     }
 
     context.inside_order_by = false;
+    context.current_clause = outer_clause;
 
     Ok(Box::new(OrderByStatement {
         arguments,
         sorting_orders,
         nulls_order_policies: null_ordering_policies,
+        collations,
     }))
 }
 
+/// Return the position if the `ORDER BY` argument is only an Integer literal like `ORDER BY 1`
+fn order_by_column_position(tokens: &[Token], range: Range<usize>) -> Option<i64> {
+    match &tokens[range] {
+        [token] => match token.kind {
+            TokenKind::Integer(position) => Some(position),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Resolve the one based `ORDER BY` position to the title of the selected column
+fn resolve_order_by_column_position(
+    context: &ParserContext,
+    column_position: i64,
+    location: SourceLocation,
+) -> Result<String, Box<Diagnostic>> {
+    let columns_count = context.selected_columns_titles.len();
+    if column_position < 1 || column_position as usize > columns_count {
+        return Err(Diagnostic::error(&format!(
+            "ORDER BY position {} is out of range, query selects {} columns",
+            column_position, columns_count
+        ))
+        .add_note("`ORDER BY` position must be between 1 and the number of selected columns")
+        .with_location(location)
+        .as_boxed());
+    }
+
+    Ok(context.selected_columns_titles[column_position as usize - 1].to_string())
+}
+
+/// Return the title of the selected expression that has the same tokens as this range
+fn find_selected_expression_title(
+    context: &ParserContext,
+    tokens: &[Token],
+    range: Range<usize>,
+) -> Option<String> {
+    let expression_tokens = &tokens[range];
+    context
+        .selected_expr_spans
+        .iter()
+        .find(|(span, _)| {
+            let selected_tokens = &tokens[span.clone()];
+            selected_tokens.len() == expression_tokens.len()
+                && selected_tokens
+                    .iter()
+                    .zip(expression_tokens)
+                    .all(|(selected, token)| selected.kind == token.kind)
+        })
+        .map(|(_, title)| title.to_string())
+}
+
+fn parse_order_by_collation(
+    tokens: &[Token],
+    position: &mut usize,
+    argument: &dyn Expr,
+    argument_location: SourceLocation,
+) -> Result<Collation, Box<Diagnostic>> {
+    if !is_current_token(tokens, position, TokenKind::Collate) {
+        return Ok(Collation::Binary);
+    }
+
+    // Consume `COLLATE` keyword
+    *position += 1;
+
+    if !argument.expr_type().is_text() {
+        return Err(Diagnostic::error(&format!(
+            "`COLLATE` expect Text argument but got `{}`",
+            argument.expr_type().literal()
+        ))
+        .with_location(argument_location)
+        .as_boxed());
+    }
+
+    if let Some(token) = tokens.get(*position) {
+        if let TokenKind::Symbol(name) = &token.kind {
+            let collation = match name.to_lowercase().as_str() {
+                "binary" => Some(Collation::Binary),
+                "nocase" => Some(Collation::NoCase),
+                _ => None,
+            };
+
+            if let Some(collation) = collation {
+                // Consume collation name
+                *position += 1;
+                return Ok(collation);
+            }
+        }
+    }
+
+    Err(
+        Diagnostic::error("Expect collation name after `COLLATE` keyword")
+            .add_note("Collation name must be `BINARY` or `NOCASE`")
+            .with_location(calculate_safe_location(tokens, *position))
+            .as_boxed(),
+    )
+}
+
 fn parse_sorting_order(
     tokens: &[Token],
     position: &mut usize,
@@ -1274,8 +2263,8 @@ fn parse_order_by_nulls_policy(
 
         return Err(Diagnostic::error("Unexpected NULL ordering policy")
             .add_note("Null ordering policy must be `FIRST` or `LAST`")
-            .add_help("Please use `NULL FIRST` or `NULL LAST`")
-            .with_location(tokens[*position].location)
+            .add_help("Please use `NULLS FIRST` or `NULLS LAST`")
+            .with_location(calculate_safe_location(tokens, *position))
             .as_boxed());
     }
 
@@ -1552,20 +2541,22 @@ pub(crate) fn parse_expression(
     let expression = parse_assignment_expression(context, env, tokens, position)?;
 
     if expression.kind() != ExprKind::Symbol {
-        // This Expression contains aggregate function call or aggregate value
-        if aggregation_count_before != context.aggregations.len() {
+        // This Expression contains window function call or window value, it may also contains
+        // aggregation values like `COUNT(*) / SUM(COUNT(*)) OVER ()` so it must be evaluated
+        // after both aggregations and window functions
+        if window_count_before != context.window_functions.len() {
             let column_name = context.name_generator.generate_column_name();
             let expr_type = expression.expr_type();
             env.define(column_name.to_string(), expr_type.clone());
 
-            // Register the new aggregation generated field if the this expression is after group by
+            // Register the new window generated field if the this expression is after group by
             if context.has_group_by_statement && !context.hidden_selections.contains(&column_name) {
                 context.hidden_selections.push(column_name.to_string());
             }
 
             context
-                .aggregations
-                .insert(column_name.clone(), AggregateValue::Expression(expression));
+                .window_functions
+                .insert(column_name.clone(), WindowValue::Expression(expression));
 
             return Ok(Box::new(SymbolExpr {
                 value: column_name,
@@ -1574,20 +2565,20 @@ pub(crate) fn parse_expression(
             }));
         }
 
-        // This Expression contains window function call or window value
-        if window_count_before != context.window_functions.len() {
+        // This Expression contains aggregate function call or aggregate value
+        if aggregation_count_before != context.aggregations.len() {
             let column_name = context.name_generator.generate_column_name();
             let expr_type = expression.expr_type();
             env.define(column_name.to_string(), expr_type.clone());
 
-            // Register the new window generated field if the this expression is after group by
+            // Register the new aggregation generated field if the this expression is after group by
             if context.has_group_by_statement && !context.hidden_selections.contains(&column_name) {
                 context.hidden_selections.push(column_name.to_string());
             }
 
             context
-                .window_functions
-                .insert(column_name.clone(), WindowValue::Expression(expression));
+                .aggregations
+                .insert(column_name.clone(), AggregateValue::Expression(expression));
 
             return Ok(Box::new(SymbolExpr {
                 value: column_name,
@@ -1679,6 +2670,7 @@ fn parse_regex_expression(
             let regex_expr = Box::new(RegexExpr {
                 input: lhs,
                 pattern,
+                location: operator_location,
             });
 
             return Ok(apply_not_keyword_if_exists(regex_expr, has_not_keyword));
@@ -1694,11 +2686,13 @@ fn parse_regex_expression(
             let casting = Box::new(CastExpr {
                 value: pattern,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             let expr = Box::new(RegexExpr {
                 input: lhs,
                 pattern: casting,
+                location: operator_location,
             });
 
             return Ok(apply_not_keyword_if_exists(expr, has_not_keyword));
@@ -1747,8 +2741,34 @@ fn parse_is_null_expression(
             }));
         }
 
+        if is_current_token(tokens, position, TokenKind::Distinct) {
+            // Consume `DISTINCT` keyword
+            *position += 1;
+
+            if !is_current_token(tokens, position, TokenKind::From) {
+                return Err(Diagnostic::error(
+                    "Expects `FROM` Keyword after `IS DISTINCT` or `IS NOT DISTINCT`",
+                )
+                .with_location(calculate_safe_location(tokens, *position - 1))
+                .as_boxed());
+            }
+
+            // Consume `FROM` keyword
+            *position += 1;
+
+            let mut lhs = expression;
+            let mut rhs = parse_in_expression(context, env, tokens, position)?;
+            resolve_is_distinct_from_sides_types(&mut lhs, &mut rhs, is_location)?;
+
+            return Ok(Box::new(IsDistinctExpr {
+                left: lhs,
+                right: rhs,
+                has_not: has_not_keyword,
+            }));
+        }
+
         return Err(
-            Diagnostic::error("Expects `NULL` Keyword after `IS` or `IS NOT`")
+            Diagnostic::error("Expects `NULL` or `DISTINCT FROM` after `IS` or `IS NOT`")
                 .with_location(is_location)
                 .as_boxed(),
         );
@@ -1756,6 +2776,56 @@ fn parse_is_null_expression(
     Ok(expression)
 }
 
+fn resolve_is_distinct_from_sides_types(
+    lhs: &mut Box<dyn Expr>,
+    rhs: &mut Box<dyn Expr>,
+    location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    let lhs_type = lhs.expr_type();
+    let rhs_type = rhs.expr_type();
+
+    // NULL is comparable with any type in the NULL-safe comparison
+    if lhs_type.is_null() || rhs_type.is_null() {
+        return Ok(());
+    }
+
+    let expected_rhs_types = lhs_type.can_perform_eq_op_with();
+    if expected_rhs_types.contains(&rhs_type) {
+        return Ok(());
+    }
+
+    // Check if RHS expr can be implicit casted to Expected LHS type
+    for expected_type in expected_rhs_types.iter() {
+        if expected_type.has_implicit_cast_from(rhs) {
+            *rhs = Box::new(CastExpr {
+                value: rhs.clone(),
+                result_type: expected_type.clone(),
+                location: None,
+            });
+            return Ok(());
+        }
+    }
+
+    // Check if LHS expr can be implicit casted to Expected RHS type
+    for expected_type in rhs_type.can_perform_eq_op_with().iter() {
+        if expected_type.has_implicit_cast_from(lhs) {
+            *lhs = Box::new(CastExpr {
+                value: lhs.clone(),
+                result_type: expected_type.clone(),
+                location: None,
+            });
+            return Ok(());
+        }
+    }
+
+    Err(Diagnostic::error(&format!(
+        "Operator `IS DISTINCT FROM` can't be performed between types `{}` and `{}`",
+        lhs_type, rhs_type
+    ))
+    .with_location(location)
+    .as_boxed())
+}
+
 fn parse_in_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -1787,6 +2857,19 @@ fn parse_in_expression(
                 .as_boxed());
         }
 
+        // Check for `IN (SELECT ...)` to test the argument against the values of a subquery
+        if is_current_token(tokens, &(*position + 1), TokenKind::Select) {
+            return parse_in_subquery_expression(
+                context,
+                env,
+                tokens,
+                position,
+                expression,
+                has_not_keyword,
+                in_location,
+            );
+        }
+
         let values =
             parse_zero_or_more_values_with_comma_between(context, env, tokens, position, "IN")?;
 
@@ -1796,18 +2879,44 @@ fn parse_in_expression(
             return Ok(Box::new(BooleanExpr { is_true }));
         }
 
-        let values_type_result = check_all_values_are_same_type(&values);
-        if values_type_result.is_none() {
+        // NULL values are allowed and make the result unknown if the argument is not found
+        let Ok(values_type) = resolve_values_common_type(values.iter()) else {
             return Err(Diagnostic::error(
                 "Expects values between `(` and `)` to have the same type",
             )
             .with_location(in_location)
             .as_boxed());
-        }
+        };
 
         // Check that argument and values has the same type
-        let values_type = values_type_result.unwrap();
-        if !values_type.is_any() && !expression.expr_type().equals(&values_type) {
+        let argument_type = expression.expr_type();
+        if argument_type.is_number() && values_type.is_text() {
+            return Err(Diagnostic::error(&format!(
+                "Can't check if numeric `{}` argument is in a list of Text values",
+                argument_type.literal()
+            ))
+            .add_note("The argument is numeric but the values between `(` and `)` are Text")
+            .add_help("Try to remove the quotes around the values, for example `IN (1, 2)`")
+            .with_location(in_location)
+            .as_boxed());
+        }
+
+        if argument_type.is_text() && values_type.is_number() {
+            return Err(Diagnostic::error(&format!(
+                "Can't check if Text argument is in a list of numeric `{}` values",
+                values_type.literal()
+            ))
+            .add_note("The argument is Text but the values between `(` and `)` are numeric")
+            .add_help("Try to add quotes around the values, for example `IN ('1', '2')`")
+            .with_location(in_location)
+            .as_boxed());
+        }
+
+        if !values_type.is_any()
+            && !values_type.is_null()
+            && !argument_type.is_null()
+            && !argument_type.equals(&values_type)
+        {
             return Err(Diagnostic::error(
                 "Argument and Values of In Expression must have the same type",
             )
@@ -1826,6 +2935,93 @@ fn parse_in_expression(
     Ok(expression)
 }
 
+/// Parse `IN (SELECT ...)` with uncorrelated subquery that selects only one column
+fn parse_in_subquery_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+    argument: Box<dyn Expr>,
+    has_not_keyword: bool,
+    in_location: SourceLocation,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    if context.subquery_depth >= env.max_subquery_depth {
+        return Err(Diagnostic::error(&format!(
+            "Subqueries can't be nested more than {} levels",
+            env.max_subquery_depth
+        ))
+        .add_help("Try to reduce the nesting of subqueries")
+        .with_location(in_location)
+        .as_boxed());
+    }
+
+    // Consume `(` token
+    *position += 1;
+
+    // The subquery has its own tables and columns, so the outer query columns are not visible inside it
+    env.push_scope();
+    let subquery_result =
+        parse_select_query_with_depth(env, tokens, position, context.subquery_depth + 1);
+    let columns_types = match &subquery_result {
+        Ok(Query::Select(subquery)) => selected_columns_types(env, subquery),
+        _ => vec![],
+    };
+    env.pop_scope();
+
+    let subquery = match subquery_result? {
+        Query::Select(gql_query) => gql_query,
+        _ => unreachable!(),
+    };
+
+    // Consume `)` token at the end of the subquery
+    consume_token_or_error(
+        tokens,
+        position,
+        TokenKind::RightParen,
+        "Expect `)` at the end of `IN` subquery",
+    )?;
+
+    if subquery.statements.contains_key("into") {
+        return Err(
+            Diagnostic::error("Can't use `INTO` statement inside `IN` subquery")
+                .with_location(in_location)
+                .as_boxed(),
+        );
+    }
+
+    if columns_types.len() != 1 {
+        return Err(
+            Diagnostic::error("`IN` subquery must select only one column")
+                .add_note(&format!(
+                    "The subquery selects {} columns",
+                    columns_types.len()
+                ))
+                .with_location(in_location)
+                .as_boxed(),
+        );
+    }
+
+    let argument_type = argument.expr_type();
+    let column_type = &columns_types[0];
+    if !column_type.is_null() && !column_type.is_undefined() && !argument_type.equals(column_type) {
+        return Err(Diagnostic::error(&format!(
+            "Can't check if `{}` argument is in subquery of `{}` values",
+            argument_type.literal(),
+            column_type.literal()
+        ))
+        .add_help("Use `CAST` to convert the selected values to the argument type")
+        .with_location(in_location)
+        .as_boxed());
+    }
+
+    context.subqueries.push(subquery);
+    Ok(Box::new(InSubqueryExpr {
+        argument,
+        subquery_index: context.subqueries.len() - 1,
+        has_not_keyword,
+    }))
+}
+
 fn parse_logical_or_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -1845,6 +3041,16 @@ fn parse_logical_or_expression(
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
 
+        // `||` is logical or between two booleans and concatenates any other values as Text
+        if operator.kind == TokenKind::OrOr && !(lhs_type.is_bool() && rhs_type.is_bool()) {
+            lhs = Box::new(ConcatExpr {
+                left: lhs,
+                right: rhs,
+            });
+
+            continue 'parse_expr;
+        }
+
         let expected_rhs_types = lhs_type.can_perform_logical_or_op_with();
 
         // Can perform this operator between LHS and RHS
@@ -1868,6 +3074,7 @@ fn parse_logical_or_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(LogicalExpr {
@@ -1890,6 +3097,7 @@ fn parse_logical_or_expression(
             let casting = Box::new(CastExpr {
                 value: lhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(LogicalExpr {
@@ -1919,7 +3127,7 @@ fn parse_logical_and_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let mut lhs = parse_bitwise_or_expression(context, env, tokens, position)?;
+    let mut lhs = parse_logical_not_expression(context, env, tokens, position)?;
 
     'parse_expr: while is_logical_and_operator(tokens, position) {
         let operator = &tokens[*position];
@@ -1927,7 +3135,7 @@ fn parse_logical_and_expression(
         // Consume`AND` operator
         *position += 1;
 
-        let rhs = parse_bitwise_or_expression(context, env, tokens, position)?;
+        let rhs = parse_logical_not_expression(context, env, tokens, position)?;
 
         let lhs_type = lhs.expr_type();
         let rhs_type = rhs.expr_type();
@@ -1955,6 +3163,7 @@ fn parse_logical_and_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(LogicalExpr {
@@ -1977,6 +3186,7 @@ fn parse_logical_and_expression(
             let casting = Box::new(CastExpr {
                 value: lhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(LogicalExpr {
@@ -2000,6 +3210,43 @@ fn parse_logical_and_expression(
     Ok(lhs)
 }
 
+/// Parse `NOT` with lower precedence than comparisons, so `NOT a = b` means `NOT (a = b)`
+fn parse_logical_not_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    if is_current_token(tokens, position, TokenKind::Not) {
+        let operator_location = tokens[*position].location;
+
+        // Consume `NOT` keyword
+        *position += 1;
+
+        let rhs = parse_logical_not_expression(context, env, tokens, position)?;
+        let rhs_type = rhs.expr_type();
+
+        // Can perform this operator between RHS
+        if rhs_type.can_perform_bang_op() {
+            return Ok(Box::new(UnaryExpr {
+                right: rhs,
+                operator: PrefixUnaryOperator::Bang,
+                result_type: rhs_type.bang_op_result_type(),
+            }));
+        }
+
+        // Return error if this operator can't be performed even with implicit cast
+        return Err(Diagnostic::error(&format!(
+            "Operator unary `NOT` can't be performed on type `{}`",
+            rhs_type
+        ))
+        .with_location(operator_location)
+        .as_boxed());
+    }
+
+    parse_bitwise_or_expression(context, env, tokens, position)
+}
+
 fn parse_bitwise_or_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -2043,6 +3290,7 @@ fn parse_bitwise_or_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(BitwiseExpr {
@@ -2066,6 +3314,7 @@ fn parse_bitwise_or_expression(
             let casting = Box::new(CastExpr {
                 value: lhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(BitwiseExpr {
@@ -2133,6 +3382,7 @@ fn parse_bitwise_xor_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(BitwiseExpr {
@@ -2156,6 +3406,7 @@ fn parse_bitwise_xor_expression(
             let casting = Box::new(CastExpr {
                 value: lhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(BitwiseExpr {
@@ -2222,6 +3473,7 @@ fn parse_logical_xor_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(LogicalExpr {
@@ -2244,6 +3496,7 @@ fn parse_logical_xor_expression(
             let casting = Box::new(CastExpr {
                 value: lhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(LogicalExpr {
@@ -2310,6 +3563,7 @@ fn parse_bitwise_and_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             lhs = Box::new(BitwiseExpr {
@@ -2330,6 +3584,7 @@ fn parse_bitwise_and_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(BitwiseExpr {
@@ -2393,6 +3648,7 @@ pub(crate) fn parse_contains_expression(
             let casting = Box::new(CastExpr {
                 value: rhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             return Ok(Box::new(ContainsExpr {
@@ -2451,6 +3707,7 @@ fn parse_contained_by_expression(
             let casting = Box::new(CastExpr {
                 value: lhs,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             return Ok(Box::new(ContainedByExpr {
@@ -2515,6 +3772,7 @@ fn parse_bitwise_shift_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(BitwiseExpr {
@@ -2538,6 +3796,7 @@ fn parse_bitwise_shift_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(BitwiseExpr {
@@ -2585,6 +3844,7 @@ fn parse_bitwise_shift_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(BitwiseExpr {
@@ -2608,6 +3868,7 @@ fn parse_bitwise_shift_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(BitwiseExpr {
@@ -2663,6 +3924,7 @@ fn parse_term_expression(
                     operator: ArithmeticOperator::Plus,
                     right: rhs,
                     result_type: lhs_type.add_op_result_type(&rhs_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2678,6 +3940,7 @@ fn parse_term_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2685,6 +3948,7 @@ fn parse_term_expression(
                     operator: ArithmeticOperator::Plus,
                     right: casting,
                     result_type: lhs_type.add_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2701,6 +3965,7 @@ fn parse_term_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2708,6 +3973,7 @@ fn parse_term_expression(
                     operator: ArithmeticOperator::Plus,
                     right: rhs,
                     result_type: rhs_type.add_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2719,7 +3985,7 @@ fn parse_term_expression(
                 lhs_type, rhs_type
             ))
             .add_help(
-                "You can use `CONCAT(Any, Any, ...Any)` function to concatenate values with different types",
+                "You can use `||` operator or `CONCAT(Any, Any, ...Any)` function to concatenate values with different types",
             )
             .with_location(operator.location)
             .as_boxed());
@@ -2736,6 +4002,7 @@ fn parse_term_expression(
                     operator: ArithmeticOperator::Minus,
                     right: rhs,
                     result_type: lhs_type.sub_op_result_type(&rhs_type),
+                    location: operator.location,
                 });
                 continue 'parse_expr;
             }
@@ -2750,6 +4017,7 @@ fn parse_term_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2757,6 +4025,7 @@ fn parse_term_expression(
                     operator: ArithmeticOperator::Minus,
                     right: casting,
                     result_type: lhs_type.sub_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2773,6 +4042,7 @@ fn parse_term_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2780,6 +4050,7 @@ fn parse_term_expression(
                     operator: ArithmeticOperator::Minus,
                     right: rhs,
                     result_type: rhs_type.sub_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2828,6 +4099,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Star,
                     right: rhs,
                     result_type: lhs_type.mul_op_result_type(&rhs_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2843,6 +4115,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2850,6 +4123,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Star,
                     right: casting,
                     result_type: lhs_type.mul_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2866,6 +4140,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2873,6 +4148,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Star,
                     right: rhs,
                     result_type: rhs_type.mul_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2898,6 +4174,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Slash,
                     right: rhs,
                     result_type: lhs_type.div_op_result_type(&rhs_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2913,6 +4190,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2920,6 +4198,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Slash,
                     right: casting,
                     result_type: lhs_type.div_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2936,6 +4215,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2943,6 +4223,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Slash,
                     right: rhs,
                     result_type: rhs_type.div_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2968,6 +4249,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Modulus,
                     right: rhs,
                     result_type: lhs_type.rem_op_result_type(&rhs_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -2983,6 +4265,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -2990,6 +4273,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Modulus,
                     right: casting,
                     result_type: lhs_type.rem_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -3006,6 +4290,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -3013,6 +4298,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Modulus,
                     right: rhs,
                     result_type: rhs_type.rem_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -3037,6 +4323,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Exponentiation,
                     right: rhs,
                     result_type: lhs_type.caret_op_result_type(&rhs_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -3052,6 +4339,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: rhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -3059,6 +4347,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Exponentiation,
                     right: casting,
                     result_type: lhs_type.caret_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -3075,6 +4364,7 @@ fn parse_factor_expression(
                 let casting = Box::new(CastExpr {
                     value: lhs,
                     result_type: expected_type.clone(),
+                    location: None,
                 });
 
                 lhs = Box::new(ArithmeticExpr {
@@ -3082,6 +4372,7 @@ fn parse_factor_expression(
                     operator: ArithmeticOperator::Exponentiation,
                     right: rhs,
                     result_type: rhs_type.caret_op_result_type(expected_type),
+                    location: operator.location,
                 });
 
                 continue 'parse_expr;
@@ -3129,6 +4420,36 @@ fn parse_like_expression(
             tokens[*position - 1].location
         };
 
+        // Check for `LIKE ANY (<array>)` or `LIKE ALL (<array>)`
+        if let Some(group_operator) = parse_optional_group_operator(tokens, position) {
+            let patterns = parse_glob_expression(context, env, tokens, position)?;
+            let lhs_type = lhs.expr_type();
+            let patterns_type = patterns.expr_type();
+
+            let is_valid_patterns = patterns_type
+                .as_any()
+                .downcast_ref::<ArrayType>()
+                .is_some_and(|array| lhs_type.can_perform_like_op_with().contains(&array.base));
+
+            if !is_valid_patterns {
+                return Err(Diagnostic::error(&format!(
+                    "Operator `LIKE` with group operator can't be performed between types `{}` and `{}`",
+                    lhs_type, patterns_type
+                ))
+                .add_help("Patterns must be an Array of Text values for example `ARRAY['%fix%', '%bug%']`")
+                .with_location(operator_location)
+                .as_boxed());
+            }
+
+            let expr = Box::new(GroupLikeExpr {
+                input: lhs,
+                patterns,
+                group_operator,
+            });
+
+            return Ok(apply_not_keyword_if_exists(expr, has_not_keyword));
+        }
+
         let pattern = parse_glob_expression(context, env, tokens, position)?;
 
         let lhs_type = lhs.expr_type();
@@ -3155,6 +4476,7 @@ fn parse_like_expression(
             let casting = Box::new(CastExpr {
                 value: pattern,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
             let expr = Box::new(LikeExpr {
@@ -3183,15 +4505,30 @@ fn parse_glob_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let lhs = parse_cast_operator_expression(context, env, tokens, position)?;
-
-    if is_current_token(tokens, position, TokenKind::Glob) {
-        let glob_location = tokens[*position].location;
+    let lhs = parse_json_access_expression(context, env, tokens, position)?;
 
-        // Consume `GLOB` Token
-        *position += 1;
+    // Check for `GLOB` or `NOT GLOB`
+    // <expr> GLOB <expr>
+    // <expr> NOT GLOB <expr>
+    if is_current_token(tokens, position, TokenKind::Glob)
+        || (is_current_token(tokens, position, TokenKind::Not)
+            && is_next_token(tokens, position, TokenKind::Glob))
+    {
+        let has_not_keyword = is_current_token(tokens, position, TokenKind::Not);
+        let glob_location: SourceLocation = if has_not_keyword {
+            // Consume `NOT` and `GLOB` keyword
+            *position += 2;
+            let mut not_location = tokens[*position - 2].location;
+            let glob_location = tokens[*position - 1].location;
+            not_location.expand_until(glob_location);
+            not_location
+        } else {
+            // Consume `GLOB` keyword
+            *position += 1;
+            tokens[*position - 1].location
+        };
 
-        let pattern = parse_cast_operator_expression(context, env, tokens, position)?;
+        let pattern = parse_json_access_expression(context, env, tokens, position)?;
 
         let lhs_type = lhs.expr_type();
         let rhs_type = pattern.expr_type();
@@ -3199,10 +4536,12 @@ fn parse_glob_expression(
         // Can perform this operator between LHS and RHS
         let expected_rhs_types = lhs_type.can_perform_glob_op_with();
         if expected_rhs_types.contains(&rhs_type) {
-            return Ok(Box::new(GlobExpr {
+            let expr = Box::new(GlobExpr {
                 input: lhs,
                 pattern,
-            }));
+            });
+
+            return Ok(apply_not_keyword_if_exists(expr, has_not_keyword));
         }
 
         // Check if RHS expr can be implicit casted to Expected LHS type to make this
@@ -3215,12 +4554,15 @@ fn parse_glob_expression(
             let casting = Box::new(CastExpr {
                 value: pattern,
                 result_type: expected_type.clone(),
+                location: None,
             });
 
-            return Ok(Box::new(GlobExpr {
+            let expr = Box::new(GlobExpr {
                 input: lhs,
                 pattern: casting,
-            }));
+            });
+
+            return Ok(apply_not_keyword_if_exists(expr, has_not_keyword));
         }
 
         // Return error if this operator can't be performed even with implicit cast
@@ -3235,6 +4577,56 @@ fn parse_glob_expression(
     Ok(lhs)
 }
 
+fn parse_json_access_expression(
+    context: &mut ParserContext,
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let mut lhs = parse_cast_operator_expression(context, env, tokens, position)?;
+
+    while is_current_token(tokens, position, TokenKind::RightArrow)
+        || is_current_token(tokens, position, TokenKind::LongRightArrow)
+    {
+        let operator = &tokens[*position];
+        let as_text = operator.kind == TokenKind::LongRightArrow;
+
+        // Consume `->` or `->>` Token
+        *position += 1;
+
+        let key = parse_cast_operator_expression(context, env, tokens, position)?;
+
+        let lhs_type = lhs.expr_type();
+        if !lhs_type.is_text() {
+            return Err(Diagnostic::error(&format!(
+                "Operator `{}` expects JSON value with type `Text` but got `{}`",
+                operator, lhs_type
+            ))
+            .with_location(operator.location)
+            .as_boxed());
+        }
+
+        let key_type = key.expr_type();
+        if !key_type.is_text() && !key_type.is_int() {
+            return Err(Diagnostic::error(&format!(
+                "Operator `{}` expects key with type `Text` or index with type `Int` but got `{}`",
+                operator, key_type
+            ))
+            .add_help("Use Text key to access JSON object and Int index to access JSON array")
+            .with_location(operator.location)
+            .as_boxed());
+        }
+
+        lhs = Box::new(JsonAccessExpr {
+            value: lhs,
+            key,
+            as_text,
+        });
+    }
+
+    Ok(lhs)
+}
+
 pub(crate) fn parse_index_or_slice_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -3554,22 +4946,19 @@ fn parse_between_expression(
 
         let range_end = parse_function_call_expression(context, env, tokens, position)?;
 
-        let lhs_type = expression.expr_type();
-        let range_start_type = &range_start.expr_type();
-        let range_end_type = &range_end.expr_type();
-
-        // Make sure LHS and Range start and end types all are equals
-        if !lhs_type.equals(range_start_type) || !lhs_type.equals(range_end_type) {
+        let mut operands = [expression, range_start, range_end];
+        let Some(lhs_type) = unify_between_operands_types(&mut operands) else {
             return Err(Diagnostic::error(&format!(
                 "Expect `BETWEEN` Left hand side type, range start and end to has same type but got {}, {} and {}",
-                lhs_type,
-                range_start_type.literal(),
-                range_end_type.literal()
+                operands[0].expr_type(),
+                operands[1].expr_type().literal(),
+                operands[2].expr_type().literal()
             ))
             .add_help("Try to make sure all of them has same type")
+            .add_note("Operands can be implicitly casted to the same type, for example Integer to Float")
             .with_location(operator_location)
             .as_boxed());
-        }
+        };
 
         // Make sure that type is supporting >= operator
         if !lhs_type.can_perform_gte_op_with().contains(&lhs_type) {
@@ -3591,8 +4980,9 @@ fn parse_between_expression(
             .as_boxed());
         }
 
+        let [value, range_start, range_end] = operands;
         let between_expr = Box::new(BetweenExpr {
-            value: expression,
+            value,
             range_start,
             range_end,
             kind,
@@ -3604,6 +4994,42 @@ fn parse_between_expression(
     Ok(expression)
 }
 
+/// Unify the types of `BETWEEN` operands by implicit casting them to the type of one of them,
+/// for example Integer bounds are casted to Float if the value is Float and the opposite,
+/// return the unified type or None if there is no type that all operands can be casted to
+fn unify_between_operands_types(operands: &mut [Box<dyn Expr>; 3]) -> Option<Box<dyn DataType>> {
+    let operands_types: Vec<Box<dyn DataType>> =
+        operands.iter().map(|operand| operand.expr_type()).collect();
+
+    for target_type in operands_types.iter() {
+        let can_unify =
+            operands
+                .iter()
+                .zip(operands_types.iter())
+                .all(|(operand, operand_type)| {
+                    target_type.equals(operand_type) || target_type.has_implicit_cast_from(operand)
+                });
+
+        if !can_unify {
+            continue;
+        }
+
+        for (operand, operand_type) in operands.iter_mut().zip(operands_types.iter()) {
+            if !target_type.equals(operand_type) {
+                *operand = Box::new(CastExpr {
+                    value: operand.clone(),
+                    result_type: target_type.clone(),
+                    location: None,
+                });
+            }
+        }
+
+        return Some(target_type.clone());
+    }
+
+    None
+}
+
 fn parse_between_expr_kind(tokens: &[Token], position: &mut usize) -> BetweenKind {
     if *position < tokens.len() {
         let token_kind = &tokens[*position].kind;
@@ -3636,6 +5062,17 @@ pub(crate) fn parse_zero_or_more_values_with_comma_between(
 
     let mut arguments: Vec<Box<dyn Expr>> = vec![];
     while *position < tokens.len() && tokens[*position].kind != TokenKind::RightParen {
+        // `*` is only valid as the only argument of `COUNT(*)` which is parsed before reaching here
+        if tokens[*position].kind == TokenKind::Star {
+            return Err(Diagnostic::error(&format!(
+                "Unexpected `*` in {} arguments",
+                expression_name
+            ))
+            .add_help("`*` can only be used as the only argument of `COUNT(*)`")
+            .with_location(tokens[*position].location)
+            .as_boxed());
+        }
+
         let argument = parse_expression(context, env, tokens, position)?;
         if let Some(argument_literal) = expression_literal(&argument) {
             context.hidden_selections.push(argument_literal);
@@ -3757,6 +5194,7 @@ fn parse_primary_expression(
         TokenKind::Cast => parse_cast_call_expression(context, env, tokens, position),
         TokenKind::Benchmark => parse_benchmark_call_expression(context, env, tokens, position),
         TokenKind::GlobalVariable(_) => parse_global_variable_expression(env, tokens, position),
+        TokenKind::Parameter(_) => parse_parameter_expression(env, tokens, position),
         TokenKind::Interval => parse_interval_expression(tokens, position),
         TokenKind::String(str) => {
             *position += 1;
@@ -3775,10 +5213,26 @@ fn parse_primary_expression(
             *position += 1;
             Ok(Box::new(NullExpr {}))
         }
+        _ if is_relaxed_keyword_column(env, &tokens[*position]) => {
+            parse_symbol_expression(context, env, tokens, position)
+        }
         _ => Err(un_expected_expression_error(tokens, position)),
     }
 }
 
+/// Return true if this keyword token is configured to be resolved as a column with the same name
+fn is_relaxed_keyword_column(env: &Environment, token: &Token) -> bool {
+    match &token.kind {
+        TokenKind::Symbol(_)
+        | TokenKind::String(_)
+        | TokenKind::Integer(_)
+        | TokenKind::Float(_)
+        | TokenKind::GlobalVariable(_)
+        | TokenKind::Parameter(_) => false,
+        _ => env.is_relaxed_keyword_column(&token.to_string()),
+    }
+}
+
 fn parse_const_integer_expression(
     tokens: &[Token],
     position: &mut usize,
@@ -3845,36 +5299,102 @@ fn parse_symbol_expression(
     tokens: &[Token],
     position: &mut usize,
 ) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
-    let mut value = tokens[*position].to_string();
-    let location = tokens[*position].location;
+    let mut value = match &tokens[*position].kind {
+        TokenKind::Symbol(symbol) => symbol.to_string(),
+        // Relaxed keyword that is resolved as a column with the same name
+        _ => tokens[*position].to_string().to_lowercase(),
+    };
 
-    // Collect projections only inside select statement
-    if !context.has_select_statement {
-        context.projection_names.push(value.to_string());
-        context.projection_locations.push(location);
-
-        // If user perform member access with Composite type, composite type name should be in hidden selection
-        // For example `SELECT (commit).author_name`, commit should be in hidden selection
-        if let Some(symbol_type) = env.schema.tables_fields_types.get(&value.as_str()) {
-            if symbol_type.is_composite() && !context.hidden_selections.contains(&value) {
-                context.hidden_selections.push(value.to_string());
-            }
-        }
-    }
+    // Resolve qualified column `table.column` or `alias.column`, the qualified name is used as the
+    // column title so the same column name can be selected from different tables like in self join
+    let mut column_name = value.to_string();
+    let is_qualified_column = matches!(tokens[*position].kind, TokenKind::Symbol(_))
+        && is_next_token(tokens, position, TokenKind::Dot);
+    if is_qualified_column {
+        let qualifier = value;
 
-    // In case of using un selected column name inside OVER(....) clauses, mark it as hidden selection for now
-    if context.inside_over_clauses
-        && env.schema.tables_fields_types.contains_key(&value.as_str())
-        && !context.hidden_selections.contains(&value)
-    {
-        context.hidden_selections.push(value.to_string());
-    }
+        // Consume table reference and `.` tokens
+        *position += 2;
 
-    if context.has_select_statement {
-        // Replace name by alias if it used after select statement
-        // This workaround will help to execute query like
-        // SELECT commit_count as cc from branches where commit_count > 1
-        if context.name_alias_table.contains_key(&value) {
+        let column_token = consume_conditional_token_or_errors(
+            tokens,
+            position,
+            |token| matches!(token.kind, TokenKind::Symbol(_)),
+            "Expect column name after `.` in qualified column",
+        )?;
+
+        column_name = column_token.to_string();
+        let column_location = column_token.location;
+        value = format!("{}.{}", qualifier, column_name);
+
+        // Tables are parsed after the selections, so the qualified columns are checked after `FROM`
+        if context.inside_selections {
+            context
+                .qualified_columns
+                .push((qualifier, column_name.to_string(), column_location));
+        } else {
+            check_qualified_column(context, env, &qualifier, &column_name, column_location)?;
+        }
+
+        // Move back to the column name token to be consumed as a symbol
+        *position -= 1;
+    } else if !context.inside_selections {
+        // Make sure the unqualified column name is not ambiguous between the selected tables
+        let tables = tables_with_column(
+            env,
+            &context.selected_tables,
+            &context.table_alias_table,
+            &column_name,
+        );
+
+        if tables.len() > 1 {
+            return Err(ambiguous_column_error(
+                &column_name,
+                &tables,
+                tokens[*position].location,
+            ));
+        }
+    }
+
+    let location = tokens[*position].location;
+
+    // Collect projections only inside select statement, qualified columns are checked separately
+    if !context.has_select_statement {
+        if !is_qualified_column {
+            context.projection_names.push(value.to_string());
+            context.projection_locations.push(location);
+        }
+
+        // Columns used inside the selected expressions should be in hidden selection,
+        // for example `SELECT a || b`, `SELECT a + 1` or `SELECT (commit).author_name`,
+        // the selected fields are removed from the hidden selection after parsing the query
+        if env
+            .schema
+            .tables_fields_types
+            .contains_key(&column_name.as_str())
+            && !context.hidden_selections.contains(&value)
+        {
+            context.hidden_selections.push(value.to_string());
+        }
+    }
+
+    // In case of using un selected column name inside OVER(....) clauses or `JOIN` predicate,
+    // mark it as hidden selection for now
+    if (context.inside_over_clauses || context.inside_join_predicate)
+        && env
+            .schema
+            .tables_fields_types
+            .contains_key(&column_name.as_str())
+        && !context.hidden_selections.contains(&value)
+    {
+        context.hidden_selections.push(value.to_string());
+    }
+
+    if context.has_select_statement {
+        // Replace name by alias if it used after select statement
+        // This workaround will help to execute query like
+        // SELECT commit_count as cc from branches where commit_count > 1
+        if context.name_alias_table.contains_key(&value) {
             value = context.name_alias_table[&value].to_string();
         }
 
@@ -3928,7 +5448,11 @@ fn parse_symbol_expression(
     // Consume `Symbol` token
     *position += 1;
 
-    let result_type = resolve_symbol_type_or_undefine(env, &value);
+    let result_type = if is_qualified_column {
+        resolve_symbol_type_or_undefine(env, &column_name)
+    } else {
+        resolve_symbol_type_or_undefine(env, &value)
+    };
 
     Ok(Box::new(SymbolExpr {
         value,
@@ -3937,6 +5461,51 @@ fn parse_symbol_expression(
     }))
 }
 
+/// Make sure the qualified column `table.column` references a column of one of the selected tables,
+/// and register the qualified name type to be resolved like the other columns
+fn check_qualified_column(
+    context: &ParserContext,
+    env: &mut Environment,
+    qualifier: &str,
+    column_name: &str,
+    location: SourceLocation,
+) -> Result<(), Box<Diagnostic>> {
+    if !context
+        .selected_tables
+        .iter()
+        .any(|table| table == qualifier)
+    {
+        return Err(
+            Diagnostic::error(&format!("Unresolved table name or alias `{}`", qualifier))
+                .add_help(
+                    "Make sure the column is qualified by one of the selected tables or aliases",
+                )
+                .with_location(location)
+                .as_boxed(),
+        );
+    }
+
+    let table_name = resolve_table_name(&context.table_alias_table, qualifier);
+    let has_field = env
+        .schema
+        .tables_fields_names
+        .get(table_name)
+        .is_some_and(|fields| fields.contains(&column_name));
+
+    if !has_field {
+        return Err(Diagnostic::error(&format!(
+            "Table `{}` has no column with name `{}`",
+            qualifier, column_name
+        ))
+        .with_location(location)
+        .as_boxed());
+    }
+
+    let column_type = env.schema.tables_fields_types[column_name].clone();
+    env.define(format!("{}.{}", qualifier, column_name), column_type);
+    Ok(())
+}
+
 fn parse_array_value_expression(
     context: &mut ParserContext,
     env: &mut Environment,
@@ -4114,19 +5683,17 @@ fn parse_case_expression(
             .as_boxed());
     }
 
-    // Assert that all values has the same type
-    let values_type = values[0].expr_type();
-    for (i, value) in values.iter().enumerate().skip(1) {
-        if !values_type.equals(&value.expr_type()) {
-            return Err(Diagnostic::error(&format!(
-                "Case value in branch {} has different type than the last branch",
+    // Assert that all values has the same type, NULL values are allowed in any branch
+    let values_type = resolve_values_common_type(values.iter().chain(default_value.iter()))
+        .map_err(|i| {
+            Diagnostic::error(&format!(
+                "Case value in branch {} has different type than the other branches",
                 i + 1
             ))
-            .add_note("All values in `CASE` expression must has the same Type")
+            .add_note("All values in `CASE` expression must has the same Type or NULL")
             .with_location(case_location)
-            .as_boxed());
-        }
-    }
+            .as_boxed()
+        })?;
 
     Ok(Box::new(CaseExpr {
         conditions,
@@ -4207,6 +5774,36 @@ fn parse_global_variable_expression(
     Ok(Box::new(GlobalVariableExpr { name, result_type }))
 }
 
+fn parse_parameter_expression(
+    env: &mut Environment,
+    tokens: &[Token],
+    position: &mut usize,
+) -> Result<Box<dyn Expr>, Box<Diagnostic>> {
+    let token = &tokens[*position];
+    if let TokenKind::Parameter(index) = token.kind {
+        if let Some(value) = env.parameter(index) {
+            // Consume `Parameter` token
+            *position += 1;
+
+            let result_type = value.data_type();
+            return Ok(Box::new(ParameterExpr { index, result_type }));
+        }
+
+        return Err(Diagnostic::error(&format!(
+            "Positional parameter `${}` has no bound value",
+            index
+        ))
+        .add_note(&format!(
+            "Number of bound parameters is {}",
+            env.parameters.len()
+        ))
+        .with_location(token.location)
+        .as_boxed());
+    }
+
+    Err(un_expected_expression_error(tokens, position))
+}
+
 fn un_expected_statement_error(tokens: &[Token], position: &mut usize) -> Box<Diagnostic> {
     let token: &Token = &tokens[*position];
     let location = token.location;
@@ -4312,16 +5909,29 @@ fn un_expected_content_after_correct_statement(
     tokens: &[Token],
     position: &mut usize,
 ) -> Box<Diagnostic> {
-    let error_message = &format!(
-        "Unexpected content after the end of `{}` statement",
-        statement_name.to_uppercase()
-    );
-
     // The range of extra content
     let last_token_location = tokens[tokens.len() - 1].location;
     let mut location_of_extra_content = tokens[*position].location;
     location_of_extra_content.expand_until(last_token_location);
 
+    // `LIMIT` and `OFFSET` statements can be used only as part of `SELECT` query
+    let current_kind = &tokens[*position].kind;
+    if matches!(current_kind, TokenKind::Limit | TokenKind::Offset) {
+        return Diagnostic::error(&format!(
+            "`{}` is only valid in `SELECT` queries, not after `{}` statement",
+            current_kind,
+            statement_name.to_uppercase()
+        ))
+        .add_help(&format!("Try to remove the `{}` statement", current_kind))
+        .with_location(location_of_extra_content)
+        .as_boxed();
+    }
+
+    let error_message = &format!(
+        "Unexpected content after the end of `{}` statement",
+        statement_name.to_uppercase()
+    );
+
     Diagnostic::error(error_message)
         .add_help("Try to check if statement keyword is missing")
         .add_help("Try remove un expected extra content")
@@ -4331,7 +5941,7 @@ fn un_expected_content_after_correct_statement(
 
 #[inline(always)]
 #[allow(clippy::borrowed_box)]
-fn expression_literal(expression: &Box<dyn Expr>) -> Option<String> {
+pub(crate) fn expression_literal(expression: &Box<dyn Expr>) -> Option<String> {
     if let Some(symbol) = expression.as_any().downcast_ref::<SymbolExpr>() {
         return Some(symbol.value.to_string());
     }
@@ -4380,24 +5990,20 @@ fn register_current_table_fields_types(
 #[inline(always)]
 fn select_all_table_fields(
     env: &mut Environment,
+    tables_aliases: &HashMap<String, String>,
     table_name: &[String],
-    selected_fields: &mut Vec<String>,
-    fields_names: &mut Vec<String>,
-) {
-    let mut tables_columns: Vec<&str> = vec![];
+) -> Vec<String> {
+    let mut fields_names: Vec<String> = vec![];
     for table in table_name {
-        let columns = env.schema.tables_fields_names.get(table.as_str()).unwrap();
+        let table_name = resolve_table_name(tables_aliases, table);
+        let columns = env.schema.tables_fields_names.get(table_name).unwrap();
         for column in columns {
-            tables_columns.push(column);
-        }
-    }
-
-    for field in tables_columns {
-        if !fields_names.contains(&field.to_string()) {
-            fields_names.push(field.to_string());
-            selected_fields.push(field.to_string());
+            if !fields_names.iter().any(|field_name| field_name == column) {
+                fields_names.push(column.to_string());
+            }
         }
     }
+    fields_names
 }
 
 #[inline(always)]
@@ -4556,3 +6162,1273 @@ fn is_join_or_join_type_token(tokens: &[Token], position: &usize) -> bool {
 fn is_asc_or_desc(token: &Token) -> bool {
     matches!(token.kind, TokenKind::Ascending | TokenKind::Descending)
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_ast::types::date::DateType;
+    use gitql_ast::types::float::FloatType;
+    use gitql_ast::types::integer::IntType;
+    use gitql_ast::types::text::TextType;
+    use gitql_core::schema::Schema;
+    use gitql_core::signature::Signature;
+    use gitql_core::values::integer::IntValue;
+
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn create_test_environment() -> Environment {
+        Environment::new(Schema::default())
+    }
+
+    fn authors_env() -> Environment {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("name", Box::new(TextType))];
+        assert!(schema.add_table("authors", &columns).is_ok());
+        Environment::new(schema)
+    }
+
+    fn parse_do_expression_type(env: &mut Environment, query: &str) -> Box<dyn DataType> {
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, env).ok().unwrap();
+        match &queries[0] {
+            Query::Do(do_statement) => do_statement.expression.expr_type(),
+            _ => panic!("Expect DO query"),
+        }
+    }
+
+    #[test]
+    fn test_positional_parameters() {
+        let mut env = create_test_environment();
+        env.with_parameters(vec![Box::new(IntValue::new(1)), Box::new(IntValue::new(2))]);
+
+        assert!(parse_do_expression_type(&mut env, "DO $1 + $2").is_int());
+
+        let tokens = Tokenizer::tokenize("DO $3".to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_case_expression_with_null_branches() {
+        let mut env = create_test_environment();
+
+        let query = "DO CASE WHEN 1 > 2 THEN NULL ELSE 5 END";
+        assert!(parse_do_expression_type(&mut env, query).is_int());
+
+        let query = "DO CASE WHEN 1 > 2 THEN 1 WHEN 2 > 1 THEN NULL ELSE 3 END";
+        assert!(parse_do_expression_type(&mut env, query).is_int());
+    }
+
+    #[test]
+    fn test_substring_from_pattern_expression() {
+        let mut env = create_test_environment();
+        env.std_signatures.insert(
+            "regexp_extract",
+            Signature {
+                parameters: vec![Box::new(TextType), Box::new(TextType)],
+                return_type: Box::new(TextType),
+            },
+        );
+
+        let query = "DO SUBSTRING('Release v1.2.3' FROM 'v([0-9.]+)')";
+        assert!(parse_do_expression_type(&mut env, query).is_text());
+
+        let tokens = Tokenizer::tokenize("DO SUBSTRING('Release' FROM 2)".to_string());
+        assert!(parse_gql(tokens.ok().unwrap(), &mut env).is_err());
+
+        let tokens = Tokenizer::tokenize("DO SUBSTRING('Release', 1 FROM 'v')".to_string());
+        let diagnostic = parse_gql(tokens.ok().unwrap(), &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Expect `FROM` after SUBSTRING input");
+    }
+
+    #[test]
+    fn test_extract_from_date_expression() {
+        let mut env = create_test_environment();
+        env.std_signatures.insert(
+            "date_part",
+            Signature {
+                parameters: vec![Box::new(TextType), Box::new(DateType)],
+                return_type: Box::new(IntType),
+            },
+        );
+
+        let query = "DO EXTRACT(year FROM '2024-03-15')";
+        assert!(parse_do_expression_type(&mut env, query).is_int());
+
+        let tokens = Tokenizer::tokenize("DO EXTRACT(1 FROM '2024-03-15')".to_string());
+        assert!(parse_gql(tokens.ok().unwrap(), &mut env).is_err());
+
+        let query = "DO EXTRACT(foo FROM '2024-03-15')";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Unknown date part field `foo`");
+        let location = diagnostic.location().unwrap();
+        assert_eq!(location.column_start, query.find("foo").unwrap() as u32);
+
+        env.std_functions
+            .insert("date_part", |inputs| inputs[0].clone());
+        let query = "DO DATE_PART('Quarter', '2024-03-15')";
+        assert!(parse_do_expression_type(&mut env, query).is_int());
+
+        let tokens = Tokenizer::tokenize("DO DATE_PART('foo', '2024-03-15')".to_string());
+        let diagnostic = parse_gql(tokens.ok().unwrap(), &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Unknown date part field `foo`");
+    }
+
+    #[test]
+    fn test_date_diff_unknown_literal_unit() {
+        let mut env = create_test_environment();
+        env.std_signatures.insert(
+            "datediff",
+            Signature {
+                parameters: vec![Box::new(TextType), Box::new(DateType), Box::new(DateType)],
+                return_type: Box::new(IntType),
+            },
+        );
+        env.std_functions
+            .insert("datediff", |inputs| inputs[0].clone());
+
+        let query = "DO DATEDIFF('Month', '2024-01-01', '2024-03-15')";
+        assert!(parse_do_expression_type(&mut env, query).is_int());
+
+        let query = "DO DATEDIFF('quarter', '2024-01-01', '2024-03-15')";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Unknown DATEDIFF unit `quarter`");
+    }
+
+    #[test]
+    fn test_offset_percent_keyword_is_not_reserved() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 1] = [("percent", Box::new(IntType))];
+        assert!(schema.add_table("stats", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT percent FROM stats ORDER BY percent OFFSET 10 PERCENT";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_ok());
+
+        let query = "SELECT percent FROM stats OFFSET 200 percent";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_limit_and_offset_outside_select() {
+        let mut env = create_test_environment();
+
+        let tokens = Tokenizer::tokenize("DO 1 LIMIT 2".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "`LIMIT` is only valid in `SELECT` queries, not after `DO` statement"
+        );
+
+        let tokens = Tokenizer::tokenize("SET @x = 5 OFFSET 1".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_boolean_compared_with_integer_literal() {
+        let mut env = create_test_environment();
+
+        let tokens = Tokenizer::tokenize("DO TRUE = 1".to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.helps()[0],
+            "Try to use `= TRUE` instead of `= 1`"
+        );
+
+        env.with_boolean_integer_coercion(true);
+        assert!(parse_do_expression_type(&mut env, "DO TRUE = 1").is_bool());
+        assert!(parse_do_expression_type(&mut env, "DO 0 != FALSE").is_bool());
+
+        let tokens = Tokenizer::tokenize("DO TRUE = 2".to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_if_function_expression() {
+        let mut env = create_test_environment();
+
+        assert!(parse_do_expression_type(&mut env, "DO IF(1 > 2, 'a', 'b')").is_text());
+        assert!(parse_do_expression_type(&mut env, "DO IF(1 > 2, 1, 2.5)").is_float());
+        assert!(parse_do_expression_type(&mut env, "DO IF(1 > 2, 1.5, 2)").is_float());
+        assert!(parse_do_expression_type(&mut env, "DO IF(1 > 2, NULL, 2)").is_int());
+
+        let tokens = Tokenizer::tokenize("DO IF(1, 2, 3)".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+
+        let tokens = Tokenizer::tokenize("DO IF(TRUE, 2, 'b')".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_implicit_cast_integer_to_float() {
+        let mut env = create_test_environment();
+        env.std_functions.insert("half", |inputs| inputs[0].clone());
+        env.std_signatures.insert(
+            "half",
+            Signature {
+                parameters: vec![Box::new(FloatType)],
+                return_type: Box::new(FloatType),
+            },
+        );
+
+        assert!(parse_do_expression_type(&mut env, "DO HALF(3)").is_float());
+        assert!(parse_do_expression_type(&mut env, "DO 1.5 > 1").is_bool());
+        assert!(parse_do_expression_type(&mut env, "DO 1.5 BETWEEN 1 AND 2").is_bool());
+
+        // Only Integer is implicitly casted to Float
+        let tokens = Tokenizer::tokenize("DO HALF('3')".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_select_function_calls_without_from() {
+        let mut env = create_test_environment();
+        env.std_functions
+            .insert("upper", |inputs| inputs[0].clone());
+        env.std_functions
+            .insert("now", |_| Box::new(IntValue::new(0)));
+        env.aggregation_functions
+            .insert("max", |rows| rows[0][0].clone());
+        env.std_signatures.insert(
+            "upper",
+            Signature {
+                parameters: vec![Box::new(TextType)],
+                return_type: Box::new(TextType),
+            },
+        );
+        env.std_signatures.insert(
+            "now",
+            Signature {
+                parameters: vec![],
+                return_type: Box::new(IntType),
+            },
+        );
+        env.aggregation_signatures.insert(
+            "max",
+            Signature {
+                parameters: vec![Box::new(IntType)],
+                return_type: Box::new(IntType),
+            },
+        );
+
+        for query in [
+            "SELECT NOW()",
+            "SELECT UPPER('x')",
+            "SELECT 1 + 1",
+            "SELECT UPPER('x'), NOW(), 1 + 1",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            let Query::Select(select_query) = &queries[0] else {
+                panic!("Expect SELECT query")
+            };
+
+            let select = select_query.statements.get("select").unwrap();
+            let select = select.as_any().downcast_ref::<SelectStatement>().unwrap();
+            assert_eq!(select.table_selections.len(), 1);
+            assert!(select.table_selections[0].table_name.is_empty());
+        }
+
+        let tokens = Tokenizer::tokenize("SELECT MAX(1)".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Aggregations functions should be used only with tables"
+        );
+    }
+
+    #[test]
+    fn test_order_by_sorting_order_per_argument() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT a, b FROM numbers ORDER BY a DESC, b";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let order_by = select_query.statements.get("order").unwrap();
+        let order_by = order_by
+            .as_any()
+            .downcast_ref::<OrderByStatement>()
+            .unwrap();
+        assert!(order_by.sorting_orders[0] == SortingOrder::Descending);
+        assert!(order_by.sorting_orders[1] == SortingOrder::Ascending);
+    }
+
+    #[test]
+    fn test_order_by_collation() {
+        let mut env = authors_env();
+
+        let query = "SELECT id, name FROM authors ORDER BY name COLLATE NOCASE DESC, id";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let order_by = select_query.statements.get("order").unwrap();
+        let order_by = order_by
+            .as_any()
+            .downcast_ref::<OrderByStatement>()
+            .unwrap();
+        assert!(order_by.collations[0] == Collation::NoCase);
+        assert!(order_by.collations[1] == Collation::Binary);
+        assert!(order_by.sorting_orders[0] == SortingOrder::Descending);
+
+        for query in [
+            "SELECT id FROM authors ORDER BY id COLLATE NOCASE",
+            "SELECT name FROM authors ORDER BY name COLLATE UTF8",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_distinct_on_qualified_field_with_join() {
+        let mut env = authors_env();
+        let books: [(&'static str, Box<dyn DataType>); 2] = [
+            ("title", Box::new(TextType)),
+            ("author_id", Box::new(IntType)),
+        ];
+        assert!(env.schema.add_table("books", &books).is_ok());
+
+        let query = "SELECT DISTINCT ON (authors.name, books.title) id FROM authors JOIN books";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let select = select_query.statements.get("select").unwrap();
+        let select = select.as_any().downcast_ref::<SelectStatement>().unwrap();
+        let Distinct::DistinctOn(fields) = &select.distinct else {
+            panic!("Expect DISTINCT ON")
+        };
+        assert_eq!(
+            fields,
+            &vec!["authors.name".to_string(), "books.title".to_string()]
+        );
+
+        for query in [
+            "SELECT DISTINCT ON (books.name) id FROM authors JOIN books",
+            "SELECT DISTINCT ON (users.name) id FROM authors JOIN books",
+            "SELECT DISTINCT ON (authors.) id FROM authors",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_in_expression_numeric_and_text_mismatch() {
+        let mut env = authors_env();
+
+        let query = "SELECT id FROM authors WHERE id IN ('1', '2')";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Can't check if numeric `Int` argument is in a list of Text values"
+        );
+        assert_eq!(
+            diagnostic.helps(),
+            &vec![
+                "Try to remove the quotes around the values, for example `IN (1, 2)`".to_string()
+            ]
+        );
+
+        let query = "SELECT name FROM authors WHERE name NOT IN (1, 2)";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Can't check if Text argument is in a list of numeric `Int` values"
+        );
+    }
+
+    #[test]
+    fn test_logical_not_precedence() {
+        let mut env = create_test_environment();
+
+        let parse_do_expression = |env: &mut Environment, query: &str| -> Box<dyn Expr> {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let mut queries = parse_gql(tokens, env).ok().unwrap();
+            match queries.remove(0) {
+                Query::Do(do_statement) => do_statement.expression,
+                _ => panic!("Expect DO query"),
+            }
+        };
+
+        // NOT 1 = 2 is NOT (1 = 2)
+        let expression = parse_do_expression(&mut env, "DO NOT 1 = 2");
+        let unary = expression.as_any().downcast_ref::<UnaryExpr>().unwrap();
+        assert!(unary.right.kind() == ExprKind::Comparison);
+
+        // NOT TRUE AND FALSE is (NOT TRUE) AND FALSE
+        let expression = parse_do_expression(&mut env, "DO NOT TRUE AND FALSE");
+        let logical = expression.as_any().downcast_ref::<LogicalExpr>().unwrap();
+        assert!(logical.operator == BinaryLogicalOperator::And);
+        assert!(logical.left.kind() == ExprKind::PrefixUnary);
+    }
+
+    #[test]
+    fn test_case_expression_in_where_comparison() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        for query in [
+            "SELECT a FROM numbers WHERE CASE WHEN b > 1 THEN 10 ELSE 0 END > 5",
+            "SELECT a FROM numbers WHERE 5 <= CASE WHEN b > 1 THEN a ELSE 0 END",
+            "SELECT a FROM numbers WHERE CASE WHEN b > 1 THEN 1.5 ELSE 0.5 END = a",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            let Query::Select(select_query) = &queries[0] else {
+                panic!("Expect SELECT query")
+            };
+
+            let where_statement = select_query.statements.get("where").unwrap();
+            let where_statement = where_statement
+                .as_any()
+                .downcast_ref::<WhereStatement>()
+                .unwrap();
+            assert!(where_statement.condition.expr_type().is_bool());
+        }
+    }
+
+    #[test]
+    fn test_select_alias_without_as_keyword() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let select_titles = |env: &mut Environment, query: &str| -> Vec<String> {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let queries = parse_gql(tokens, env).ok().unwrap();
+            let Query::Select(select_query) = &queries[0] else {
+                panic!("Expect SELECT query")
+            };
+
+            let select = select_query.statements.get("select").unwrap();
+            let select = select.as_any().downcast_ref::<SelectStatement>().unwrap();
+            select.selected_expr_titles.clone()
+        };
+
+        let titles = select_titles(&mut env, "SELECT a x, b AS y FROM numbers WHERE a > 1");
+        assert_eq!(titles, vec!["x".to_string(), "y".to_string()]);
+
+        let titles = select_titles(&mut env, "SELECT a, b FROM numbers");
+        assert_eq!(titles, vec!["a".to_string(), "b".to_string()]);
+
+        let titles = select_titles(&mut env, "SELECT a + 1 total FROM numbers");
+        assert_eq!(titles, vec!["total".to_string()]);
+    }
+
+    #[test]
+    fn test_select_from_table_registered_with_schema_builder() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("title", Box::new(TextType))];
+        assert!(schema.add_table("issues", &columns).is_ok());
+        assert!(schema
+            .add_table("labels", &[("id", Box::new(TextType))])
+            .is_err());
+
+        let mut env = Environment::new(schema);
+        let query = "SELECT id, title FROM issues WHERE id > 1";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_ok());
+
+        let tokens = Tokenizer::tokenize("SELECT name FROM issues".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_is_distinct_from_expression() {
+        let mut env = create_test_environment();
+
+        for query in [
+            "DO NULL IS DISTINCT FROM 1",
+            "DO 1 IS NOT DISTINCT FROM NULL",
+            "DO 1 IS DISTINCT FROM 1.5",
+            "DO 'a' IS NOT DISTINCT FROM 'b'",
+        ] {
+            assert!(parse_do_expression_type(&mut env, query).is_bool());
+        }
+
+        for query in [
+            "DO 1 IS DISTINCT FROM 'a'",
+            "DO 1 IS DISTINCT 2",
+            "DO 1 IS NOT 2",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_hidden_selections_are_deduplicated() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT a FROM numbers WHERE b > 1 GROUP BY a HAVING b > 0 ORDER BY b";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        assert_eq!(
+            select_query.hidden_selections["numbers"],
+            vec!["b".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_cast_array_between_element_types() {
+        let mut env = create_test_environment();
+
+        let expected_type: Box<dyn DataType> = Box::new(ArrayType::new(Box::new(FloatType)));
+        for query in ["DO CAST([1, 2, 3] AS Float[])", "DO [1, 2, 3]::Float[]"] {
+            let expr_type = parse_do_expression_type(&mut env, query);
+            assert!(expr_type.equals(&expected_type));
+        }
+
+        for query in ["DO CAST([1, 2, 3] AS Float)", "DO CAST(1 AS Float[])"] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_not_like_and_not_glob_expressions() {
+        let mut env = create_test_environment();
+
+        for query in [
+            "DO 'src/main.rs' NOT LIKE '%.rs'",
+            "DO 'src/main.rs' NOT GLOB '*.rs'",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            let Query::Do(do_statement) = &queries[0] else {
+                panic!("Expect DO query")
+            };
+
+            let expression = &do_statement.expression;
+            assert!(expression.kind() == ExprKind::PrefixUnary);
+            let unary = expression.as_any().downcast_ref::<UnaryExpr>().unwrap();
+            assert!(unary.operator == PrefixUnaryOperator::Bang);
+            assert!(matches!(
+                unary.right.kind(),
+                ExprKind::Like | ExprKind::Glob
+            ));
+        }
+
+        let tokens = Tokenizer::tokenize("DO 1 NOT GLOB '*.rs'".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Operator `GLOB` can't be performed between types `Int` and `Text`"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_cast_diagnostic() {
+        let mut env = create_test_environment();
+
+        let expected_type: Box<dyn DataType> = Box::new(FloatType);
+        for query in ["DO CAST(1 AS Float)", "DO 1::Float"] {
+            let expr_type = parse_do_expression_type(&mut env, query);
+            assert!(expr_type.equals(&expected_type));
+        }
+
+        let tokens = Tokenizer::tokenize("DO CAST('now' AS Date)".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Unsupported `CAST` operator from type `Text` to type `Date`"
+        );
+        assert_eq!(
+            diagnostic.helps(),
+            &vec!["Only Text literal with format `YYYY-MM-DD` can be casted to `Date`".to_string()]
+        );
+
+        let tokens = Tokenizer::tokenize("DO 1.5::Date".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.notes(),
+            &vec!["Type `Float` can be explicitly casted only to `Int`".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_concat_operator() {
+        let mut env = create_test_environment();
+
+        let query = "DO 'a' || 1 || TRUE";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Do(do_statement) = &queries[0] else {
+            panic!("Expect DO query")
+        };
+
+        // Chained operators are left associative and concatenate non Boolean operands as Text
+        let expression = &do_statement.expression;
+        let concat = expression.as_any().downcast_ref::<ConcatExpr>().unwrap();
+        assert!(concat.left.kind() == ExprKind::Concat);
+        assert!(concat.right.kind() == ExprKind::Boolean);
+        assert!(concat.expr_type().is_text());
+
+        // `||` between two Booleans is still a logical or with the same precedence of `OR`
+        let query = "DO 1 = 2 || 3 = 3";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Do(do_statement) = &queries[0] else {
+            panic!("Expect DO query")
+        };
+        assert!(do_statement.expression.kind() == ExprKind::Logical);
+        assert!(do_statement.expression.expr_type().is_bool());
+    }
+
+    #[test]
+    fn test_json_access_operators() {
+        let mut env = create_test_environment();
+
+        for query in [
+            "DO '{\"a\": 1}' -> 'a'",
+            "DO '{\"a\": [1, 2]}' -> 'a' ->> 0",
+            "DO '[1, 2]'->>1",
+        ] {
+            assert!(parse_do_expression_type(&mut env, query).is_text());
+        }
+
+        let tokens = Tokenizer::tokenize("DO '{}' -> 'a' ->> 1".to_string())
+            .ok()
+            .unwrap();
+        let mut queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Do(do_statement) = queries.remove(0) else {
+            panic!("Expect DO query")
+        };
+        let outer = do_statement
+            .expression
+            .as_any()
+            .downcast_ref::<JsonAccessExpr>()
+            .unwrap();
+        assert!(outer.as_text);
+        assert!(outer.value.kind() == ExprKind::JsonAccess);
+
+        for query in ["DO 1 -> 'a'", "DO '{}' -> 1.5", "DO '{}' ->> TRUE"] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_select_all_exclude_and_replace_columns() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 3] = [
+            ("a", Box::new(IntType)),
+            ("b", Box::new(IntType)),
+            ("c", Box::new(TextType)),
+        ];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT * EXCLUDE (a, c) FROM numbers";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let select_statement = select_query.statements["select"]
+            .as_any()
+            .downcast_ref::<SelectStatement>()
+            .unwrap();
+        let table_selection = &select_statement.table_selections[0];
+        assert_eq!(table_selection.columns_names, vec!["b".to_string()]);
+
+        for query in [
+            "SELECT * EXCLUDE (d) FROM numbers",
+            "SELECT * EXCLUDE (a, a) FROM numbers",
+            "SELECT * EXCLUDE (a, b, c) FROM numbers",
+            "SELECT * EXCLUDE a FROM numbers",
+            "SELECT * REPLACE (a + 1 AS d) FROM numbers",
+            "SELECT * REPLACE (a + 1 AS a, b AS a) FROM numbers",
+            "SELECT * EXCLUDE (a) REPLACE (a + 1 AS a) FROM numbers",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_select_all_with_explicit_columns_collision() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT *, a + 1 FROM numbers";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let select_statement = select_query.statements["select"]
+            .as_any()
+            .downcast_ref::<SelectStatement>()
+            .unwrap();
+        let table_selection = &select_statement.table_selections[0];
+        assert_eq!(
+            table_selection.columns_names,
+            vec!["a".to_string(), "b".to_string(), "column_0".to_string()]
+        );
+        assert_eq!(select_statement.selected_expr.len(), 1);
+
+        let query = "SELECT *, a FROM numbers";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Column `a` is selected twice");
+
+        let query = "SELECT * REPLACE (a + 1 AS a), a FROM numbers WHERE b > 1";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Column `a` is selected twice");
+        let location = diagnostic.location().unwrap();
+        assert_eq!(location.column_start, query.find(", a").unwrap() as u32 + 2);
+
+        for query in [
+            "SELECT * EXCLUDE (a), a FROM numbers",
+            "SELECT * REPLACE (a + 1 AS a), b + 1 FROM numbers",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_ok());
+        }
+
+        let tokens = Tokenizer::tokenize("SELECT *, * FROM numbers".to_string())
+            .ok()
+            .unwrap();
+        assert!(parse_gql(tokens, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_diagnostic_note_with_current_clause() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(TextType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT a FROM numbers ORDER BY a + b";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert!(diagnostic
+            .notes()
+            .contains(&"While parsing `ORDER BY` clause".to_string()));
+
+        let query = "SELECT a FROM numbers WHERE a + b";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert!(diagnostic
+            .notes()
+            .contains(&"While parsing `WHERE` clause".to_string()));
+    }
+
+    #[test]
+    fn test_having_constant_condition_folding() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("a", Box::new(IntType)), ("b", Box::new(IntType))];
+        assert!(schema.add_table("numbers", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        for (condition, expected) in [
+            ("1 = 1", Some(true)),
+            ("FALSE", Some(false)),
+            ("NOT 'a' = 'b'", Some(true)),
+            ("1 > 2 OR 2.5 >= 2.5", Some(true)),
+            ("TRUE AND 1 != 1", Some(false)),
+            ("a > 1", None),
+        ] {
+            let query = format!("SELECT a FROM numbers GROUP BY a HAVING {}", condition);
+            let tokens = Tokenizer::tokenize(query).ok().unwrap();
+            let queries = parse_gql(tokens, &mut env).ok().unwrap();
+            let Query::Select(select_query) = &queries[0] else {
+                panic!("Expect SELECT query")
+            };
+
+            let having = select_query.statements["having"]
+                .as_any()
+                .downcast_ref::<HavingStatement>()
+                .unwrap();
+            let folded = having
+                .condition
+                .as_any()
+                .downcast_ref::<BooleanExpr>()
+                .map(|boolean| boolean.is_true);
+            assert_eq!(folded, expected, "HAVING {}", condition);
+        }
+    }
+
+    #[test]
+    fn test_join_on_predicate_type_check() {
+        let mut schema = Schema::default();
+        let branches: [(&'static str, Box<dyn DataType>); 2] = [
+            ("name", Box::new(TextType)),
+            ("commit_id", Box::new(IntType)),
+        ];
+        let commits: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("message", Box::new(TextType))];
+        let tags: [(&'static str, Box<dyn DataType>); 1] = [("tag_name", Box::new(TextType))];
+        assert!(schema.add_table("branches", &branches).is_ok());
+        assert!(schema.add_table("commits", &commits).is_ok());
+        assert!(schema.add_table("tags", &tags).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT name, message FROM branches INNER JOIN commits ON branches.commit_id = commits.id";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+        assert_eq!(
+            select_query.hidden_selections["branches"],
+            vec!["branches.commit_id"]
+        );
+        assert_eq!(
+            select_query.hidden_selections["commits"],
+            vec!["commits.id"]
+        );
+
+        let query = "SELECT name FROM branches JOIN commits ON message";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Expect `JOIN` predicate to be type Boolean but got Text"
+        );
+
+        for query in [
+            "SELECT name FROM branches JOIN commits ON branches.id = commits.id",
+            "SELECT name FROM branches JOIN commits ON commit_id = tag_name",
+            "SELECT name FROM branches JOIN commits ON commit_id = tags.tag_name",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_table_alias_and_qualified_columns() {
+        let mut schema = Schema::default();
+        let commits: [(&'static str, Box<dyn DataType>); 3] = [
+            ("id", Box::new(IntType)),
+            ("name", Box::new(TextType)),
+            ("parent_id", Box::new(IntType)),
+        ];
+        let authors: [(&'static str, Box<dyn DataType>); 1] = [("name", Box::new(TextType))];
+        assert!(schema.add_table("commits", &commits).is_ok());
+        assert!(schema.add_table("authors", &authors).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT c.name FROM commits AS c WHERE c.id > 1";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let select = select_query.statements.get("select").unwrap();
+        let select = select.as_any().downcast_ref::<SelectStatement>().unwrap();
+        assert_eq!(select.table_selections[0].table_name, "commits");
+        assert_eq!(select.table_selections[0].reference_name(), "c");
+        assert_eq!(select.table_selections[0].columns_names, vec!["c.name"]);
+        assert_eq!(select_query.hidden_selections["c"], vec!["c.id"]);
+
+        let query =
+            "SELECT a.name, b.name FROM commits AS a JOIN commits AS b ON a.parent_id = b.id";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_ok());
+
+        let query = "SELECT name FROM commits AS a JOIN commits AS b";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Column `name` is ambiguous between tables `a`, `b`"
+        );
+
+        for query in [
+            "SELECT id FROM commits JOIN commits",
+            "SELECT id FROM commits AS a JOIN authors AS a",
+            "SELECT commits.name FROM commits AS c",
+            "SELECT c.title FROM commits AS c",
+            "SELECT c.name FROM commits AS c JOIN authors WHERE name = 'amr'",
+            "SELECT a.id FROM commits AS a JOIN commits AS b WHERE id > 1",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_set_operations_between_select_queries() {
+        let mut schema = Schema::default();
+        let commits: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("name", Box::new(TextType))];
+        let tags: [(&'static str, Box<dyn DataType>); 1] = [("tag_name", Box::new(TextType))];
+        assert!(schema.add_table("commits", &commits).is_ok());
+        assert!(schema.add_table("tags", &tags).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT name FROM commits UNION ALL SELECT tag_name FROM tags \
+                     EXCEPT SELECT 'v1'";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        assert_eq!(queries.len(), 1);
+        let Query::Compound(compound_query) = &queries[0] else {
+            panic!("Expect compound query")
+        };
+        assert!(compound_query.operator == SetOperator::Except);
+        let Query::Compound(left_query) = compound_query.left.as_ref() else {
+            panic!("Expect compound query")
+        };
+        assert!(left_query.operator == SetOperator::UnionAll);
+
+        // INTERSECT binds tighter than UNION and EXCEPT
+        let query = "SELECT name FROM commits UNION SELECT tag_name FROM tags \
+                     INTERSECT SELECT 'v1' EXCEPT SELECT 'v2'";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Compound(compound_query) = &queries[0] else {
+            panic!("Expect compound query")
+        };
+        assert!(compound_query.operator == SetOperator::Except);
+        let Query::Compound(left_query) = compound_query.left.as_ref() else {
+            panic!("Expect compound query")
+        };
+        assert!(left_query.operator == SetOperator::Union);
+        assert!(matches!(left_query.left.as_ref(), Query::Select(_)));
+        let Query::Compound(intersect_query) = left_query.right.as_ref() else {
+            panic!("Expect compound query")
+        };
+        assert!(intersect_query.operator == SetOperator::Intersect);
+
+        let query = "SELECT id, name FROM commits INTERSECT SELECT tag_name FROM tags";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Each `INTERSECT` query must have the same number of columns"
+        );
+
+        let query = "SELECT id FROM commits UNION SELECT tag_name FROM tags";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "`UNION` types `Int` and `Text` of column number 1 can't be matched"
+        );
+
+        for query in [
+            "SELECT id FROM commits UNION",
+            "SELECT id FROM commits UNION DO 1",
+            "SELECT id FROM commits INTO OUTFILE 'ids.csv' UNION SELECT id FROM commits",
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            assert!(parse_gql(tokens, &mut env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_order_by_column_position() {
+        let mut schema = Schema::default();
+        let commits: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("name", Box::new(TextType))];
+        assert!(schema.add_table("commits", &commits).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT name AS title, id FROM commits ORDER BY 2 DESC, 1, id + 1";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let order_by = select_query.statements.get("order").unwrap();
+        let order_by = order_by
+            .as_any()
+            .downcast_ref::<OrderByStatement>()
+            .unwrap();
+        let symbols: Vec<String> = order_by.arguments[..2]
+            .iter()
+            .map(|argument| {
+                let symbol = argument.as_any().downcast_ref::<SymbolExpr>().unwrap();
+                assert!(!symbol.expr_type.is_undefined());
+                symbol.value.to_string()
+            })
+            .collect();
+        assert_eq!(symbols, vec!["id", "title"]);
+        assert!(order_by.arguments[2].kind() == ExprKind::Arithmetic);
+
+        for (query, message) in [
+            (
+                "SELECT name, id FROM commits ORDER BY 5",
+                "ORDER BY position 5 is out of range, query selects 2 columns",
+            ),
+            (
+                "SELECT * FROM commits ORDER BY 0",
+                "ORDER BY position 0 is out of range, query selects 2 columns",
+            ),
+        ] {
+            let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+            let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+            assert_eq!(diagnostic.message(), message);
+        }
+    }
+
+    #[test]
+    fn test_order_by_nulls_policy() {
+        let mut schema = Schema::default();
+        let commits: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("email", Box::new(TextType))];
+        assert!(schema.add_table("commits", &commits).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT id, email FROM commits \
+                     ORDER BY id, id DESC, email DESC NULLS LAST, email NULLS FIRST";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let queries = parse_gql(tokens, &mut env).ok().unwrap();
+        let Query::Select(select_query) = &queries[0] else {
+            panic!("Expect SELECT query")
+        };
+
+        let order_by = select_query.statements.get("order").unwrap();
+        let order_by = order_by
+            .as_any()
+            .downcast_ref::<OrderByStatement>()
+            .unwrap();
+        assert!(
+            order_by.nulls_order_policies
+                == vec![
+                    NullsOrderPolicy::NullsLast,
+                    NullsOrderPolicy::NullsFirst,
+                    NullsOrderPolicy::NullsLast,
+                    NullsOrderPolicy::NullsFirst,
+                ]
+        );
+
+        let tokens = Tokenizer::tokenize("SELECT id FROM commits ORDER BY id NULLS".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Unexpected NULL ordering policy");
+    }
+
+    #[test]
+    fn test_coalesce_function_expression() {
+        let mut env = create_test_environment();
+
+        assert!(parse_do_expression_type(&mut env, "DO COALESCE(NULL, 'a', 'b')").is_text());
+        assert!(parse_do_expression_type(&mut env, "DO COALESCE(1, NULL, 2)").is_int());
+        assert!(parse_do_expression_type(&mut env, "DO COALESCE(NULL, NULL)").is_null());
+
+        let tokens = Tokenizer::tokenize("DO COALESCE(1)".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Function `coalesce` expects at least `2` arguments but got `1`"
+        );
+
+        let tokens = Tokenizer::tokenize("DO COALESCE(NULL, 1, 'a')".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Function `coalesce` argument 3 has different type than the other arguments"
+        );
+    }
+
+    #[test]
+    fn test_in_subquery_expression() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("title", Box::new(TextType))];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+
+        let query = "SELECT title FROM commits WHERE id IN (SELECT id FROM commits)";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_ok());
+
+        let query = "SELECT title FROM commits WHERE title NOT IN (SELECT 1)";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Can't check if `Text` argument is in subquery of `Int` values"
+        );
+
+        let query = "SELECT title FROM commits WHERE title IN (SELECT title, 1 FROM commits)";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "`IN` subquery must select only one column"
+        );
+
+        let nested_query = |depth: usize| {
+            let mut query = "SELECT id FROM commits".to_string();
+            for _ in 0..depth {
+                query = format!("SELECT id FROM commits WHERE id IN ({})", query);
+            }
+            Tokenizer::tokenize(query).ok().unwrap()
+        };
+
+        assert!(parse_gql(nested_query(16), &mut env).is_ok());
+        let diagnostic = parse_gql(nested_query(17), &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Subqueries can't be nested more than 16 levels"
+        );
+
+        env.with_max_subquery_depth(2);
+        assert!(parse_gql(nested_query(2), &mut env).is_ok());
+        let diagnostic = parse_gql(nested_query(3), &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Subqueries can't be nested more than 2 levels"
+        );
+
+        let tokens = Tokenizer::tokenize("DO 1 IN (SELECT 1)".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Subqueries can't be used inside `DO` statement"
+        );
+    }
+
+    #[test]
+    fn test_subquery_scope_is_isolated() {
+        let mut schema = Schema::default();
+        let commits: [(&'static str, Box<dyn DataType>); 2] =
+            [("id", Box::new(IntType)), ("title", Box::new(TextType))];
+        let books: [(&'static str, Box<dyn DataType>); 1] = [("author_id", Box::new(IntType))];
+        assert!(schema.add_table("commits", &commits).is_ok());
+        assert!(schema.add_table("books", &books).is_ok());
+        let mut env = Environment::new(schema);
+
+        // Subquery columns don't leak into the outer query
+        let query = "SELECT title FROM commits \
+                     WHERE (id IN (SELECT author_id FROM books)) AND author_id = 1";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "Unresolved column or variable name");
+
+        // Outer query columns are not visible inside the subquery
+        let query = "SELECT title FROM commits WHERE id IN (SELECT title FROM books)";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Column `title` not exists in any of the selected tables"
+        );
+
+        // The outer query scope is restored after parsing the subquery
+        let query = "SELECT title FROM commits \
+                     WHERE (id IN (SELECT author_id FROM books)) AND title = 'a'";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_ok());
+        assert!(env.scopes.is_empty());
+    }
+
+    #[test]
+    fn test_distinct_modifier_in_function_call() {
+        let mut schema = Schema::default();
+        let columns: [(&'static str, Box<dyn DataType>); 1] = [("title", Box::new(TextType))];
+        assert!(schema.add_table("commits", &columns).is_ok());
+        let mut env = Environment::new(schema);
+        env.std_signatures.insert(
+            "lower",
+            Signature {
+                parameters: vec![Box::new(TextType)],
+                return_type: Box::new(TextType),
+            },
+        );
+        env.std_functions
+            .insert("lower", |inputs| inputs[0].clone());
+        env.aggregation_signatures.insert(
+            "count",
+            Signature {
+                parameters: vec![Box::new(AnyType)],
+                return_type: Box::new(IntType),
+            },
+        );
+
+        let query = "SELECT COUNT(DISTINCT title) FROM commits";
+        let tokens = Tokenizer::tokenize(query.to_string()).ok().unwrap();
+        assert!(parse_gql(tokens, &mut env).is_ok());
+
+        let tokens = Tokenizer::tokenize("SELECT LOWER(DISTINCT title) FROM commits".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Function `lower` doesn't support `DISTINCT` modifier"
+        );
+
+        let tokens = Tokenizer::tokenize("SELECT UNKNOWN(DISTINCT title) FROM commits".to_string())
+            .ok()
+            .unwrap();
+        let diagnostic = parse_gql(tokens, &mut env).err().unwrap();
+        assert_eq!(diagnostic.message(), "No such function name");
+    }
+}
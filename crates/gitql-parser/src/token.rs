@@ -2,12 +2,15 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result;
 
+pub use gitql_ast::location::SourceLocation;
+
 #[derive(PartialEq)]
 pub enum TokenKind {
     Do,
     Set,
     Select,
     Distinct,
+    Exclude,
     From,
     Group,
     Where,
@@ -63,11 +66,16 @@ pub enum TokenKind {
     Partition,
     First,
     Last,
+    Collate,
     Interval,
+    Union,
+    Intersect,
+    Except,
 
     // Values
     Symbol(String),
     GlobalVariable(String),
+    Parameter(usize),
     String(String),
     Integer(i64),
     Float(f64),
@@ -92,6 +100,8 @@ pub enum TokenKind {
     NullSafeEqual,
     AtRightArrow,
     ArrowRightAt,
+    RightArrow,
+    LongRightArrow,
     LeftParen,
     RightParen,
     LeftBracket,
@@ -126,6 +136,7 @@ impl Display for TokenKind {
             TokenKind::Set => "SET",
             TokenKind::Select => "SELECT",
             TokenKind::Distinct => "DISTINCT",
+            TokenKind::Exclude => "EXCLUDE",
             TokenKind::From => "FROM",
             TokenKind::Group => "GROUP",
             TokenKind::Where => "WHERE",
@@ -182,11 +193,16 @@ impl Display for TokenKind {
             TokenKind::Nulls => "NULLS",
             TokenKind::First => "FIRST",
             TokenKind::Last => "LAST",
+            TokenKind::Collate => "COLLATE",
             TokenKind::Interval => "INTERVAL",
+            TokenKind::Union => "UNION",
+            TokenKind::Intersect => "INTERSECT",
+            TokenKind::Except => "EXCEPT",
 
             // Values
             TokenKind::Symbol(literal) => literal,
             TokenKind::GlobalVariable(literal) => literal,
+            TokenKind::Parameter(index) => &format!("${}", index),
             TokenKind::String(string) => string,
             TokenKind::Integer(integer) => &integer.to_string(),
             TokenKind::Float(float) => &float.to_string(),
@@ -212,6 +228,8 @@ impl Display for TokenKind {
             TokenKind::NullSafeEqual => "<=>",
             TokenKind::AtRightArrow => "@>",
             TokenKind::ArrowRightAt => "<@",
+            TokenKind::RightArrow => "->",
+            TokenKind::LongRightArrow => "->>",
             TokenKind::LeftParen => "(",
             TokenKind::RightParen => ")",
             TokenKind::LeftBracket => "[",
@@ -241,44 +259,6 @@ impl Display for TokenKind {
     }
 }
 
-#[derive(Copy, Clone)]
-pub struct SourceLocation {
-    pub line_start: u32,
-    pub line_end: u32,
-    pub column_start: u32,
-    pub column_end: u32,
-}
-
-impl SourceLocation {
-    pub fn new(
-        line_start: u32,
-        line_end: u32,
-        column_start: u32,
-        column_end: u32,
-    ) -> SourceLocation {
-        SourceLocation {
-            line_start,
-            line_end,
-            column_start,
-            column_end,
-        }
-    }
-
-    pub fn expand_until(&mut self, location: SourceLocation) {
-        self.column_end = location.column_end;
-        self.line_end = location.line_end;
-    }
-}
-
-impl Display for SourceLocation {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.write_fmt(format_args!(
-            "Loc(L {}:{}, C {}:{})",
-            self.line_start, self.line_end, self.column_start, self.column_end
-        ))
-    }
-}
-
 pub struct Token {
     pub kind: TokenKind,
     pub location: SourceLocation,
@@ -314,6 +294,7 @@ fn resolve_symbol_kind(symbol: String) -> TokenKind {
         "set" => TokenKind::Set,
         "select" => TokenKind::Select,
         "distinct" => TokenKind::Distinct,
+        "exclude" => TokenKind::Exclude,
         "from" => TokenKind::From,
         "where" => TokenKind::Where,
         "limit" => TokenKind::Limit,
@@ -404,6 +385,14 @@ fn resolve_symbol_kind(symbol: String) -> TokenKind {
         "first" => TokenKind::First,
         "last" => TokenKind::Last,
 
+        // Order by text collation
+        "collate" => TokenKind::Collate,
+
+        // Set operations between queries
+        "union" => TokenKind::Union,
+        "intersect" => TokenKind::Intersect,
+        "except" => TokenKind::Except,
+
         // Array data type
         "array" => TokenKind::Array,
 
@@ -3,6 +3,10 @@ use crate::token::SourceLocation;
 use crate::token::Token;
 use crate::token::TokenKind;
 
+const BINARY_RADIX: u32 = 2;
+const OCTAL_RADIX: u32 = 8;
+const HEX_RADIX: u32 = 16;
+
 pub struct Tokenizer {
     pub(crate) content: Vec<char>,
     pub(crate) content_len: usize,
@@ -73,27 +77,35 @@ impl Tokenizer {
                 continue;
             }
 
+            // Positional parameter
+            if char == '$' {
+                tokens.push(self.consume_positional_parameter()?);
+                continue;
+            }
+
+            // Numeric literal can't start with digits separator like `_5`
+            if char == '_' && self.index + 1 < len && self.content[self.index + 1].is_numeric() {
+                let start_index = self.index;
+                while self.has_next() && self.is_current_char_func(|c| c == '_' || c.is_numeric()) {
+                    self.advance();
+                }
+
+                let literal: String = self.content[start_index..self.index].iter().collect();
+                return Err(self.invalid_digits_separators_error(&literal));
+            }
+
             // Number
             if char.is_numeric() {
                 if char == '0' && self.index + 1 < len {
-                    if self.content[self.index + 1] == 'x' {
-                        self.index += 2;
-                        self.column_start += 2;
-                        tokens.push(self.consume_hex_number()?);
-                        continue;
-                    }
-
-                    if self.content[self.index + 1] == 'b' {
-                        self.index += 2;
-                        self.column_start += 2;
-                        tokens.push(self.consume_binary_number()?);
-                        continue;
-                    }
-
-                    if self.content[self.index + 1] == 'o' {
-                        self.index += 2;
-                        self.column_start += 2;
-                        tokens.push(self.consume_octal_number()?);
+                    let radix = match self.content[self.index + 1] {
+                        'x' => Some(HEX_RADIX),
+                        'b' => Some(BINARY_RADIX),
+                        'o' => Some(OCTAL_RADIX),
+                        _ => None,
+                    };
+
+                    if let Some(radix) = radix {
+                        tokens.push(self.consume_radix_number(radix)?);
                         continue;
                     }
                 }
@@ -136,6 +148,22 @@ impl Tokenizer {
                     continue;
                 }
 
+                // JSON access operators `->` and `->>`
+                if self.index + 1 < self.content_len && self.content[self.index + 1] == '>' {
+                    let location = self.current_source_location();
+                    self.advance();
+                    self.advance();
+
+                    if self.index < self.content_len && self.content[self.index] == '>' {
+                        self.advance();
+                        tokens.push(Token::new(TokenKind::LongRightArrow, location));
+                        continue;
+                    }
+
+                    tokens.push(Token::new(TokenKind::RightArrow, location));
+                    continue;
+                }
+
                 let location = self.current_source_location();
                 tokens.push(Token::new(TokenKind::Minus, location));
                 self.advance();
@@ -434,6 +462,31 @@ impl Tokenizer {
         Ok(Token::new(TokenKind::GlobalVariable(string), location))
     }
 
+    fn consume_positional_parameter(&mut self) -> Result<Token, Box<Diagnostic>> {
+        // Advance `$`
+        self.advance();
+
+        let start_index = self.index;
+        while self.has_next() && self.is_current_char_func(|c| c.is_ascii_digit()) {
+            self.advance();
+        }
+
+        let literal = &self.content[start_index..self.index];
+        let string: String = literal.iter().collect();
+        match string.parse::<usize>() {
+            Ok(index) if index > 0 => {
+                let location = self.current_source_location();
+                Ok(Token::new(TokenKind::Parameter(index), location))
+            }
+            _ => Err(
+                Diagnostic::error("Positional parameter must be `$` followed by number")
+                    .add_help("Positional parameters start from one, for example `$1`")
+                    .with_location(self.current_source_location())
+                    .as_boxed(),
+            ),
+        }
+    }
+
     fn consume_identifier(&mut self) -> Token {
         let start_index = self.index;
 
@@ -495,6 +548,7 @@ impl Tokenizer {
 
         let literal = &self.content[start_index..self.index];
         let string: String = literal.iter().collect();
+        self.check_digits_separators(&string)?;
         let literal_num = string.replace('_', "");
         let location = self.current_source_location();
 
@@ -525,119 +579,98 @@ impl Tokenizer {
         }
     }
 
-    fn consume_binary_number(&mut self) -> Result<Token, Box<Diagnostic>> {
-        let start_index = self.index;
-        let mut has_digit = false;
+    /// Check that `_` digits separators are only used between two digits like `1_000_000`
+    fn check_digits_separators(&self, literal: &str) -> Result<(), Box<Diagnostic>> {
+        let is_valid = literal.split('.').all(|digits| {
+            !digits.starts_with('_') && !digits.ends_with('_') && !digits.contains("__")
+        });
 
-        while self.has_next() && self.is_current_char_func(|c| c == '_' || c == '0' || c >= '1') {
-            self.advance();
-            has_digit = true;
+        if is_valid {
+            return Ok(());
         }
 
-        if !has_digit {
-            return Err(
-                Diagnostic::error("Missing digits after the integer base prefix")
-                    .add_help("Expect at least one binary digits after the prefix 0b")
-                    .add_help("Binary digit mean 0 or 1")
-                    .with_location(self.current_source_location())
-                    .as_boxed(),
-            );
-        }
-
-        let literal = &self.content[start_index..self.index];
-        let string: String = literal.iter().collect();
-        let literal_num = string.replace('_', "");
+        Err(self.invalid_digits_separators_error(literal))
+    }
 
-        const BINARY_RADIX: u32 = 2;
-        match i64::from_str_radix(&literal_num, BINARY_RADIX) {
-            Ok(integer) => {
-                let location = self.current_source_location();
-                Ok(Token::new(TokenKind::Integer(integer), location))
-            }
-            Err(parse_int_error) => Err(Diagnostic::error(&parse_int_error.to_string())
-                .add_note(&format!(
-                    "Value must be between {} and {}",
-                    i64::MIN,
-                    i64::MAX
-                ))
-                .with_location(self.current_source_location())
-                .as_boxed()),
-        }
+    fn invalid_digits_separators_error(&self, literal: &str) -> Box<Diagnostic> {
+        Diagnostic::error(&format!(
+            "Invalid digits separator in numeric literal `{}`",
+            literal
+        ))
+        .add_note("Digits separator `_` must be between two digits like `1_000_000`")
+        .with_location(self.current_source_location())
+        .as_boxed()
     }
 
-    fn consume_octal_number(&mut self) -> Result<Token, Box<Diagnostic>> {
-        let start_index = self.index;
-        let mut has_digit = false;
+    /// Consume Integer literal with base prefix `0x`, `0b` or `0o`
+    fn consume_radix_number(&mut self, radix: u32) -> Result<Token, Box<Diagnostic>> {
+        let (base_name, prefix, digits_help) = match radix {
+            HEX_RADIX => ("hex", "0x", "Hex digit mean 0 to 9 and a to f"),
+            BINARY_RADIX => ("binary", "0b", "Binary digit mean 0 or 1"),
+            _ => ("octal", "0o", "Octal digit mean 0 to 7"),
+        };
 
-        while self.has_next() && self.is_current_char_func(|c| c == '_' || ('0'..='8').contains(&c))
-        {
-            self.advance();
-            has_digit = true;
-        }
+        // Advance the base prefix
+        self.advance_n(2);
 
-        if !has_digit {
-            return Err(
-                Diagnostic::error("Missing digits after the integer base prefix")
-                    .add_help("Expect at least one octal digits after the prefix 0o")
-                    .add_help("Octal digit mean 0 to 8 number")
-                    .with_location(self.current_source_location())
-                    .as_boxed(),
-            );
+        let start_index = self.index;
+        while self.has_next() && self.is_current_char_func(|c| c == '_' || c.is_alphanumeric()) {
+            self.advance();
         }
 
         let literal = &self.content[start_index..self.index];
         let string: String = literal.iter().collect();
         let literal_num = string.replace('_', "");
 
-        const OCTAL_RADIX: u32 = 2;
-        match i64::from_str_radix(&literal_num, OCTAL_RADIX) {
-            Ok(integer) => {
-                let location = self.current_source_location();
-                Ok(Token::new(TokenKind::Integer(integer), location))
-            }
-            Err(parse_int_error) => Err(Diagnostic::error(&parse_int_error.to_string())
-                .add_note(&format!(
-                    "Value must be between {} and {}",
-                    i64::MIN,
-                    i64::MAX
-                ))
-                .with_location(self.current_source_location())
-                .as_boxed()),
-        }
-    }
-
-    fn consume_hex_number(&mut self) -> Result<Token, Box<Diagnostic>> {
-        let start_index = self.index;
-        let mut has_digit = false;
-
-        while self.has_next() && self.is_current_char_func(|c| c == '_' || c.is_ascii_hexdigit()) {
-            self.advance();
-            has_digit = true;
+        if !literal_num.is_empty() {
+            self.check_digits_separators(&string)?;
         }
 
-        if !has_digit {
+        if literal_num.is_empty() {
             return Err(
                 Diagnostic::error("Missing digits after the integer base prefix")
-                    .add_help("Expect at least one hex digits after the prefix 0x")
-                    .add_help("Hex digit mean 0 to 9 and a to f")
+                    .add_help(&format!(
+                        "Expect at least one {} digits after the prefix {}",
+                        base_name, prefix
+                    ))
+                    .add_help(digits_help)
                     .with_location(self.current_source_location())
                     .as_boxed(),
             );
         }
 
-        let literal = &self.content[start_index..self.index];
-        let string: String = literal.iter().collect();
-        let literal_num = string.replace('_', "");
+        if let Some(invalid_digit) = literal_num.chars().find(|c| !c.is_digit(radix)) {
+            return Err(Diagnostic::error(&format!(
+                "Invalid digit `{}` in {} Integer literal",
+                invalid_digit, base_name
+            ))
+            .add_help(digits_help)
+            .with_location(self.current_source_location())
+            .as_boxed());
+        }
 
-        const HEX_RADIX: u32 = 16;
-        match i64::from_str_radix(&literal_num, HEX_RADIX) {
+        if self.index + 1 < self.content_len
+            && self.is_current_char('.')
+            && self.content[self.index + 1].is_ascii_digit()
+        {
+            return Err(Diagnostic::error(&format!(
+                "Float literal with {} prefix is not supported",
+                prefix
+            ))
+            .add_help("Use decimal Float literal instead")
+            .with_location(self.current_source_location())
+            .as_boxed());
+        }
+
+        match i64::from_str_radix(&literal_num, radix) {
             Ok(integer) => {
                 let location = self.current_source_location();
                 Ok(Token::new(TokenKind::Integer(integer), location))
             }
-            Err(parse_int_error) => Err(Diagnostic::error(&parse_int_error.to_string())
+            Err(_) => Err(Diagnostic::error("Too big Integer value")
+                .add_help("Try to use smaller value")
                 .add_note(&format!(
-                    "Value must be between {} and {}",
+                    "Integer value must be between {} and {}",
                     i64::MIN,
                     i64::MAX
                 ))
@@ -761,8 +794,33 @@ impl Tokenizer {
         self.advance_n(2);
 
         while self.index + 1 < self.content_len
-            && (!self.is_current_char('*') && self.content[self.index + 1] != '/')
+            && !(self.is_current_char('*') && self.content[self.index + 1] == '/')
         {
+            // Nested comments are not supported, the first `*/` will end the outer comment
+            if self.is_current_char('/') && self.content[self.index + 1] == '*' {
+                let location = SourceLocation {
+                    line_start: self.line_end,
+                    line_end: self.line_end,
+                    column_start: self.column_end,
+                    column_end: self.column_end + 2,
+                };
+
+                return Err(
+                    Diagnostic::error("Nested C Style comments are not supported")
+                        .add_help("Remove the inner /* or end the outer comment with */ before it")
+                        .with_location(location)
+                        .as_boxed(),
+                );
+            }
+
+            // Keep tracking lines so locations after multi lines comment are correct
+            if self.is_current_char('\n') {
+                self.advance();
+                self.line_end += 1;
+                self.column_end = 0;
+                continue;
+            }
+
             // Advance char
             self.advance();
         }
@@ -805,3 +863,96 @@ impl Tokenizer {
         self.index == self.content_len - 1
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_style_comment_with_star_inside() {
+        let tokens = Tokenizer::tokenize("DO /* 2 * 3 */ 1".to_string())
+            .ok()
+            .unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens[1].kind == TokenKind::Integer(1));
+    }
+
+    #[test]
+    fn test_nested_c_style_comment() {
+        let query = "DO 1 /* outer /* inner */ */".to_string();
+        let diagnostic = Tokenizer::tokenize(query).err().unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Nested C Style comments are not supported"
+        );
+        assert_eq!(diagnostic.location().unwrap().column_start, 14);
+
+        let tokens = Tokenizer::tokenize("DO '/* -- not a comment */'".to_string())
+            .ok()
+            .unwrap();
+        assert!(tokens[1].kind == TokenKind::String("/* -- not a comment */".to_string()));
+    }
+
+    #[test]
+    fn test_integer_literals_with_base_prefix() {
+        let tokens = Tokenizer::tokenize("DO 0xFF + 0o755 + 0b1010 + 0x1_0".to_string())
+            .ok()
+            .unwrap();
+        let integers: Vec<i64> = tokens
+            .iter()
+            .filter_map(|token| match token.kind {
+                TokenKind::Integer(integer) => Some(integer),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(integers, vec![255, 493, 10, 16]);
+        assert_eq!(tokens[1].location.column_start, 3);
+        assert_eq!(tokens[1].location.column_end, 7);
+
+        for (query, message) in [
+            ("DO 0b102", "Invalid digit `2` in binary Integer literal"),
+            ("DO 0o8", "Invalid digit `8` in octal Integer literal"),
+            ("DO 0x", "Missing digits after the integer base prefix"),
+            ("DO 0x1.5", "Float literal with 0x prefix is not supported"),
+            ("DO 0xFFFFFFFFFFFFFFFFF", "Too big Integer value"),
+        ] {
+            let diagnostic = Tokenizer::tokenize(query.to_string()).err().unwrap();
+            assert_eq!(diagnostic.message(), message);
+        }
+    }
+
+    #[test]
+    fn test_numeric_literals_digits_separators() {
+        let tokens = Tokenizer::tokenize("DO 1_000_000 + 1.250_5".to_string())
+            .ok()
+            .unwrap();
+        assert!(tokens[1].kind == TokenKind::Integer(1_000_000));
+        assert!(tokens[3].kind == TokenKind::Float(1.250_5));
+
+        for query in [
+            "DO 1__0", "DO _5", "DO 5_", "DO 1_.5", "DO 1._5", "DO 0x_10",
+        ] {
+            let diagnostic = Tokenizer::tokenize(query.to_string()).err().unwrap();
+            assert!(diagnostic
+                .message()
+                .starts_with("Invalid digits separator in numeric literal"));
+        }
+
+        let diagnostic = Tokenizer::tokenize("DO _5_000 + 1".to_string())
+            .err()
+            .unwrap();
+        assert_eq!(
+            diagnostic.message(),
+            "Invalid digits separator in numeric literal `_5_000`"
+        );
+    }
+
+    #[test]
+    fn test_error_location_after_comments() {
+        let query = "DO 1 /* first\nsecond */ -- third\n  ?".to_string();
+        let diagnostic = Tokenizer::tokenize(query).err().unwrap();
+        let location = diagnostic.location().unwrap();
+        assert_eq!(location.line_start, 3);
+        assert_eq!(location.column_start, 2);
+    }
+}
@@ -5,6 +5,7 @@ use gitql_ast::expression::Expr;
 use gitql_ast::statement::TableSelection;
 use gitql_ast::types::any::AnyType;
 use gitql_ast::types::dynamic::DynamicType;
+use gitql_ast::types::null::NullType;
 use gitql_ast::types::varargs::VarargsType;
 use gitql_ast::types::DataType;
 use gitql_core::environment::Environment;
@@ -31,6 +32,29 @@ pub fn check_all_values_are_same_type(arguments: &[Box<dyn Expr>]) -> Option<Box
     Some(data_type)
 }
 
+/// Resolve the common type of values from the first value that is not NULL,
+/// NULL values are allowed in any position and if all values are NULL the type is NULL
+///
+/// Return the index of the first value that has a different type if the types are not matched
+pub fn resolve_values_common_type<'a>(
+    values: impl Iterator<Item = &'a Box<dyn Expr>> + Clone,
+) -> Result<Box<dyn DataType>, usize> {
+    let values_type = values
+        .clone()
+        .map(|value| value.expr_type())
+        .find(|value_type| !value_type.is_null())
+        .unwrap_or_else(|| Box::new(NullType));
+
+    for (i, value) in values.enumerate() {
+        let value_type = value.expr_type();
+        if !value_type.is_null() && !values_type.equals(&value_type) {
+            return Err(i);
+        }
+    }
+
+    Ok(values_type)
+}
+
 /// Check That function call arguments types are matches the parameter types
 /// Return a Diagnostic Error if anything is wrong
 pub fn check_function_call_arguments(
@@ -108,6 +132,7 @@ pub fn check_function_call_arguments(
             arguments[index] = Box::new(CastExpr {
                 value: argument.clone(),
                 result_type: parameter_type.clone(),
+                location: None,
             });
             continue;
         }
@@ -158,6 +183,7 @@ pub fn check_function_call_arguments(
             arguments[index] = Box::new(CastExpr {
                 value: argument.clone(),
                 result_type: parameter_type.clone(),
+                location: None,
             });
             continue;
         }
@@ -204,6 +230,7 @@ pub fn check_function_call_arguments(
                 arguments[index] = Box::new(CastExpr {
                     value: argument.clone(),
                     result_type: varargs_type.clone(),
+                    location: None,
                 });
                 continue;
             }
@@ -223,29 +250,103 @@ pub fn check_function_call_arguments(
     Ok(())
 }
 
+/// Return the table name of a selected table reference, that can be the table name or its alias
+pub fn resolve_table_name<'a>(
+    tables_aliases: &'a HashMap<String, String>,
+    reference_name: &'a str,
+) -> &'a str {
+    tables_aliases
+        .get(reference_name)
+        .map(|table_name| table_name.as_str())
+        .unwrap_or(reference_name)
+}
+
+/// Split qualified column `table.column` into the selected table reference and the column name
+/// Return None if the column is not qualified by one of the selected tables
+pub fn split_qualified_column<'a>(
+    selected_tables: &[String],
+    column: &'a str,
+) -> Option<(&'a str, &'a str)> {
+    let (qualifier, column_name) = column.split_once('.')?;
+    if selected_tables.iter().any(|table| table == qualifier) {
+        return Some((qualifier, column_name));
+    }
+    None
+}
+
+/// Return the references of the selected tables that have a column with this name
+pub fn tables_with_column<'a>(
+    env: &Environment,
+    selected_tables: &'a [String],
+    tables_aliases: &HashMap<String, String>,
+    column: &str,
+) -> Vec<&'a String> {
+    selected_tables
+        .iter()
+        .filter(|table| {
+            let table_name = resolve_table_name(tables_aliases, table);
+            env.schema
+                .tables_fields_names
+                .get(table_name)
+                .is_some_and(|columns| columns.contains(&column))
+        })
+        .collect()
+}
+
+/// Return a Diagnostic Error for unqualified column that exists in more than one of the selected tables
+pub fn ambiguous_column_error(
+    column: &str,
+    tables: &[&String],
+    location: SourceLocation,
+) -> Box<Diagnostic> {
+    let tables_names: Vec<String> = tables.iter().map(|table| format!("`{}`", table)).collect();
+    Diagnostic::error(&format!(
+        "Column `{}` is ambiguous between tables {}",
+        column,
+        tables_names.join(", ")
+    ))
+    .add_help(&format!(
+        "Qualify the column with the table name or alias like `{}.{}`",
+        tables[0], column
+    ))
+    .with_location(location)
+    .as_boxed()
+}
+
 /// Check that all selected fields types are defined correctly in selected tables
 /// Return the columns classified for each table
 /// Return a Diagnostic Error if anything is wrong
 pub fn type_check_and_classify_selected_fields(
     env: &mut Environment,
     selected_tables: &Vec<String>,
+    tables_aliases: &HashMap<String, String>,
     selected_columns: &Vec<String>,
     location: SourceLocation,
 ) -> Result<Vec<TableSelection>, Box<Diagnostic>> {
     let mut table_selections: Vec<TableSelection> = vec![];
     let mut table_index: HashMap<String, usize> = HashMap::new();
     for (index, table) in selected_tables.iter().enumerate() {
+        let table_name = resolve_table_name(tables_aliases, table);
         table_selections.push(TableSelection {
-            table_name: table.to_string(),
+            table_name: table_name.to_string(),
+            table_alias: tables_aliases.get(table).map(|_| table.to_string()),
             columns_names: vec![],
         });
         table_index.insert(table.to_string(), index);
     }
 
     for selected_column in selected_columns {
+        // Qualified column is selected only from the table that it references
+        if let Some((qualifier, _)) = split_qualified_column(selected_tables, selected_column) {
+            let selection = &mut table_selections[table_index[qualifier]];
+            selection.columns_names.push(selected_column.to_string());
+            continue;
+        }
+
         let mut is_column_resolved = false;
         for table in selected_tables {
-            let table_columns = env.schema.tables_fields_names.get(table.as_str()).unwrap();
+            let table_name = resolve_table_name(tables_aliases, table);
+            let table_columns = env.schema.tables_fields_names.get(table_name).unwrap();
 
             // Check if this column name exists in current table
             if table_columns.contains(&selected_column.as_str()) {
@@ -267,6 +368,7 @@ pub fn type_check_and_classify_selected_fields(
                                 .first()
                                 .unwrap_or(&"".to_string())
                                 .to_string(),
+                            table_alias: None,
                             columns_names: vec![selected_column.to_string()],
                         });
                     } else {
@@ -292,24 +394,18 @@ pub fn type_check_and_classify_selected_fields(
 }
 
 /// Check that all projection columns are valid for this table name
+/// and not ambiguous between the selected tables
 /// Return a Diagnostic Error if anything is wrong
 pub fn type_check_projection_symbols(
     env: &mut Environment,
     selected_tables: &[String],
+    tables_aliases: &HashMap<String, String>,
     projection_names: &[String],
     projection_locations: &[SourceLocation],
 ) -> Result<(), Box<Diagnostic>> {
     for (index, selected_column) in projection_names.iter().enumerate() {
-        let mut is_column_resolved = false;
-        for table in selected_tables {
-            let table_columns = env.schema.tables_fields_names.get(table.as_str()).unwrap();
-            if table_columns.contains(&selected_column.as_str()) {
-                is_column_resolved = true;
-                break;
-            }
-        }
-
-        if !is_column_resolved {
+        let tables = tables_with_column(env, selected_tables, tables_aliases, selected_column);
+        if tables.is_empty() {
             return Err(Diagnostic::error(&format!(
                 "Column `{}` not exists in any of the selected tables",
                 selected_column
@@ -318,6 +414,14 @@ pub fn type_check_projection_symbols(
             .with_location(projection_locations[index])
             .as_boxed());
         }
+
+        if tables.len() > 1 {
+            return Err(ambiguous_column_error(
+                selected_column,
+                &tables,
+                projection_locations[index],
+            ));
+        }
     }
 
     Ok(())
@@ -15,6 +15,9 @@ use gitql_ast::types::text::TextType;
 use gitql_ast::types::time::TimeType;
 use gitql_ast::types::varargs::VarargsType;
 use gitql_ast::types::variant::VariantType;
+use gitql_core::indexmap::IndexMap;
+use gitql_core::signature::AggregationAccumulator;
+use gitql_core::signature::AggregationAccumulatorFactory;
 use gitql_core::signature::AggregationFunction;
 use gitql_core::signature::Signature;
 use gitql_core::values::array::ArrayValue;
@@ -38,11 +41,32 @@ pub fn aggregation_functions() -> &'static HashMap<&'static str, AggregationFunc
         map.insert("count", aggregation_count);
         map.insert("group_concat", aggregation_group_concat);
         map.insert("bool_and", aggregation_bool_and);
+        map.insert("every", aggregation_bool_and);
         map.insert("bool_or", aggregation_bool_or);
         map.insert("bit_and", aggregation_bit_and);
         map.insert("bit_or", aggregation_bit_or);
         map.insert("bit_xor", aggregation_bit_xor);
         map.insert("array_agg", aggregation_array_agg);
+        map.insert("mode", aggregation_mode);
+        map.insert("json_agg", aggregation_json_agg);
+        map.insert("json_array_agg", aggregation_json_agg);
+        map
+    })
+}
+
+/// Accumulators of the aggregation functions that can be computed row by row without collecting the group rows,
+/// other aggregation functions are computed from the collected rows
+pub fn aggregation_accumulators() -> &'static HashMap<&'static str, AggregationAccumulatorFactory> {
+    static HASHMAP: OnceLock<HashMap<&'static str, AggregationAccumulatorFactory>> =
+        OnceLock::new();
+    HASHMAP.get_or_init(|| {
+        let mut map: HashMap<&'static str, AggregationAccumulatorFactory> = HashMap::new();
+        map.insert("max", || Box::new(OrderingAccumulator::new(Ordering::Less)));
+        map.insert("min", || {
+            Box::new(OrderingAccumulator::new(Ordering::Greater))
+        });
+        map.insert("sum", || Box::new(SumAccumulator { sum: 0 }));
+        map.insert("count", || Box::new(CountAccumulator { count: 0 }));
         map
     })
 }
@@ -124,6 +148,13 @@ pub fn aggregation_function_signatures() -> HashMap<&'static str, Signature> {
             return_type: Box::new(BoolType),
         },
     );
+    map.insert(
+        "every",
+        Signature {
+            parameters: vec![Box::new(BoolType)],
+            return_type: Box::new(BoolType),
+        },
+    );
     map.insert(
         "bool_or",
         Signature {
@@ -161,6 +192,29 @@ pub fn aggregation_function_signatures() -> HashMap<&'static str, Signature> {
             }),
         },
     );
+    map.insert(
+        "mode",
+        Signature {
+            parameters: vec![Box::new(AnyType)],
+            return_type: Box::new(DynamicType {
+                function: first_element_type,
+            }),
+        },
+    );
+    map.insert(
+        "json_agg",
+        Signature {
+            parameters: vec![Box::new(AnyType)],
+            return_type: Box::new(TextType),
+        },
+    );
+    map.insert(
+        "json_array_agg",
+        Signature {
+            parameters: vec![Box::new(AnyType)],
+            return_type: Box::new(TextType),
+        },
+    );
     map
 }
 
@@ -226,13 +280,20 @@ pub fn aggregation_group_concat(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn
 }
 
 pub fn aggregation_bool_and(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let mut has_non_null = false;
     for row_values in group_values {
         if let Some(bool_value) = row_values[0].as_any().downcast_ref::<BoolValue>() {
             if !bool_value.value {
                 return Box::new(BoolValue { value: false });
             }
+            has_non_null = true;
         }
     }
+
+    if !has_non_null {
+        return Box::new(NullValue);
+    }
+
     Box::new(BoolValue { value: true })
 }
 
@@ -327,3 +388,241 @@ pub fn aggregation_array_agg(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Va
         base_type: element_type,
     })
 }
+
+pub fn aggregation_mode(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    // Count non null values by their hash key, keeping the order of the first seen
+    let mut counts: IndexMap<String, (usize, &Box<dyn Value>)> = IndexMap::new();
+    for row_values in group_values {
+        let value = &row_values[0];
+        if value.data_type().is_null() {
+            continue;
+        }
+
+        counts.entry(value.hash_key()).or_insert((0, value)).0 += 1;
+    }
+
+    let mut mode_value: Option<&Box<dyn Value>> = None;
+    let mut mode_count = 0;
+    for (count, value) in counts.values() {
+        if *count > mode_count {
+            mode_count = *count;
+            mode_value = Some(value);
+        }
+    }
+
+    match mode_value {
+        Some(value) => value.clone(),
+        None => Box::new(NullValue),
+    }
+}
+
+pub fn aggregation_json_agg(group_values: &[Vec<Box<dyn Value>>]) -> Box<dyn Value> {
+    let mut elements: Vec<String> = Vec::with_capacity(group_values.len());
+    for row_values in group_values {
+        elements.push(value_to_json(&row_values[0]));
+    }
+    Box::new(TextValue {
+        value: format!("[{}]", elements.join(",")),
+    })
+}
+
+/// Format the value as JSON element, numbers and booleans are unquoted and NULL is `null`,
+/// other values are represented as JSON strings of their literal
+#[allow(clippy::borrowed_box)]
+fn value_to_json(value: &Box<dyn Value>) -> String {
+    if value.is_null() {
+        return "null".to_string();
+    }
+
+    if value.is_int() || value.is_bool() {
+        return value.literal();
+    }
+
+    if let Some(float) = value.as_float() {
+        return if float.is_finite() {
+            value.literal()
+        } else {
+            "null".to_string()
+        };
+    }
+
+    let literal = value.literal();
+    let mut json = String::with_capacity(literal.len() + 2);
+    json.push('"');
+    for character in literal.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Keep the first value and replace it by each value that it compares to with the target ordering,
+/// `Less` keeps the maximum value and `Greater` keeps the minimum value like `aggregation_max` and `aggregation_min`
+struct OrderingAccumulator {
+    value: Option<Box<dyn Value>>,
+    replace_ordering: Ordering,
+}
+
+impl OrderingAccumulator {
+    fn new(replace_ordering: Ordering) -> Self {
+        OrderingAccumulator {
+            value: None,
+            replace_ordering,
+        }
+    }
+}
+
+impl AggregationAccumulator for OrderingAccumulator {
+    fn accumulate(&mut self, mut arguments: Vec<Box<dyn Value>>) {
+        let single_value = arguments.swap_remove(0);
+        match &self.value {
+            Some(value) if value.compare(&single_value) != Some(self.replace_ordering) => {}
+            _ => self.value = Some(single_value),
+        }
+    }
+
+    fn finish(&mut self) -> Box<dyn Value> {
+        self.value.take().unwrap_or_else(|| Box::new(NullValue))
+    }
+}
+
+struct SumAccumulator {
+    sum: i64,
+}
+
+impl AggregationAccumulator for SumAccumulator {
+    fn accumulate(&mut self, arguments: Vec<Box<dyn Value>>) {
+        if let Some(int_value) = arguments[0].as_any().downcast_ref::<IntValue>() {
+            self.sum += int_value.value;
+        }
+    }
+
+    fn finish(&mut self) -> Box<dyn Value> {
+        Box::new(IntValue { value: self.sum })
+    }
+}
+
+struct CountAccumulator {
+    count: i64,
+}
+
+impl AggregationAccumulator for CountAccumulator {
+    fn accumulate(&mut self, _arguments: Vec<Box<dyn Value>>) {
+        self.count += 1;
+    }
+
+    fn finish(&mut self) -> Box<dyn Value> {
+        Box::new(IntValue { value: self.count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::date::DateValue;
+
+    use super::*;
+
+    fn text_rows(values: &[&str]) -> Vec<Vec<Box<dyn Value>>> {
+        values
+            .iter()
+            .map(|value| vec![Box::new(TextValue::new(value.to_string())) as Box<dyn Value>])
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregation_mode() {
+        let rows = text_rows(&["fix", "feat", "fix", "docs", "fix"]);
+        assert_eq!(aggregation_mode(&rows).as_text(), Some("fix".to_string()));
+
+        let mut rows = text_rows(&["docs", "feat", "feat", "docs"]);
+        rows.push(vec![Box::new(NullValue)]);
+        rows.push(vec![Box::new(NullValue)]);
+        rows.push(vec![Box::new(NullValue)]);
+        assert_eq!(aggregation_mode(&rows).as_text(), Some("docs".to_string()));
+
+        let rows: Vec<Vec<Box<dyn Value>>> = vec![vec![Box::new(NullValue)]];
+        assert!(aggregation_mode(&rows).is_null());
+    }
+
+    #[test]
+    fn test_aggregation_every_and_bool_and_agree() {
+        let every = aggregation_functions()["every"];
+        let groups: Vec<Vec<Vec<Box<dyn Value>>>> = vec![
+            vec![
+                vec![Box::new(BoolValue::new(true))],
+                vec![Box::new(NullValue)],
+                vec![Box::new(BoolValue::new(true))],
+            ],
+            vec![
+                vec![Box::new(BoolValue::new(true))],
+                vec![Box::new(BoolValue::new(false))],
+            ],
+            vec![vec![Box::new(NullValue)], vec![Box::new(NullValue)]],
+        ];
+
+        let expected = [Some(true), Some(false), None];
+        for (rows, expected) in groups.iter().zip(expected) {
+            let every_result = every(rows);
+            let bool_and_result = aggregation_bool_and(rows);
+            assert_eq!(every_result.as_bool(), expected);
+            assert_eq!(bool_and_result.as_bool(), expected);
+            assert_eq!(every_result.is_null(), bool_and_result.is_null());
+        }
+    }
+
+    #[test]
+    fn test_aggregation_json_agg() {
+        let rows = text_rows(&["fix \"parser\"", "feat"]);
+        let result = aggregation_json_agg(&rows);
+        assert_eq!(
+            result.as_text(),
+            Some(r#"["fix \"parser\"","feat"]"#.to_string())
+        );
+
+        let rows: Vec<Vec<Box<dyn Value>>> = vec![
+            vec![Box::new(IntValue::new(1))],
+            vec![Box::new(NullValue)],
+            vec![Box::new(IntValue::new(3))],
+        ];
+        let result = aggregation_json_agg(&rows);
+        assert_eq!(result.as_text(), Some("[1,null,3]".to_string()));
+
+        let rows: Vec<Vec<Box<dyn Value>>> = vec![vec![Box::new(DateValue::new(0))]];
+        let result = aggregation_json_agg(&rows);
+        assert_eq!(result.as_text(), Some(r#"["1970-01-01"]"#.to_string()));
+    }
+
+    #[test]
+    fn test_aggregation_accumulators_agree_with_functions() {
+        let int_rows = |values: &[i64]| -> Vec<Vec<Box<dyn Value>>> {
+            values
+                .iter()
+                .map(|value| vec![Box::new(IntValue::new(*value)) as Box<dyn Value>])
+                .collect()
+        };
+
+        for rows in [
+            int_rows(&[3, 1, 4, 1, 5]),
+            int_rows(&[-2]),
+            text_rows(&["b", "c", "a"]),
+        ] {
+            for (name, factory) in aggregation_accumulators() {
+                let mut accumulator = factory();
+                for row in &rows {
+                    accumulator.accumulate(row.clone());
+                }
+
+                let expected = aggregation_functions()[name](&rows);
+                assert!(accumulator.finish().equals(&expected), "{}", name);
+            }
+        }
+    }
+}
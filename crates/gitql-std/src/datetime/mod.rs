@@ -25,6 +25,7 @@ use gitql_core::values::date::DateValue;
 use gitql_core::values::datetime::DateTimeValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::interval::IntervalValue;
+use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::time::TimeValue;
 use gitql_core::values::Value;
@@ -55,6 +56,9 @@ pub fn register_std_datetime_functions(map: &mut HashMap<&'static str, StandardF
     map.insert("to_days", date_to_days);
     map.insert("last_day", date_last_day);
     map.insert("yearweek", date_year_and_week);
+    map.insert("datediff", date_diff);
+    map.insert("timestampdiff", date_diff);
+    map.insert("date_part", date_part);
 
     map.insert("justify_days", interval_justify_days);
     map.insert("justify_hours", interval_justify_hours);
@@ -232,6 +236,32 @@ pub fn register_std_datetime_function_signatures(map: &mut HashMap<&'static str,
             return_type: Box::new(TextType),
         },
     );
+    map.insert(
+        "datediff",
+        Signature {
+            parameters: vec![Box::new(TextType), Box::new(DateType), Box::new(DateType)],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "timestampdiff",
+        Signature {
+            parameters: vec![Box::new(TextType), Box::new(DateType), Box::new(DateType)],
+            return_type: Box::new(IntType),
+        },
+    );
+    map.insert(
+        "date_part",
+        Signature {
+            parameters: vec![
+                Box::new(TextType),
+                Box::new(VariantType {
+                    variants: vec![Box::new(DateType), Box::new(DateTimeType)],
+                }),
+            ],
+            return_type: Box::new(IntType),
+        },
+    );
 
     map.insert(
         "justify_days",
@@ -455,6 +485,77 @@ pub fn date_to_days(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     Box::new(IntValue::new(days))
 }
 
+/// Units supported by `DATEDIFF` and `TIMESTAMPDIFF`, see `date_diff`
+pub const DATE_DIFF_UNITS: [&str; 4] = ["day", "week", "month", "year"];
+
+/// Return the difference from the second to the third date in `day`, `week`, `month` or `year` units,
+/// month and year differences are calendar based, return NULL if the unit is unknown or a date is out of range
+pub fn date_diff(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let unit = inputs[0].as_text().unwrap().to_lowercase();
+    let start = DateTime::from_timestamp(inputs[1].as_date().unwrap(), 0);
+    let end = DateTime::from_timestamp(inputs[2].as_date().unwrap(), 0);
+    let (Some(start), Some(end)) = (start, end) else {
+        return Box::new(NullValue);
+    };
+
+    let days = end
+        .date_naive()
+        .signed_duration_since(start.date_naive())
+        .num_days();
+    let months =
+        (end.year() - start.year()) as i64 * 12 + end.month() as i64 - start.month() as i64;
+
+    let value = match unit.as_str() {
+        "day" => days,
+        "week" => days / 7,
+        "month" => months,
+        "year" => (end.year() - start.year()) as i64,
+        _ => return Box::new(NullValue),
+    };
+
+    Box::new(IntValue::new(value))
+}
+
+/// Return the field from the second Date or DateTime argument, used by `DATE_PART` and `EXTRACT`,
+/// return NULL if the field name is unknown
+pub fn date_part(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let field = inputs[0].as_text().unwrap();
+    let timestamp = inputs[1]
+        .as_date()
+        .or_else(|| inputs[1].as_date_time())
+        .unwrap();
+
+    match extract_date_part(&field, timestamp) {
+        Some(value) => Box::new(IntValue::new(value)),
+        None => Box::new(NullValue),
+    }
+}
+
+/// Fields supported by `DATE_PART` and `EXTRACT`, see `extract_date_part`
+pub const DATE_PART_FIELDS: [&str; 11] = [
+    "year", "quarter", "month", "week", "day", "dow", "doy", "hour", "minute", "second", "epoch",
+];
+
+/// Extract a field from timestamp, field names are case insensitive
+pub fn extract_date_part(field: &str, timestamp: i64) -> Option<i64> {
+    let date_time = DateTime::from_timestamp(timestamp, 0)?;
+    let value = match field.to_lowercase().as_str() {
+        "year" => date_time.year() as i64,
+        "quarter" => (date_time.month() as i64 - 1) / 3 + 1,
+        "month" => date_time.month() as i64,
+        "week" => date_time.iso_week().week() as i64,
+        "day" => date_time.day() as i64,
+        "dow" => date_time.weekday().num_days_from_sunday() as i64,
+        "doy" => date_time.ordinal() as i64,
+        "hour" => date_time.hour() as i64,
+        "minute" => date_time.minute() as i64,
+        "second" => date_time.second() as i64,
+        "epoch" => timestamp,
+        _ => return None,
+    };
+    Some(value)
+}
+
 pub fn date_last_day(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     let date = inputs[0].as_date().unwrap();
     let parsed_date = DateTime::from_timestamp(date, 0).unwrap();
@@ -493,3 +594,100 @@ pub fn interval_justify_hours(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     }
     Box::new(IntervalValue::new(input_interval))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date_diff_inputs(unit: &str, start: &str, end: &str) -> Vec<Box<dyn Value>> {
+        let to_date_value = |date: &str| -> Box<dyn Value> {
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+            let timestamp = date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+            Box::new(DateValue::new(timestamp))
+        };
+
+        vec![
+            Box::new(TextValue::new(unit.to_string())),
+            to_date_value(start),
+            to_date_value(end),
+        ]
+    }
+
+    #[test]
+    fn test_date_diff_days() {
+        let inputs = date_diff_inputs("day", "2024-02-27", "2024-03-01");
+        assert_eq!(date_diff(&inputs).as_int(), Some(3));
+
+        let inputs = date_diff_inputs("DAY", "2024-12-31", "2025-01-01");
+        assert_eq!(date_diff(&inputs).as_int(), Some(1));
+
+        let inputs = date_diff_inputs("week", "2024-01-01", "2024-01-20");
+        assert_eq!(date_diff(&inputs).as_int(), Some(2));
+
+        let inputs = date_diff_inputs("day", "2024-03-01", "2024-02-27");
+        assert_eq!(date_diff(&inputs).as_int(), Some(-3));
+    }
+
+    #[test]
+    fn test_date_diff_months_and_years() {
+        let inputs = date_diff_inputs("month", "2024-01-31", "2024-02-28");
+        assert_eq!(date_diff(&inputs).as_int(), Some(1));
+
+        let inputs = date_diff_inputs("month", "2023-11-15", "2024-02-01");
+        assert_eq!(date_diff(&inputs).as_int(), Some(3));
+
+        let inputs = date_diff_inputs("year", "2023-12-31", "2024-01-01");
+        assert_eq!(date_diff(&inputs).as_int(), Some(1));
+
+        let inputs = date_diff_inputs("quarter", "2023-12-31", "2024-01-01");
+        assert!(date_diff(&inputs).is_null());
+    }
+
+    #[test]
+    fn test_date_diff_out_of_range_date() {
+        let mut inputs = date_diff_inputs("day", "2024-01-01", "2024-01-02");
+        inputs[2] = Box::new(DateValue::new(i64::MAX));
+        assert!(date_diff(&inputs).is_null());
+    }
+
+    #[test]
+    fn test_date_diff_units_list() {
+        for unit in DATE_DIFF_UNITS {
+            let inputs = date_diff_inputs(unit, "2024-01-01", "2025-03-01");
+            assert!(!date_diff(&inputs).is_null(), "{}", unit);
+        }
+    }
+
+    #[test]
+    fn test_date_part_fields() {
+        // 2024-03-15 13:45:30 UTC, a Friday
+        let timestamp = 1710510330;
+        let date_part_of = |field: &str| -> Box<dyn Value> {
+            let inputs: Vec<Box<dyn Value>> = vec![
+                Box::new(TextValue::new(field.to_string())),
+                Box::new(DateTimeValue::new(timestamp)),
+            ];
+            date_part(&inputs)
+        };
+
+        assert_eq!(date_part_of("year").as_int(), Some(2024));
+        assert_eq!(date_part_of("QUARTER").as_int(), Some(1));
+        assert_eq!(date_part_of("month").as_int(), Some(3));
+        assert_eq!(date_part_of("week").as_int(), Some(11));
+        assert_eq!(date_part_of("day").as_int(), Some(15));
+        assert_eq!(date_part_of("dow").as_int(), Some(5));
+        assert_eq!(date_part_of("doy").as_int(), Some(75));
+        assert_eq!(date_part_of("hour").as_int(), Some(13));
+        assert_eq!(date_part_of("minute").as_int(), Some(45));
+        assert_eq!(date_part_of("second").as_int(), Some(30));
+        assert_eq!(date_part_of("epoch").as_int(), Some(timestamp));
+        assert!(date_part_of("century").is_null());
+    }
+
+    #[test]
+    fn test_date_part_fields_list() {
+        for field in DATE_PART_FIELDS {
+            assert!(extract_date_part(field, 0).is_some(), "{}", field);
+        }
+    }
+}
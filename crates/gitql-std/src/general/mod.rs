@@ -9,11 +9,11 @@ use gitql_ast::types::varargs::VarargsType;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
 use crate::meta_types::first_element_type;
-use crate::meta_types::second_element_type;
 
 use uuid::Uuid;
 
@@ -24,8 +24,9 @@ pub fn register_std_general_functions(map: &mut HashMap<&'static str, StandardFu
     map.insert("typeof", general_type_of);
     map.insert("greatest", general_greatest);
     map.insert("least", general_least);
+    map.insert("greatest_nulls", general_greatest_nulls);
+    map.insert("least_nulls", general_least_nulls);
     map.insert("uuid", general_uuid);
-    map.insert("if", general_if);
     map.insert("ifnull", general_ifnull);
 }
 
@@ -79,25 +80,36 @@ pub fn register_std_general_function_signatures(map: &mut HashMap<&'static str,
         },
     );
     map.insert(
-        "uuid",
+        "greatest_nulls",
         Signature {
-            parameters: vec![],
-            return_type: Box::new(TextType),
+            parameters: vec![
+                Box::new(AnyType),
+                Box::new(AnyType),
+                Box::new(VarargsType {
+                    base: Box::new(AnyType),
+                }),
+            ],
+            return_type: Box::new(AnyType),
         },
     );
     map.insert(
-        "if",
+        "least_nulls",
         Signature {
             parameters: vec![
-                Box::new(BoolType),
                 Box::new(AnyType),
-                Box::new(DynamicType {
-                    function: second_element_type,
+                Box::new(AnyType),
+                Box::new(VarargsType {
+                    base: Box::new(AnyType),
                 }),
             ],
-            return_type: Box::new(DynamicType {
-                function: second_element_type,
-            }),
+            return_type: Box::new(AnyType),
+        },
+    );
+    map.insert(
+        "uuid",
+        Signature {
+            parameters: vec![],
+            return_type: Box::new(TextType),
         },
     );
     map.insert(
@@ -136,27 +148,42 @@ pub fn general_type_of(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
 }
 
 pub fn general_greatest(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let mut max = &inputs[0];
+    extreme_non_null_value(inputs, Ordering::Greater)
+}
 
-    for value in inputs.iter().skip(1) {
-        if max.compare(value) == Some(Ordering::Greater) {
-            max = value;
-        }
-    }
+pub fn general_least(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    extreme_non_null_value(inputs, Ordering::Less)
+}
 
-    max.to_owned()
+pub fn general_greatest_nulls(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if inputs.iter().any(|value| value.is_null()) {
+        return Box::new(NullValue);
+    }
+    extreme_non_null_value(inputs, Ordering::Greater)
 }
 
-pub fn general_least(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let mut least = &inputs[0];
+pub fn general_least_nulls(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    if inputs.iter().any(|value| value.is_null()) {
+        return Box::new(NullValue);
+    }
+    extreme_non_null_value(inputs, Ordering::Less)
+}
 
-    for value in inputs.iter().skip(1) {
-        if least.compare(value) == Some(Ordering::Less) {
-            least = value;
+/// Return the first value that has the expected ordering with all other values,
+/// NULL values are skipped and NULL is returned only if all values are NULL
+fn extreme_non_null_value(inputs: &[Box<dyn Value>], ordering: Ordering) -> Box<dyn Value> {
+    let mut extreme: Option<&Box<dyn Value>> = None;
+    for value in inputs.iter().filter(|value| !value.is_null()) {
+        match extreme {
+            Some(current) if value.compare(current) != Some(ordering) => {}
+            _ => extreme = Some(value),
         }
     }
 
-    least.to_owned()
+    match extreme {
+        Some(value) => value.to_owned(),
+        None => Box::new(NullValue),
+    }
 }
 
 pub fn general_uuid(_inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -166,18 +193,69 @@ pub fn general_uuid(_inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     })
 }
 
-pub fn general_if(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
-    let condition = inputs[0].as_bool().unwrap();
-    if condition {
-        inputs[1].clone()
-    } else {
-        inputs[2].clone()
-    }
-}
-
 pub fn general_ifnull(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     if inputs[0].data_type().is_null() {
         return inputs[1].clone();
     }
     inputs[0].clone()
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::values::integer::IntValue;
+
+    use super::*;
+
+    fn values_with_null() -> Vec<Box<dyn Value>> {
+        vec![
+            Box::new(IntValue::new(3)),
+            Box::new(NullValue),
+            Box::new(IntValue::new(7)),
+            Box::new(IntValue::new(1)),
+        ]
+    }
+
+    #[test]
+    fn test_greatest_and_least_without_nulls() {
+        let int_values = |values: &[i64]| -> Vec<Box<dyn Value>> {
+            values
+                .iter()
+                .map(|value| Box::new(IntValue::new(*value)) as Box<dyn Value>)
+                .collect()
+        };
+
+        for values in [&[1, 5, 3], &[5, 3, 1], &[1, 3, 5]] {
+            assert_eq!(general_greatest(&int_values(values)).as_int(), Some(5));
+            assert_eq!(general_least(&int_values(values)).as_int(), Some(1));
+        }
+
+        let texts: Vec<Box<dyn Value>> = vec![
+            Box::new(TextValue::new("b".to_string())),
+            Box::new(TextValue::new("c".to_string())),
+            Box::new(TextValue::new("a".to_string())),
+        ];
+        assert_eq!(general_greatest(&texts).as_text(), Some("c".to_string()));
+        assert_eq!(general_least(&texts).as_text(), Some("a".to_string()));
+    }
+
+    #[test]
+    fn test_greatest_and_least_skip_nulls() {
+        assert_eq!(general_greatest(&values_with_null()).as_int(), Some(7));
+        assert_eq!(general_least(&values_with_null()).as_int(), Some(1));
+
+        let nulls: Vec<Box<dyn Value>> = vec![Box::new(NullValue), Box::new(NullValue)];
+        assert!(general_greatest(&nulls).is_null());
+        assert!(general_least(&nulls).is_null());
+    }
+
+    #[test]
+    fn test_greatest_and_least_nulls_variants_return_null() {
+        assert!(general_greatest_nulls(&values_with_null()).is_null());
+        assert!(general_least_nulls(&values_with_null()).is_null());
+
+        let values: Vec<Box<dyn Value>> =
+            vec![Box::new(IntValue::new(3)), Box::new(IntValue::new(7))];
+        assert_eq!(general_greatest_nulls(&values).as_int(), Some(7));
+        assert_eq!(general_least_nulls(&values).as_int(), Some(3));
+    }
+}
@@ -5,6 +5,7 @@ use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
 use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::integer::IntValue;
+use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
@@ -18,6 +19,7 @@ pub fn register_std_regex_functions(map: &mut HashMap<&'static str, StandardFunc
     map.insert("regexp_like", regexp_like);
     map.insert("regexp_replace", regexp_replace);
     map.insert("regexp_substr", regexp_substr);
+    map.insert("regexp_extract", regexp_extract);
 }
 
 #[inline(always)]
@@ -50,6 +52,13 @@ pub fn register_std_regex_function_signatures(map: &mut HashMap<&'static str, Si
             return_type: Box::new(TextType),
         },
     );
+    map.insert(
+        "regexp_extract",
+        Signature {
+            parameters: vec![Box::new(TextType), Box::new(TextType)],
+            return_type: Box::new(TextType),
+        },
+    );
 }
 
 /// Return the position of the pattern in the input
@@ -107,3 +116,43 @@ pub fn regexp_substr(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
         value: "".to_string(),
     })
 }
+
+/// Return the first capture group of the first match, or the whole match if the pattern has no groups
+/// Return NULL if the pattern is invalid or no match found
+pub fn regexp_extract(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let input = inputs[0].as_text().unwrap();
+    let pattern = inputs[1].as_text().unwrap();
+    if let Ok(regex) = Regex::new(&pattern) {
+        if let Some(captures) = regex.captures(&input) {
+            let matched = captures.get(1).or_else(|| captures.get(0));
+            if let Some(matched) = matched {
+                return Box::new(TextValue {
+                    value: matched.as_str().to_string(),
+                });
+            }
+        }
+    }
+    Box::new(NullValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regexp_extract_version_from_message() {
+        let message: Box<dyn Value> =
+            Box::new(TextValue::new("Bump release to v2.10.1".to_string()));
+
+        let pattern: Box<dyn Value> = Box::new(TextValue::new(r"v(\d+\.\d+\.\d+)".to_string()));
+        let result = regexp_extract(&[message.clone(), pattern]);
+        assert_eq!(result.as_text(), Some("2.10.1".to_string()));
+
+        let pattern: Box<dyn Value> = Box::new(TextValue::new(r"\d+\.\d+".to_string()));
+        let result = regexp_extract(&[message.clone(), pattern]);
+        assert_eq!(result.as_text(), Some("2.10".to_string()));
+
+        let pattern: Box<dyn Value> = Box::new(TextValue::new(r"rc\d+".to_string()));
+        assert!(regexp_extract(&[message, pattern]).is_null());
+    }
+}
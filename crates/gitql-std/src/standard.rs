@@ -26,6 +26,11 @@ pub fn standard_functions() -> &'static HashMap<&'static str, StandardFunction>
     })
 }
 
+/// Standard functions that can return different values for the same arguments
+pub fn volatile_standard_functions() -> &'static [&'static str] {
+    &["rand", "uuid", "array_shuffle"]
+}
+
 pub fn standard_function_signatures() -> HashMap<&'static str, Signature> {
     let mut map: HashMap<&'static str, Signature> = HashMap::new();
     register_std_text_function_signatures(&mut map);
@@ -37,3 +42,62 @@ pub fn standard_function_signatures() -> HashMap<&'static str, Signature> {
     register_std_range_function_signatures(&mut map);
     map
 }
+
+#[cfg(test)]
+mod tests {
+    use gitql_core::environment::Environment;
+    use gitql_core::schema::Schema;
+
+    use super::*;
+    use crate::aggregation::aggregation_function_signatures;
+    use crate::aggregation::aggregation_functions;
+    use crate::window::window_function_signatures;
+    use crate::window::window_functions;
+
+    #[test]
+    fn test_environment_function_names_and_signatures() {
+        let mut env = Environment::new(Schema::default());
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+        env.with_aggregation_functions(&aggregation_function_signatures(), aggregation_functions());
+        env.with_window_functions(&window_function_signatures(), window_functions());
+
+        let names = env.all_function_names();
+        for name in ["lower", "concat", "count", "max", "row_number"] {
+            assert!(names.contains(&name.to_string()), "missing `{}`", name);
+        }
+
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+
+        let lower = env.function_signature("lower").unwrap();
+        assert_eq!(lower.parameters.len(), 1);
+        assert!(lower.parameters[0].is_text());
+        assert!(lower.return_type.is_text());
+
+        let count = env.function_signature("count").unwrap();
+        assert_eq!(count.parameters.len(), 1);
+        assert!(count.return_type.is_int());
+
+        let row_number = env.function_signature("row_number").unwrap();
+        assert!(row_number.parameters.is_empty());
+
+        assert!(env.function_signature("not_a_function").is_none());
+    }
+
+    #[test]
+    fn test_volatile_standard_functions_registration() {
+        let mut env = Environment::new(Schema::default());
+        env.with_standard_functions(&standard_function_signatures(), standard_functions());
+        env.with_volatile_functions(volatile_standard_functions());
+
+        for name in ["rand", "uuid", "array_shuffle"] {
+            assert!(
+                env.is_volatile_function(name),
+                "`{}` must be volatile",
+                name
+            );
+        }
+        assert!(!env.is_volatile_function("lower"));
+    }
+}
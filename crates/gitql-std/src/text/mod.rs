@@ -1,11 +1,15 @@
 use gitql_ast::types::any::AnyType;
+use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::float::FloatType;
 use gitql_ast::types::integer::IntType;
 use gitql_ast::types::optional::OptionType;
 use gitql_ast::types::text::TextType;
+use gitql_ast::types::varargs::VarargsType;
 use gitql_ast::types::variant::VariantType;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
+use gitql_core::values::boolean::BoolValue;
+use gitql_core::values::error::ErrorValue;
 use gitql_core::values::integer::IntValue;
 use gitql_core::values::null::NullValue;
 use gitql_core::values::text::TextValue;
@@ -39,11 +43,14 @@ pub fn register_std_text_functions(map: &mut HashMap<&'static str, StandardFunct
     map.insert("soundex", text_soundex);
     map.insert("concat", text_concat);
     map.insert("concat_ws", text_concat_ws);
+    map.insert("format", text_format);
     map.insert("unicode", text_unicode);
     map.insert("strcmp", text_strcmp);
     map.insert("quotename", text_quotename);
     map.insert("str", text_str);
     map.insert("to_hex", text_to_hex);
+    map.insert("blank", text_blank);
+    map.insert("like_match", text_like_match);
 }
 
 #[inline(always)]
@@ -234,6 +241,18 @@ pub fn register_std_text_function_signatures(map: &mut HashMap<&'static str, Sig
             return_type: Box::new(TextType),
         },
     );
+    map.insert(
+        "format",
+        Signature {
+            parameters: vec![
+                Box::new(TextType),
+                Box::new(VarargsType {
+                    base: Box::new(AnyType),
+                }),
+            ],
+            return_type: Box::new(TextType),
+        },
+    );
     map.insert(
         "unicode",
         Signature {
@@ -284,6 +303,20 @@ pub fn register_std_text_function_signatures(map: &mut HashMap<&'static str, Sig
             return_type: Box::new(TextType),
         },
     );
+    map.insert(
+        "blank",
+        Signature {
+            parameters: vec![Box::new(TextType)],
+            return_type: Box::new(BoolType),
+        },
+    );
+    map.insert(
+        "like_match",
+        Signature {
+            parameters: vec![Box::new(TextType), Box::new(TextType)],
+            return_type: Box::new(TextType),
+        },
+    );
 }
 
 pub fn text_bin(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -584,6 +617,50 @@ pub fn text_concat_ws(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     Box::new(TextValue { value })
 }
 
+/// Replace each `%s` placeholder in the format with the next argument, and `%%` with `%`
+pub fn text_format(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let Some(format) = inputs[0].as_text() else {
+        return Box::new(NullValue);
+    };
+
+    let arguments = &inputs[1..];
+    let mut placeholders_count = 0;
+    let mut value = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+    while let Some(char) = chars.next() {
+        if char == '%' {
+            match chars.peek() {
+                Some('s') => {
+                    chars.next();
+                    if let Some(argument) = arguments.get(placeholders_count) {
+                        value.push_str(&argument.to_string());
+                    }
+                    placeholders_count += 1;
+                    continue;
+                }
+                Some('%') => {
+                    chars.next();
+                    value.push('%');
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        value.push(char);
+    }
+
+    // Same as the check of the literal format, but for formats that are known only at runtime
+    if placeholders_count != arguments.len() {
+        return Box::new(ErrorValue::new(format!(
+            "FORMAT has {} `%s` placeholders but got {} arguments",
+            placeholders_count,
+            arguments.len()
+        )));
+    }
+
+    Box::new(TextValue { value })
+}
+
 pub fn text_strcmp(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     let value = match inputs[0].as_text().cmp(&inputs[1].as_text()) {
         std::cmp::Ordering::Less => 1,
@@ -670,3 +747,112 @@ pub fn text_to_hex(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
     let value = format!("0x{}", number);
     Box::new(TextValue { value })
 }
+
+pub fn text_blank(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let value = inputs[0].as_text().is_none_or(|text| text.is_empty());
+    Box::new(BoolValue { value })
+}
+
+/// Return the portion of the text matched by the single `%` wildcard of the pattern,
+/// or NULL if the text doesn't match or the pattern has other shape
+pub fn text_like_match(inputs: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let (Some(text), Some(pattern)) = (inputs[0].as_text(), inputs[1].as_text()) else {
+        return Box::new(NullValue);
+    };
+
+    if pattern.contains('_') || pattern.matches('%').count() != 1 {
+        return Box::new(NullValue);
+    }
+
+    // Same as `LIKE` the prefix and suffix are matched case insensitive
+    let (prefix, suffix) = pattern.split_once('%').unwrap();
+    let text_chars: Vec<char> = text.chars().collect();
+    let prefix_len = prefix.chars().count();
+    let suffix_len = suffix.chars().count();
+    if text_chars.len() < prefix_len + suffix_len {
+        return Box::new(NullValue);
+    }
+
+    let is_same_chars = |chars: &[char], expected: &str| {
+        chars
+            .iter()
+            .zip(expected.chars())
+            .all(|(char, expected_char)| char.to_lowercase().eq(expected_char.to_lowercase()))
+    };
+
+    let suffix_start = text_chars.len() - suffix_len;
+    if !is_same_chars(&text_chars[..prefix_len], prefix)
+        || !is_same_chars(&text_chars[suffix_start..], suffix)
+    {
+        return Box::new(NullValue);
+    }
+
+    Box::new(TextValue {
+        value: text_chars[prefix_len..suffix_start].iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_format_substitution() {
+        let format: Box<dyn Value> =
+            Box::new(TextValue::new("%s has %s commits, 100%%".to_string()));
+        let name: Box<dyn Value> = Box::new(TextValue::new("amr".to_string()));
+        let count: Box<dyn Value> = Box::new(IntValue::new(42));
+        let result = text_format(&[format, name, count]);
+        assert_eq!(
+            result.as_text(),
+            Some("amr has 42 commits, 100%".to_string())
+        );
+
+        let format: Box<dyn Value> = Box::new(NullValue);
+        assert!(text_format(&[format]).is_null());
+
+        let format: Box<dyn Value> = Box::new(TextValue::new("%s and %s".to_string()));
+        let name: Box<dyn Value> = Box::new(TextValue::new("amr".to_string()));
+        let result = text_format(&[format, name]);
+        assert_eq!(
+            result.as_error(),
+            Some("FORMAT has 2 `%s` placeholders but got 1 arguments".to_string())
+        );
+    }
+
+    #[test]
+    fn test_text_blank() {
+        let null: Box<dyn Value> = Box::new(NullValue);
+        assert_eq!(text_blank(&[null]).as_bool(), Some(true));
+
+        let empty: Box<dyn Value> = Box::new(TextValue::new(String::new()));
+        assert_eq!(text_blank(&[empty]).as_bool(), Some(true));
+
+        let text: Box<dyn Value> = Box::new(TextValue::new("amr".to_string()));
+        assert_eq!(text_blank(&[text]).as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_text_like_match() {
+        let like_match = |text: &str, pattern: &str| {
+            let text: Box<dyn Value> = Box::new(TextValue::new(text.to_string()));
+            let pattern: Box<dyn Value> = Box::new(TextValue::new(pattern.to_string()));
+            text_like_match(&[text, pattern]).as_text()
+        };
+
+        assert_eq!(
+            like_match("feat: add LIKE_MATCH", "feat: %"),
+            Some("add LIKE_MATCH".to_string())
+        );
+        assert_eq!(
+            like_match("Merge branch 'main'", "merge branch '%'"),
+            Some("main".to_string())
+        );
+        assert_eq!(like_match("v1.2.0", "v%"), Some("1.2.0".to_string()));
+        assert_eq!(like_match("ab", "a%b"), Some("".to_string()));
+        assert_eq!(like_match("fix: typo", "feat: %"), None);
+        assert_eq!(like_match("a", "a%a"), None);
+        assert_eq!(like_match("feat: x", "feat%: %"), None);
+        assert_eq!(like_match("feat: x", "fea_: %"), None);
+    }
+}
@@ -1,14 +1,25 @@
 use std::collections::HashMap;
 
+use gitql_ast::types::boolean::BoolType;
 use gitql_ast::types::text::TextType;
 use gitql_core::signature::Signature;
 use gitql_core::signature::StandardFunction;
+use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::text::TextValue;
 use gitql_core::values::Value;
 
+/// Number of characters used by git to abbreviate commit hashes
+pub(crate) const SHORT_HASH_LEN: usize = 7;
+
+/// Number of hex characters of SHA-1 and SHA-256 object hashes
+const SHA_HASHES_LEN: [usize; 2] = [40, 64];
+
 #[inline(always)]
 pub(crate) fn register_commits_functions(map: &mut HashMap<&'static str, StandardFunction>) {
     map.insert("commit_conventional", commit_conventional);
+    map.insert("short_hash", short_hash);
+    map.insert("is_sha", is_sha);
+    map.insert("sha_prefix_match", sha_prefix_match);
 }
 
 #[inline(always)]
@@ -17,6 +28,20 @@ pub(crate) fn register_commits_function_signatures(map: &mut HashMap<&'static st
         "commit_conventional",
         Signature::with_return(Box::new(TextType)).add_parameter(Box::new(TextType)),
     );
+    map.insert(
+        "short_hash",
+        Signature::with_return(Box::new(TextType)).add_parameter(Box::new(TextType)),
+    );
+    map.insert(
+        "is_sha",
+        Signature::with_return(Box::new(BoolType)).add_parameter(Box::new(TextType)),
+    );
+    map.insert(
+        "sha_prefix_match",
+        Signature::with_return(Box::new(BoolType))
+            .add_parameter(Box::new(TextType))
+            .add_parameter(Box::new(TextType)),
+    );
 }
 
 fn commit_conventional(values: &[Box<dyn Value>]) -> Box<dyn Value> {
@@ -25,3 +50,91 @@ fn commit_conventional(values: &[Box<dyn Value>]) -> Box<dyn Value> {
     let value = if split.len() == 1 { "" } else { split[0] }.to_string();
     Box::new(TextValue::new(value))
 }
+
+fn short_hash(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let hash = values[0].as_text().unwrap();
+    let value = hash.chars().take(SHORT_HASH_LEN).collect();
+    Box::new(TextValue::new(value))
+}
+
+fn is_sha(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let value = values[0]
+        .as_text()
+        .and_then(|hash| normalize_sha(&hash))
+        .is_some_and(|hash| SHA_HASHES_LEN.contains(&hash.len()));
+    Box::new(BoolValue::new(value))
+}
+
+fn sha_prefix_match(values: &[Box<dyn Value>]) -> Box<dyn Value> {
+    let full_hash = values[0].as_text().and_then(|hash| normalize_sha(&hash));
+    let prefix = values[1].as_text().and_then(|hash| normalize_sha(&hash));
+    let value = match (full_hash, prefix) {
+        (Some(full_hash), Some(prefix)) => !prefix.is_empty() && full_hash.starts_with(&prefix),
+        _ => false,
+    };
+    Box::new(BoolValue::new(value))
+}
+
+/// Return the lowercase hash without surrounding whitespaces, or None if it has non hex characters
+fn normalize_sha(hash: &str) -> Option<String> {
+    let hash = hash.trim();
+    if !hash.chars().all(|char| char.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(hash.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_hash() {
+        let hash: Box<dyn Value> = Box::new(TextValue::new(
+            "4077891c0a3b5e2d9f1e8a7b6c5d4e3f2a1b0c9d".to_string(),
+        ));
+        assert_eq!(short_hash(&[hash]).literal(), "4077891");
+
+        let short: Box<dyn Value> = Box::new(TextValue::new("abc".to_string()));
+        assert_eq!(short_hash(&[short]).literal(), "abc");
+    }
+
+    #[test]
+    fn test_is_sha() {
+        let hashes = [
+            ("4077891c0a3b5e2d9f1e8a7b6c5d4e3f2a1b0c9d", true),
+            ("4077891C0A3B5E2D9F1E8A7B6C5D4E3F2A1B0C9D", true),
+            (
+                "4077891c0a3b5e2d9f1e8a7b6c5d4e3f2a1b0c9d4077891c0a3b5e2d9f1e8a7b",
+                true,
+            ),
+            ("4077891", false),
+            ("4077891c0a3b5e2d9f1e8a7b6c5d4e3f2a1b0c9g", false),
+            ("", false),
+        ];
+
+        for (hash, expected) in hashes {
+            let hash: Box<dyn Value> = Box::new(TextValue::new(hash.to_string()));
+            assert_eq!(is_sha(&[hash]).as_bool(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_sha_prefix_match() {
+        let full_hash = "4077891c0a3b5e2d9f1e8a7b6c5d4e3f2a1b0c9d";
+        let prefixes = [
+            ("4077891", true),
+            ("4077891C0A", true),
+            (full_hash, true),
+            ("4077892", false),
+            ("", false),
+            ("40778z", false),
+        ];
+
+        for (prefix, expected) in prefixes {
+            let full: Box<dyn Value> = Box::new(TextValue::new(full_hash.to_string()));
+            let prefix: Box<dyn Value> = Box::new(TextValue::new(prefix.to_string()));
+            assert_eq!(sha_prefix_match(&[full, prefix]).as_bool(), Some(expected));
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::OnceLock;
 
 use commits::register_commits_function_signatures;
 use commits::register_commits_functions;
+pub(crate) use commits::SHORT_HASH_LEN;
 use diffs::register_diffs_function_signatures;
 use diffs::register_diffs_functions;
 use gitql_core::signature::Signature;
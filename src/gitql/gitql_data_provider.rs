@@ -1,5 +1,6 @@
 use std::convert::Infallible;
 
+use gitql_cli::arguments::WalkScope;
 use gitql_core::object::Row;
 use gitql_core::values::boolean::BoolValue;
 use gitql_core::values::datetime::DateTimeValue;
@@ -12,17 +13,29 @@ use gitql_engine::data_provider::DataProvider;
 use gix::diff::blob::pipeline::Mode;
 use gix::refs::Category;
 
+use super::functions::SHORT_HASH_LEN;
 use super::values::diff_changes::DiffChange;
 use super::values::diff_changes::DiffChangesValue;
 
 pub struct GitQLDataProvider {
     repos: Vec<gix::Repository>,
+    walk_scope: WalkScope,
 }
 
 impl GitQLDataProvider {
     #[must_use]
     pub fn new(repos: Vec<gix::Repository>) -> Self {
-        Self { repos }
+        Self {
+            repos,
+            walk_scope: WalkScope::Head,
+        }
+    }
+
+    /// Set which references are used as starting points when walking commits
+    #[must_use]
+    pub fn with_walk_scope(mut self, walk_scope: WalkScope) -> Self {
+        self.walk_scope = walk_scope;
+        self
     }
 }
 
@@ -31,8 +44,12 @@ impl DataProvider for GitQLDataProvider {
         let mut rows: Vec<Row> = vec![];
 
         for repository in &self.repos {
-            let mut repo_rows =
-                select_gql_objects(repository, table.to_string(), selected_columns)?;
+            let mut repo_rows = select_gql_objects(
+                repository,
+                table.to_string(),
+                selected_columns,
+                self.walk_scope,
+            )?;
             rows.append(&mut repo_rows);
         }
 
@@ -44,13 +61,14 @@ fn select_gql_objects(
     repo: &gix::Repository,
     table: String,
     selected_columns: &[String],
+    walk_scope: WalkScope,
 ) -> Result<Vec<Row>, String> {
     match table.as_str() {
         "refs" => select_references(repo, selected_columns),
-        "commits" => select_commits(repo, selected_columns),
+        "commits" => select_commits(repo, selected_columns, walk_scope),
         "branches" => select_branches(repo, selected_columns),
-        "diffs" => select_diffs(repo, selected_columns),
-        "diffs_changes" => select_diffs_changes(repo, selected_columns),
+        "diffs" => select_diffs(repo, selected_columns, walk_scope),
+        "diffs_changes" => select_diffs_changes(repo, selected_columns, walk_scope),
         "tags" => select_tags(repo, selected_columns),
         _ => Ok(vec![Row { values: vec![] }]),
     }
@@ -109,14 +127,50 @@ fn select_references(
     Ok(rows)
 }
 
-fn select_commits(repo: &gix::Repository, selected_columns: &[String]) -> Result<Vec<Row>, String> {
-    let head_id = repo.head_id();
-    if let Err(error) = head_id {
-        return Err(error.to_string());
+/// Collect the commits ids that the commits walk should start from for the given scope
+fn select_walk_tips(
+    repo: &gix::Repository,
+    walk_scope: WalkScope,
+) -> Result<Vec<gix::ObjectId>, String> {
+    if walk_scope == WalkScope::Head {
+        let head_id = repo.head_id().map_err(|error| error.to_string())?;
+        return Ok(vec![head_id.detach()]);
+    }
+
+    let git_references = repo.references().map_err(|error| error.to_string())?;
+    let references = if walk_scope == WalkScope::Branches {
+        git_references.local_branches()
+    } else {
+        git_references.all()
+    }
+    .map_err(|error| error.to_string())?;
+
+    let mut tips: Vec<gix::ObjectId> = vec![];
+    for mut reference in references.flatten() {
+        // References can point to non commit objects, for example tags on blobs
+        if let Ok(id) = reference.peel_to_id_in_place() {
+            if let Ok(object) = id.object() {
+                if object.kind == gix::object::Kind::Commit && !tips.contains(&id.detach()) {
+                    tips.push(id.detach());
+                }
+            }
+        }
     }
 
+    Ok(tips)
+}
+
+fn select_commits(
+    repo: &gix::Repository,
+    selected_columns: &[String],
+    walk_scope: WalkScope,
+) -> Result<Vec<Row>, String> {
+    let tips = select_walk_tips(repo, walk_scope)?;
     let repo_path = repo.path().to_str().unwrap();
-    let walker = head_id.unwrap().ancestors().all().unwrap();
+    let walker = repo
+        .rev_walk(tips)
+        .all()
+        .map_err(|error| error.to_string())?;
     let mut rows: Vec<Row> = vec![];
 
     for commit_info in walker {
@@ -131,6 +185,12 @@ fn select_commits(repo: &gix::Repository, selected_columns: &[String]) -> Result
                 continue;
             }
 
+            if column_name == "short_hash" {
+                let short_hash = commit_info.id.to_hex_with_len(SHORT_HASH_LEN).to_string();
+                values.push(Box::new(TextValue::new(short_hash)));
+                continue;
+            }
+
             if column_name == "author_name" {
                 let author_name = commit.author().name.to_string();
                 values.push(Box::new(TextValue::new(author_name)));
@@ -284,7 +344,11 @@ fn select_branches(
     Ok(rows)
 }
 
-fn select_diffs(repo: &gix::Repository, selected_columns: &[String]) -> Result<Vec<Row>, String> {
+fn select_diffs(
+    repo: &gix::Repository,
+    selected_columns: &[String],
+    walk_scope: WalkScope,
+) -> Result<Vec<Row>, String> {
     let repo = {
         let mut repo = repo.clone();
         repo.object_cache_size_if_unset(4 * 1024 * 1024);
@@ -302,7 +366,11 @@ fn select_diffs(repo: &gix::Repository, selected_columns: &[String]) -> Result<V
     });
 
     let repo_path = repo.path().to_str().unwrap();
-    let walker = repo.head_id().unwrap().ancestors().all().unwrap();
+    let tips = select_walk_tips(&repo, walk_scope)?;
+    let walker = repo
+        .rev_walk(tips)
+        .all()
+        .map_err(|error| error.to_string())?;
     let commits_info = walker.filter_map(Result::ok);
 
     let mut rows: Vec<Row> = vec![];
@@ -408,6 +476,7 @@ fn select_diffs(repo: &gix::Repository, selected_columns: &[String]) -> Result<V
 fn select_diffs_changes(
     repo: &gix::Repository,
     selected_columns: &[String],
+    walk_scope: WalkScope,
 ) -> Result<Vec<Row>, String> {
     let repo = {
         let mut repo = repo.clone();
@@ -422,7 +491,11 @@ fn select_diffs_changes(
     let mut diff_cache = rewrite_cache.clone();
 
     let repo_path = repo.path().to_str().unwrap();
-    let walker = repo.head_id().unwrap().ancestors().all().unwrap();
+    let tips = select_walk_tips(&repo, walk_scope)?;
+    let walker = repo
+        .rev_walk(tips)
+        .all()
+        .map_err(|error| error.to_string())?;
     let commits_info = walker.filter_map(Result::ok);
 
     let mut rows: Vec<Row> = vec![];
@@ -538,3 +611,68 @@ fn select_tags(repo: &gix::Repository, selected_columns: &[String]) -> Result<Ve
 
     Ok(rows)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::process::Command;
+
+    use super::*;
+
+    fn run_git(repo_path: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(["-c", "user.name=gitql", "-c", "user.email=gitql@test.com"])
+            .args(args)
+            .current_dir(repo_path)
+            .status()
+            .expect("git must be installed to run this test");
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    /// Create a repository with one commit on `main`, one more on `feature`
+    /// and one more that is reachable only from the `detached` tag
+    fn create_fixture_repository(repo_path: &Path) -> gix::Repository {
+        std::fs::create_dir_all(repo_path).unwrap();
+
+        run_git(repo_path, &["init", "-q", "-b", "main"]);
+        run_git(repo_path, &["commit", "-q", "--allow-empty", "-m", "first"]);
+        run_git(repo_path, &["checkout", "-q", "-b", "feature"]);
+        run_git(
+            repo_path,
+            &["commit", "-q", "--allow-empty", "-m", "second"],
+        );
+        run_git(repo_path, &["checkout", "-q", "--detach", "main"]);
+        run_git(repo_path, &["commit", "-q", "--allow-empty", "-m", "third"]);
+        run_git(repo_path, &["tag", "detached"]);
+        run_git(repo_path, &["checkout", "-q", "main"]);
+
+        gix::open(repo_path).unwrap()
+    }
+
+    #[test]
+    fn test_commits_count_for_each_walk_scope() {
+        // Unique directory per run so parallel or concurrent test runs never share the fixture
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let fixture_name = format!("gitql_walk_scope_{}_{}", std::process::id(), nanos);
+        let repo_path = std::env::temp_dir().join(fixture_name);
+        let repo = create_fixture_repository(&repo_path);
+
+        let scopes = [
+            (WalkScope::Head, 1),
+            (WalkScope::Branches, 2),
+            (WalkScope::All, 3),
+        ];
+
+        let selected_columns = ["commit_id".to_string()];
+        for (walk_scope, expected_count) in scopes {
+            let provider = GitQLDataProvider::new(vec![repo.clone()]).with_walk_scope(walk_scope);
+            let rows = provider.provide("commits", &selected_columns).unwrap();
+            assert_eq!(rows.len(), expected_count, "{:?}", walk_scope);
+        }
+
+        let _ = std::fs::remove_dir_all(&repo_path);
+    }
+}
@@ -12,11 +12,12 @@ use lineeditor::Span;
 use lineeditor::StringPrompt;
 use lineeditor::Suggestion;
 
-const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
+const GITQL_RESERVED_KEYWORDS: [&str; 64] = [
     "do",
     "set",
     "select",
     "distinct",
+    "exclude",
     "from",
     "where",
     "limit",
@@ -69,7 +70,13 @@ const GITQL_RESERVED_KEYWORDS: [&str; 57] = [
     "as",
     "asc",
     "desc",
+    "collate",
+    "binary",
+    "nocase",
     "array",
+    "union",
+    "intersect",
+    "except",
 ];
 
 #[derive(Default)]
@@ -12,6 +12,7 @@ use crate::gitql::types::diff_changes::DiffChangesType;
 pub fn tables_fields_types() -> HashMap<&'static str, Box<dyn DataType>> {
     let mut map: HashMap<&'static str, Box<dyn DataType>> = HashMap::new();
     map.insert("commit_id", Box::new(TextType));
+    map.insert("short_hash", Box::new(TextType));
     map.insert("title", Box::new(TextType));
     map.insert("message", Box::new(TextType));
     map.insert("name", Box::new(TextType));
@@ -37,6 +38,89 @@ pub fn tables_fields_types() -> HashMap<&'static str, Box<dyn DataType>> {
     map
 }
 
+/// Short description of the git data that each field of each table maps to, used by `DESCRIBE`
+pub fn tables_fields_descriptions() -> HashMap<(&'static str, &'static str), &'static str> {
+    let mut map: HashMap<(&'static str, &'static str), &'static str> = HashMap::new();
+    map.insert(("refs", "name"), "Reference short name");
+    map.insert(
+        ("refs", "full_name"),
+        "Full reference name for example `refs/heads/main`",
+    );
+    map.insert(
+        ("refs", "type"),
+        "Reference category for example `LocalBranch` or `Tag`",
+    );
+    map.insert(("refs", "repo"), "Repository path");
+    map.insert(("commits", "commit_id"), "Commit object id (SHA)");
+    map.insert(("commits", "short_hash"), "Abbreviated commit object id");
+    map.insert(("commits", "title"), "First line of the commit message");
+    map.insert(("commits", "message"), "Full commit message");
+    map.insert(("commits", "author_name"), "Commit author name");
+    map.insert(("commits", "author_email"), "Commit author email");
+    map.insert(("commits", "committer_name"), "Commit committer name");
+    map.insert(("commits", "committer_email"), "Commit committer email");
+    map.insert(("commits", "datetime"), "Commit time");
+    map.insert(("commits", "parents_count"), "Number of the commit parents");
+    map.insert(("commits", "repo"), "Repository path");
+    map.insert(("branches", "name"), "Branch name");
+    map.insert(
+        ("branches", "commit_count"),
+        "Number of commits reachable from the branch",
+    );
+    map.insert(
+        ("branches", "is_head"),
+        "True if the branch is the current HEAD",
+    );
+    map.insert(
+        ("branches", "is_remote"),
+        "True if the branch is a remote tracking branch",
+    );
+    map.insert(
+        ("branches", "updated"),
+        "Time of the last commit on the branch",
+    );
+    map.insert(("branches", "repo"), "Repository path");
+    map.insert(("diffs", "commit_id"), "Object id of the diffed commit");
+    map.insert(("diffs", "author_name"), "Commit author name");
+    map.insert(("diffs", "author_email"), "Commit author email");
+    map.insert(
+        ("diffs", "insertions"),
+        "Number of inserted lines in the commit",
+    );
+    map.insert(
+        ("diffs", "removals"),
+        "Number of removed lines in the commit",
+    );
+    map.insert(
+        ("diffs", "files_changed"),
+        "Number of changed files in the commit",
+    );
+    map.insert(
+        ("diffs", "diff_changes"),
+        "List of changes per file in the commit diff",
+    );
+    map.insert(("diffs", "datetime"), "Commit time");
+    map.insert(("diffs", "repo"), "Repository path");
+    map.insert(
+        ("diffs_changes", "commit_id"),
+        "Object id of the diffed commit",
+    );
+    map.insert(
+        ("diffs_changes", "insertions"),
+        "Number of inserted lines in the file",
+    );
+    map.insert(
+        ("diffs_changes", "removals"),
+        "Number of removed lines in the file",
+    );
+    map.insert(("diffs_changes", "mode"), "Change kind of the file");
+    map.insert(("diffs_changes", "path"), "Changed file path");
+    map.insert(("diffs_changes", "repo"), "Repository path");
+    map.insert(("tags", "name"), "Tag name");
+    map.insert(("tags", "repo"), "Repository path");
+    map
+}
+
 pub fn tables_fields_names() -> &'static HashMap<&'static str, Vec<&'static str>> {
     static HASHMAP: OnceLock<HashMap<&'static str, Vec<&'static str>>> = OnceLock::new();
     HASHMAP.get_or_init(|| {
@@ -46,6 +130,7 @@ pub fn tables_fields_names() -> &'static HashMap<&'static str, Vec<&'static str>
             "commits",
             vec![
                 "commit_id",
+                "short_hash",
                 "title",
                 "message",
                 "author_name",
@@ -97,3 +182,31 @@ pub fn tables_fields_names() -> &'static HashMap<&'static str, Vec<&'static str>
         map
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_fields_descriptions() {
+        let descriptions = tables_fields_descriptions();
+        for (table, fields) in tables_fields_names() {
+            for field in fields {
+                assert!(
+                    descriptions.contains_key(&(*table, *field)),
+                    "missing description of `{}.{}`",
+                    table,
+                    field
+                );
+            }
+        }
+
+        // The same field name can have different meaning in different tables
+        assert_eq!(descriptions[&("branches", "name")], "Branch name");
+        assert_eq!(descriptions[&("tags", "name")], "Tag name");
+        assert_ne!(
+            descriptions[&("diffs", "insertions")],
+            descriptions[&("diffs_changes", "insertions")]
+        );
+    }
+}
@@ -1,9 +1,12 @@
 use gitql_core::environment::Environment;
 use gitql_core::schema::Schema;
+use gitql_schema::tables_fields_descriptions;
 use gitql_schema::tables_fields_names;
 use gitql_schema::tables_fields_types;
+use gitql_std::aggregation::aggregation_accumulators;
 use gitql_std::aggregation::aggregation_function_signatures;
 use gitql_std::aggregation::aggregation_functions;
+use gitql_std::standard::volatile_standard_functions;
 use gitql_std::window::window_function_signatures;
 use gitql_std::window::window_functions;
 
@@ -15,10 +18,11 @@ pub(crate) mod types;
 pub(crate) mod values;
 
 pub(crate) fn create_gitql_environment() -> Environment {
-    let schema = Schema {
-        tables_fields_names: tables_fields_names().to_owned(),
-        tables_fields_types: tables_fields_types().to_owned(),
-    };
+    let schema = Schema::new(
+        tables_fields_names().to_owned(),
+        tables_fields_types().to_owned(),
+    )
+    .with_tables_fields_descriptions(tables_fields_descriptions());
 
     let std_signatures = functions::gitql_std_signatures();
     let std_functions = functions::gitql_std_functions();
@@ -31,7 +35,9 @@ pub(crate) fn create_gitql_environment() -> Environment {
 
     let mut env = Environment::new(schema);
     env.with_standard_functions(&std_signatures, std_functions);
+    env.with_volatile_functions(volatile_standard_functions());
     env.with_aggregation_functions(&aggregation_signatures, aggregation_functions);
+    env.with_aggregation_accumulators(aggregation_accumulators());
     env.with_window_functions(&window_signatures, window_function);
     env
 }
@@ -10,6 +10,10 @@ impl DataType for DiffChangesType {
         "DiffChangesType".to_owned()
     }
 
+    fn type_tag(&self) -> String {
+        "diff_changes".to_owned()
+    }
+
     #[allow(clippy::borrowed_box)]
     fn equals(&self, other: &Box<dyn DataType>) -> bool {
         let self_type: Box<dyn DataType> = Box::new(DiffChangesType);
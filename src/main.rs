@@ -204,23 +204,36 @@ fn execute_gitql_query(
     let front_duration = front_start.elapsed();
 
     let engine_start = std::time::Instant::now();
-    let provider: Box<dyn DataProvider> = Box::new(GitQLDataProvider::new(repos.to_vec()));
+    let provider: Box<dyn DataProvider> =
+        Box::new(GitQLDataProvider::new(repos.to_vec()).with_walk_scope(arguments.walk_scope));
     let evaluation_result = engine::evaluate(env, &provider, query_node);
     let engine_duration = engine_start.elapsed();
 
     // Report Runtime exceptions if they exists
     if evaluation_result.is_err() {
-        let exception = Diagnostic::exception(&evaluation_result.err().unwrap());
-        reporter.report_diagnostic("", exception);
+        let error = evaluation_result.err().unwrap();
+        let mut exception = Diagnostic::exception(&error.message);
+        if let Some(location) = error.location {
+            exception = exception.with_location(location);
+            reporter.report_diagnostic(&query, exception);
+        } else {
+            reporter.report_diagnostic("", exception);
+        }
         return;
     }
 
     let printer: Box<dyn OutputPrinter> = match arguments.output_format {
         OutputFormat::Render => {
-            Box::new(TablePrinter::new(arguments.pagination, arguments.page_size))
+            let printer = TablePrinter::new(arguments.pagination, arguments.page_size);
+            Box::new(printer.with_float_precision(arguments.float_precision))
         }
-        OutputFormat::JSON => Box::new(JSONPrinter {}),
-        OutputFormat::CSV => Box::new(CSVPrinter {}),
+        OutputFormat::JSON => Box::new(JSONPrinter {
+            float_precision: arguments.float_precision,
+            with_types: arguments.typed_output,
+        }),
+        OutputFormat::CSV => Box::new(CSVPrinter {
+            float_precision: arguments.float_precision,
+        }),
     };
 
     // Render the result only if they are selected groups not any other statement